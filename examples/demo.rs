@@ -1,8 +1,9 @@
-use sui_token_transfer_tracker::transaction_processor::{TransactionProcessor};
+use sui_token_transfer_tracker::transaction_processor::{TransactionProcessor, TransactionStatus};
 use sui_token_transfer_tracker::event_monitor::TransferEvent;
 use sui_token_transfer_tracker::output_formatter::OutputFormatter;
 use sui_token_transfer_tracker::alert_system::AlertSystem;
 use sui_token_transfer_tracker::config::Config;
+use sui_token_transfer_tracker::units::{mist_to_sui, SUI_DECIMALS};
 use chrono::Utc;
 use tokio::time::{sleep, Duration};
 
@@ -39,6 +40,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             timestamp: Utc::now().timestamp() as u64,
             block_number: 10000001,
             event_type: "transfer".to_string(),
+            gas_used: None,
+            status: TransactionStatus::Success,
+            network: String::new(),
         },
         TransferEvent {
             transaction_id: "0xtx2_abcdef1234567890abcdef1234567890abcdef12".to_string(),
@@ -51,6 +55,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             timestamp: Utc::now().timestamp() as u64 + 1,
             block_number: 10000002,
             event_type: "transfer".to_string(),
+            gas_used: None,
+            status: TransactionStatus::Success,
+            network: String::new(),
         },
         TransferEvent {
             transaction_id: "0xtx3_567890abcdef1234567890abcdef1234567890abcd".to_string(),
@@ -63,6 +70,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             timestamp: Utc::now().timestamp() as u64 + 2,
             block_number: 10000003,
             event_type: "transfer".to_string(),
+            gas_used: None,
+            status: TransactionStatus::Success,
+            network: String::new(),
         },
     ];
     
@@ -76,14 +86,14 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         match processor.process_transfer_event(event.clone()).await {
             Ok(processed_tx) => {
                 println!("      ✅ 交易处理成功");
-                println!("      💰 发送方余额变化: {} SUI", processed_tx.sender_balance_change as f64 / 1_000_000_000.0);
-                println!("      💰 接收方余额变化: +{} SUI", processed_tx.receiver_balance_change as f64 / 1_000_000_000.0);
+                println!("      💰 发送方余额变化: {} SUI", mist_to_sui(processed_tx.sender_balance_change, SUI_DECIMALS));
+                println!("      💰 接收方余额变化: +{} SUI", mist_to_sui(processed_tx.receiver_balance_change, SUI_DECIMALS));
                 println!("      ⏱️  处理时间: {}ms", processed_tx.processing_time_ms);
                 
                 // 检查警报 (简化版)
                 if event.amount > 2000000000 {
                     println!("      🚨 触发警报: 大额转账检测");
-                    println!("         ⚠️  转账金额: {} SUI", event.amount as f64 / 1_000_000_000.0);
+                    println!("         ⚠️  转账金额: {} SUI", mist_to_sui(event.amount, SUI_DECIMALS));
                 }
                 
                 // 格式化并显示交易
@@ -109,17 +119,17 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("   📊 处理器统计:");
     println!("      📍 监控地址数: {}", processor_stats.total_addresses);
     println!("      📈 总交易数: {}", processor_stats.total_transactions);
-    println!("      💰 总交易量: {} SUI", processor_stats.total_volume as f64 / 1_000_000_000.0);
+    println!("      💰 总交易量: {} SUI", mist_to_sui(processor_stats.total_volume, SUI_DECIMALS));
     
     println!("\n   💳 地址余额:");
     for (address, balance) in balances {
-        println!("      🔑 {}: {} SUI", format!("{}...", &address[..16]), balance as f64 / 1_000_000_000.0);
+        println!("      🔑 {}: {} SUI", format!("{}...", &address[..16]), mist_to_sui(balance, SUI_DECIMALS));
         
         if let Some(addr_stats) = stats.get(&address) {
             println!("         📊 交易统计: {} 笔交易, 发送: {} SUI, 接收: {} SUI", 
                 addr_stats.total_transactions,
-                addr_stats.total_sent as f64 / 1_000_000_000.0,
-                addr_stats.total_received as f64 / 1_000_000_000.0);
+                mist_to_sui(addr_stats.total_sent, SUI_DECIMALS),
+                mist_to_sui(addr_stats.total_received, SUI_DECIMALS));
         }
     }
     
@@ -162,7 +172,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     
     for (amount, alert_type) in test_alerts {
         if amount > 1000000000 {
-            println!("   ⚠️  {} - 金额: {} SUI", alert_type, amount as f64 / 1_000_000_000.0);
+            println!("   ⚠️  {} - 金额: {} SUI", alert_type, mist_to_sui(amount, SUI_DECIMALS));
             println!("      📢 {}", formatter.format_warning("检测到异常交易"));
         } else {
             println!("   ✅ {} - 无警报", alert_type);
@@ -2,6 +2,7 @@ use anyhow::Result;
 use reqwest::Client;
 use serde_json::{json, Value};
 use std::collections::HashMap;
+use sui_token_transfer_tracker::units::{mist_to_sui, SUI_DECIMALS};
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -36,7 +37,7 @@ async fn main() -> Result<()> {
                 if let Some(total_balance) = result.get("totalBalance") {
                     if let Some(balance_str) = total_balance.as_str() {
                         if let Ok(balance) = balance_str.parse::<u64>() {
-                            let sui_amount = balance as f64 / 1_000_000_000.0;
+                            let sui_amount = mist_to_sui(balance, SUI_DECIMALS);
                             println!("💳 SUI 余额: {:.9} SUI ({} MIST)", sui_amount, balance);
                         }
                     }
@@ -77,7 +78,7 @@ async fn main() -> Result<()> {
                             if let Some(balance_str) = total_balance.as_str() {
                                 if let Ok(balance_num) = balance_str.parse::<u64>() {
                                     let formatted_balance = if coin_type.as_str() == Some("0x2::sui::SUI") {
-                                        format!("{:.9} SUI", balance_num as f64 / 1_000_000_000.0)
+                                        format!("{:.9} SUI", mist_to_sui(balance_num, SUI_DECIMALS))
                                     } else {
                                         format!("{} units", balance_num)
                                     };
@@ -179,7 +180,7 @@ async fn main() -> Result<()> {
                                             ) {
                                                 if let Some(amount_str) = amount.as_str() {
                                                     if let Ok(amount_num) = amount_str.parse::<i64>() {
-                                                        let sui_amount = amount_num as f64 / 1_000_000_000.0;
+                                                        let sui_amount = mist_to_sui(amount_num, SUI_DECIMALS);
                                                         let sign = if amount_num > 0 { "+" } else { "" };
                                                         println!("   💰 余额变化: {}{:.9} SUI ({})", sign, sui_amount, owner);
                                                         println!("      🪙 代币: {}", coin_type);
@@ -254,7 +255,7 @@ async fn main() -> Result<()> {
                                                     if let Some(amount_str) = amount.as_str() {
                                                         if let Ok(amount_num) = amount_str.parse::<i64>() {
                                                             if amount_num > 0 {
-                                                                let sui_amount = amount_num as f64 / 1_000_000_000.0;
+                                                                let sui_amount = mist_to_sui(amount_num, SUI_DECIMALS);
                                                                 println!("   💰 接收: +{:.9} SUI", sui_amount);
                                                             }
                                                         }
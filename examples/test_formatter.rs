@@ -33,6 +33,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         gas_used: Some(1000000),
         gas_price: Some(1000),
         status: TransactionStatus::Success,
+        network: String::new(),
     };
     
     let formatted_transaction = formatter.format_transaction(&transaction);
@@ -1,22 +1,41 @@
-use tokio::sync::mpsc;
-use std::collections::HashMap;
-use std::fs::OpenOptions;
+use tokio::sync::{mpsc, RwLock};
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::io::Write;
-use crate::transaction_processor::Transaction;
+use crate::transaction_processor::{Transaction, TransactionStatus};
 use crate::error::{TrackerError, TrackerResult};
+use crate::rotating_file::RotatingFile;
+use crate::units::{mist_to_sui, SUI_DECIMALS};
 use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc};
 
+/// 外部通知（Slack 等）HTTP 请求超时
+const WEBHOOK_TIMEOUT_SECONDS: u64 = 10;
+
+const TELEGRAM_API_BASE: &str = "https://api.telegram.org";
+
+/// 警报通道默认容量
+pub const DEFAULT_ALERT_CHANNEL_CAPACITY: usize = 100;
+
+pub const DEFAULT_WEBHOOK_TEMPLATE: &str =
+    r#"{"severity": "{severity}", "type": "{type}", "message": "{message}", "timestamp": "{timestamp}"}"#;
+
 #[derive(Debug, Clone)]
 pub struct AlertSystem {
-    thresholds: HashMap<String, u64>,
-    large_transfer_threshold: u64,
-    #[allow(dead_code)]
-    alert_sender: mpsc::UnboundedSender<Alert>,
-    #[allow(dead_code)]
-    alert_history: Vec<Alert>,
+    thresholds: Arc<RwLock<HashMap<String, u64>>>,
+    low_balance_threshold: Arc<RwLock<u64>>,
+    large_transfer_threshold: Arc<RwLock<u64>>,
+    alert_sender: mpsc::Sender<Alert>,
+    /// 因警报通道已满而丢弃的警报数
+    dropped_alerts: Arc<AtomicU64>,
+    /// 保留窗口内的警报，按时间从旧到新排列
+    alert_history: Arc<RwLock<VecDeque<Alert>>>,
+    /// 警报文件，首次写入时打开，按大小轮转
+    alert_file: Arc<std::sync::Mutex<Option<RotatingFile>>>,
     config: AlertConfig,
     suspicious_activity_detector: SuspiciousActivityDetector,
+    http_client: reqwest::Client,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -26,12 +45,44 @@ pub struct AlertConfig {
     pub enable_console_alerts: bool,
     pub enable_file_alerts: bool,
     pub alert_file_path: String,
+    /// 警报文件超过该大小（MB）时轮转，0 表示不轮转
+    pub alert_file_max_size_mb: u32,
+    /// 保留的警报文件归档数（0 表示轮转时直接清空）
+    pub alert_file_rotate_count: u32,
     pub enable_email_alerts: bool,
     pub email_smtp_server: String,
     pub email_sender: String,
     pub email_recipients: Vec<String>,
     pub enable_discord_alerts: bool,
     pub discord_webhook_url: String,
+    pub enable_slack_alerts: bool,
+    pub slack_webhook_url: String,
+    pub enable_telegram_alerts: bool,
+    pub telegram_bot_token: String,
+    pub telegram_chat_id: String,
+    pub telegram_use_markdown: bool,
+    pub enable_webhook_alerts: bool,
+    pub webhook_url: String,
+    pub webhook_method: String,
+    pub webhook_content_type: String,
+    /// 支持 {severity}、{type}、{message}、{timestamp} 占位符
+    pub webhook_template: String,
+    /// 低于该级别的警报只输出到控制台和历史记录，不发送到文件及外部渠道
+    pub min_delivery_severity: AlertSeverity,
+    /// 控制台警报的输出样式
+    pub console_alert_style: ConsoleAlertStyle,
+    /// 回流金额与原转账金额的最大差值（MIST），用于容纳手续费等损耗
+    pub circular_transfer_tolerance: u64,
+    /// 资金在该时间窗口（秒）内回流才视为循环转账
+    pub circular_transfer_window_seconds: u64,
+    /// 超过该时长（小时）没有交易的地址再次发起转账时发出警报，0 表示关闭
+    pub dormancy_threshold_hours: u64,
+    /// 警报历史最多保留的条数
+    pub alert_history_max: usize,
+    /// 警报历史最长保留时间（小时），0 表示不按时间淘汰
+    pub alert_history_max_age_hours: u64,
+    /// 警报通道容量，满时丢弃新警报并计数
+    pub channel_capacity: usize,
     pub cooldown_period_seconds: u64,
 }
 
@@ -43,12 +94,33 @@ impl Default for AlertConfig {
             enable_console_alerts: true,
             enable_file_alerts: false,
             alert_file_path: "alerts.log".to_string(),
+            alert_file_max_size_mb: 10,
+            alert_file_rotate_count: 5,
             enable_email_alerts: false,
             email_smtp_server: String::new(),
             email_sender: String::new(),
             email_recipients: Vec::new(),
             enable_discord_alerts: false,
             discord_webhook_url: String::new(),
+            enable_slack_alerts: false,
+            slack_webhook_url: String::new(),
+            enable_telegram_alerts: false,
+            telegram_bot_token: String::new(),
+            telegram_chat_id: String::new(),
+            telegram_use_markdown: false,
+            enable_webhook_alerts: false,
+            webhook_url: String::new(),
+            webhook_method: "POST".to_string(),
+            webhook_content_type: "application/json".to_string(),
+            webhook_template: DEFAULT_WEBHOOK_TEMPLATE.to_string(),
+            min_delivery_severity: AlertSeverity::Info,
+            console_alert_style: ConsoleAlertStyle::Emoji,
+            circular_transfer_tolerance: 10_000_000, // 0.01 SUI
+            circular_transfer_window_seconds: 600,
+            dormancy_threshold_hours: 720,
+            alert_history_max: 1000,
+            alert_history_max_age_hours: 24,
+            channel_capacity: DEFAULT_ALERT_CHANNEL_CAPACITY,
             cooldown_period_seconds: 300, // 5分钟冷却时间
         }
     }
@@ -71,6 +143,9 @@ pub enum Alert {
         token_type: String,
         severity: AlertSeverity,
         timestamp: DateTime<Utc>,
+        /// 交易所在网络
+        #[serde(default)]
+        network: String,
     },
     SuspiciousActivity {
         address: String,
@@ -80,6 +155,9 @@ pub enum Alert {
         related_transactions: Vec<String>,
         severity: AlertSeverity,
         timestamp: DateTime<Utc>,
+        /// 交易所在网络
+        #[serde(default)]
+        network: String,
     },
     NetworkError {
         error: String,
@@ -102,11 +180,26 @@ pub enum Alert {
     },
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// 控制台警报样式：emoji 前缀、`[WARN]` 式纯文本前缀，或每条一行JSON
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConsoleAlertStyle {
+    #[default]
+    Emoji,
+    Plain,
+    Json,
+}
+
+/// 按严重程度从低到高排序，可直接比较
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum AlertSeverity {
+    #[serde(alias = "info")]
     Info,
+    #[serde(alias = "warning")]
     Warning,
+    #[serde(alias = "error")]
     Error,
+    #[serde(alias = "critical")]
     Critical,
 }
 
@@ -122,6 +215,21 @@ pub enum RiskLevel {
 pub struct SuspiciousActivityDetector {
     transaction_counts: HashMap<String, TransactionCount>,
     last_alert_times: HashMap<String, DateTime<Utc>>,
+    recent_transfers: Arc<RwLock<VecDeque<RecentTransfer>>>,
+}
+
+/// 循环转账检测保留的最近转账记录上限
+const MAX_RECENT_TRANSFERS: usize = 1000;
+
+/// 用于循环转账检测的最近转账
+#[derive(Debug, Clone)]
+struct RecentTransfer {
+    transaction_id: String,
+    sender: String,
+    recipient: String,
+    amount: u64,
+    timestamp: u64,
+    network: String,
 }
 
 #[derive(Debug, Clone)]
@@ -134,30 +242,59 @@ pub struct TransactionCount {
 }
 
 impl AlertSystem {
-    pub fn new() -> (Self, mpsc::UnboundedReceiver<Alert>) {
+    pub fn new() -> (Self, mpsc::Receiver<Alert>) {
         Self::with_config(AlertConfig::default())
     }
 
-    pub fn with_config(config: AlertConfig) -> (Self, mpsc::UnboundedReceiver<Alert>) {
-        let (alert_sender, alert_receiver) = mpsc::unbounded_channel();
+    pub fn with_config(config: AlertConfig) -> (Self, mpsc::Receiver<Alert>) {
+        let (alert_sender, alert_receiver) = mpsc::channel(config.channel_capacity.max(1));
         let system = Self {
-            thresholds: HashMap::new(),
-            large_transfer_threshold: config.large_transfer_threshold,
+            thresholds: Arc::new(RwLock::new(HashMap::new())),
+            low_balance_threshold: Arc::new(RwLock::new(config.low_balance_threshold)),
+            large_transfer_threshold: Arc::new(RwLock::new(config.large_transfer_threshold)),
             alert_sender,
-            alert_history: Vec::new(),
+            dropped_alerts: Arc::new(AtomicU64::new(0)),
+            alert_history: Arc::new(RwLock::new(VecDeque::new())),
+            alert_file: Arc::new(std::sync::Mutex::new(None)),
             config,
             suspicious_activity_detector: SuspiciousActivityDetector::new(),
+            http_client: reqwest::Client::builder()
+                .timeout(std::time::Duration::from_secs(WEBHOOK_TIMEOUT_SECONDS))
+                .build()
+                .unwrap_or_default(),
         };
         (system, alert_receiver)
     }
 
-    pub async fn set_threshold(&self, _address: String, _threshold: u64) {
-        // This method needs to be mutable or use interior mutability
-        log::warn!("Cannot set threshold on immutable AlertSystem");
+    pub async fn set_threshold(&self, address: String, threshold: u64) {
+        let mut thresholds = self.thresholds.write().await;
+        thresholds.insert(address, threshold);
+    }
+
+    pub async fn remove_threshold(&self, address: &str) {
+        let mut thresholds = self.thresholds.write().await;
+        thresholds.remove(address);
+    }
+
+    /// 当前的默认低余额阈值（新增地址时使用）
+    pub async fn low_balance_threshold(&self) -> u64 {
+        *self.low_balance_threshold.read().await
     }
 
+    pub async fn large_transfer_threshold(&self) -> u64 {
+        *self.large_transfer_threshold.read().await
+    }
+
+    /// 运行时更新默认阈值（用于配置热重载），已设置的单地址阈值不受影响
+    pub async fn update_default_thresholds(&self, low_balance_threshold: u64, large_transfer_threshold: u64) {
+        *self.low_balance_threshold.write().await = low_balance_threshold;
+        *self.large_transfer_threshold.write().await = large_transfer_threshold;
+    }
+
+    /// 余额低于该地址的阈值时发送警报，阈值为 0 的地址不检查
     pub async fn check_balance_alert(&self, address: &str, balance: u64) -> TrackerResult<()> {
-        if let Some(&threshold) = self.thresholds.get(address) {
+        let threshold = self.thresholds.read().await.get(address).copied();
+        if let Some(threshold) = threshold.filter(|threshold| *threshold > 0) {
             if balance < threshold {
                 let severity = if balance < threshold / 10 {
                     AlertSeverity::Critical
@@ -182,10 +319,11 @@ impl AlertSystem {
     }
 
     pub async fn check_large_transfer(&self, transaction: &Transaction) -> TrackerResult<()> {
-        if transaction.amount > self.large_transfer_threshold {
-            let severity = if transaction.amount > self.large_transfer_threshold * 10 {
+        let large_transfer_threshold = *self.large_transfer_threshold.read().await;
+        if transaction.amount > large_transfer_threshold {
+            let severity = if transaction.amount > large_transfer_threshold.saturating_mul(10) {
                 AlertSeverity::Critical
-            } else if transaction.amount > self.large_transfer_threshold * 5 {
+            } else if transaction.amount > large_transfer_threshold.saturating_mul(5) {
                 AlertSeverity::Error
             } else {
                 AlertSeverity::Warning
@@ -199,6 +337,7 @@ impl AlertSystem {
                 token_type: transaction.token_type.clone(),
                 severity,
                 timestamp: Utc::now(),
+                network: transaction.network.clone(),
             };
             
             self.send_alert(alert).await?;
@@ -222,6 +361,41 @@ impl AlertSystem {
         Ok(())
     }
 
+    /// 发送方在 `last_transaction` 之后休眠超过 `dormancy_threshold_hours` 又发起转账时发出警报
+    pub async fn check_dormant_reactivation(
+        &self,
+        transaction: &Transaction,
+        last_transaction: Option<u64>,
+    ) -> TrackerResult<()> {
+        let threshold_hours = self.config.dormancy_threshold_hours;
+        let Some(last_transaction) = last_transaction else {
+            return Ok(());
+        };
+        let dormant_seconds = transaction.timestamp.saturating_sub(last_transaction);
+        if threshold_hours == 0 || dormant_seconds <= threshold_hours.saturating_mul(3600) {
+            return Ok(());
+        }
+
+        let alert = Alert::SuspiciousActivity {
+            address: transaction.sender.clone(),
+            activity_type: "dormant_reactivation".to_string(),
+            description: format!(
+                "Address sent {} after being dormant for {} (last activity at {})",
+                Self::format_token_amount(transaction.amount, &transaction.token_type),
+                format_duration_estimate(std::time::Duration::from_secs(dormant_seconds)),
+                DateTime::from_timestamp(last_transaction as i64, 0)
+                    .map(|t| t.format("%Y-%m-%d %H:%M:%S UTC").to_string())
+                    .unwrap_or_else(|| last_transaction.to_string())
+            ),
+            risk_level: RiskLevel::High,
+            related_transactions: vec![transaction.id.clone()],
+            severity: AlertSeverity::Warning,
+            timestamp: Utc::now(),
+            network: transaction.network.clone(),
+        };
+        self.send_alert(alert).await
+    }
+
     pub async fn send_network_error_alert(&self, error: String, component: String) -> TrackerResult<()> {
         let alert = Alert::NetworkError {
             error,
@@ -242,6 +416,22 @@ impl AlertSystem {
         self.send_alert(alert).await
     }
 
+    /// 预计余额将在 time_to_depletion 后耗尽
+    pub async fn send_depletion_alert(&self, address: &str, time_to_depletion: std::time::Duration) -> TrackerResult<()> {
+        let alert = Alert::Custom {
+            title: format!("Projected depletion {}", address),
+            message: format!(
+                "Address {} projected to deplete in ~{}",
+                address,
+                format_duration_estimate(time_to_depletion)
+            ),
+            severity: AlertSeverity::Warning,
+            category: "depletion".to_string(),
+            timestamp: Utc::now(),
+        };
+        self.send_alert(alert).await
+    }
+
     pub async fn send_custom_alert(&self, title: String, message: String, category: String) -> TrackerResult<()> {
         let alert = Alert::Custom {
             title,
@@ -267,34 +457,107 @@ impl AlertSystem {
             self.send_console_alert(&alert).await;
         }
 
+        let delivery = if *alert.severity() >= self.config.min_delivery_severity {
+            self.deliver_alert(&alert).await
+        } else {
+            log::debug!("Alert {} is below min_delivery_severity, not delivered to sinks", alert_key);
+            Ok(())
+        };
+
+        // 记录发送时间
+        self.record_alert_time(alert_key.clone()).await;
+
+        // 添加到历史记录
+        self.add_to_history(alert.clone()).await;
+
+        // 发送到channel (用于测试和其他组件)
+        self.enqueue_alert(alert.clone());
+
+        log::info!("Alert sent: {}", alert_key);
+        delivery
+    }
+
+    /// 放入警报通道，不等待消费者
+    ///
+    /// 通道剩余空间不足四分之一时先丢弃 Error 以下的警报，为高严重程度的警报保留空间；
+    /// 完全满时丢弃所有新警报。被丢弃的警报已输出到控制台、各渠道和历史记录，只是不再进入通道。
+    fn enqueue_alert(&self, alert: Alert) {
+        let reserved = self.alert_sender.max_capacity() / 4;
+        if self.alert_sender.capacity() <= reserved && *alert.severity() < AlertSeverity::Error {
+            self.record_dropped_alert(&alert);
+            return;
+        }
+
+        match self.alert_sender.try_send(alert) {
+            Ok(()) => {}
+            Err(mpsc::error::TrySendError::Full(alert)) => self.record_dropped_alert(&alert),
+            Err(mpsc::error::TrySendError::Closed(_)) => {
+                log::warn!("Failed to send alert to channel, receiver may be dropped");
+            }
+        }
+    }
+
+    fn record_dropped_alert(&self, alert: &Alert) {
+        let dropped = self.dropped_alerts.fetch_add(1, Ordering::Relaxed) + 1;
+        log::warn!("Alert channel full, dropped {:?} alert ({} dropped so far)", alert.severity(), dropped);
+    }
+
+    /// 因警报通道已满而丢弃的警报数
+    pub fn dropped_alerts(&self) -> u64 {
+        self.dropped_alerts.load(Ordering::Relaxed)
+    }
+
+    /// 警报通道中等待处理的警报数
+    pub fn queued_alerts(&self) -> usize {
+        self.alert_sender.max_capacity() - self.alert_sender.capacity()
+    }
+
+    /// 发送到文件及外部渠道
+    ///
+    /// 各渠道相互独立，某个渠道失败不影响其余渠道；全部尝试后若有失败，返回第一个错误。
+    async fn deliver_alert(&self, alert: &Alert) -> TrackerResult<()> {
+        let mut first_error = None;
+        let mut record = |sink: &str, result: TrackerResult<()>| {
+            if let Err(e) = result {
+                log::error!("Failed to deliver alert to {}: {}", sink, e);
+                first_error.get_or_insert(e);
+            }
+        };
+
         // 发送到文件
         if self.config.enable_file_alerts {
-            self.send_file_alert(&alert).await?;
+            record("file", self.send_file_alert(alert).await);
         }
 
         // 发送到邮件
         if self.config.enable_email_alerts {
-            self.send_email_alert(&alert).await?;
+            record("email", self.send_email_alert(alert).await);
         }
 
         // 发送到Discord
         if self.config.enable_discord_alerts {
-            self.send_discord_alert(&alert).await?;
+            record("discord", self.send_discord_alert(alert).await);
         }
 
-        // 记录发送时间
-        self.record_alert_time(alert_key.clone()).await;
+        // 发送到Slack
+        if self.config.enable_slack_alerts {
+            record("slack", self.send_slack_alert(alert).await);
+        }
 
-        // 添加到历史记录
-        self.add_to_history(alert.clone()).await;
+        // 发送到Telegram
+        if self.config.enable_telegram_alerts {
+            record("telegram", self.send_telegram_alert(alert).await);
+        }
 
-        // 发送到channel (用于测试和其他组件)
-        if let Err(_) = self.alert_sender.send(alert.clone()) {
-            log::warn!("Failed to send alert to channel, receiver may be dropped");
+        // 发送到自定义Webhook
+        if self.config.enable_webhook_alerts {
+            record("webhook", self.send_webhook_alert(alert).await);
         }
 
-        log::info!("Alert sent: {}", alert_key);
-        Ok(())
+        match first_error {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
     }
 
     fn get_alert_key(&self, alert: &Alert) -> String {
@@ -327,12 +590,35 @@ impl AlertSystem {
     }
 
     async fn send_console_alert(&self, alert: &Alert) {
-        let message = self.format_alert_message(alert);
+        let line = self.format_console_alert(alert);
         match alert.severity() {
-            AlertSeverity::Info => println!("{}", message),
-            AlertSeverity::Warning => eprintln!("⚠️  {}", message),
-            AlertSeverity::Error => eprintln!("❌ {}", message),
-            AlertSeverity::Critical => eprintln!("🚨 {}", message),
+            AlertSeverity::Info => println!("{}", line),
+            _ => eprintln!("{}", line),
+        }
+    }
+
+    /// 按 `console_alert_style` 生成控制台输出的一行
+    fn format_console_alert(&self, alert: &Alert) -> String {
+        match self.config.console_alert_style {
+            ConsoleAlertStyle::Json => crate::output_formatter::OutputFormatter::new(false, true).format_alert_json(alert),
+            ConsoleAlertStyle::Plain => {
+                let prefix = match alert.severity() {
+                    AlertSeverity::Info => "[INFO]",
+                    AlertSeverity::Warning => "[WARN]",
+                    AlertSeverity::Error => "[ERROR]",
+                    AlertSeverity::Critical => "[CRIT]",
+                };
+                format!("{} {}", prefix, self.format_alert_message(alert))
+            }
+            ConsoleAlertStyle::Emoji => {
+                let message = self.format_alert_message(alert);
+                match alert.severity() {
+                    AlertSeverity::Info => message,
+                    AlertSeverity::Warning => format!("⚠️  {}", message),
+                    AlertSeverity::Error => format!("❌ {}", message),
+                    AlertSeverity::Critical => format!("🚨 {}", message),
+                }
+            }
         }
     }
 
@@ -340,14 +626,19 @@ impl AlertSystem {
         let message = self.format_alert_message(alert);
         let timestamp = Utc::now().format("%Y-%m-%d %H:%M:%S UTC");
         
-        let mut file = OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(&self.config.alert_file_path)
-            .map_err(|e| TrackerError::IoError(e))?;
+        let mut alert_file = self.alert_file.lock().unwrap();
+        let file = match &mut *alert_file {
+            Some(file) => file,
+            slot @ None => slot.insert(RotatingFile::open_mb(
+                &self.config.alert_file_path,
+                self.config.alert_file_max_size_mb,
+                self.config.alert_file_rotate_count,
+            )?),
+        };
 
-        writeln!(file, "[{}] {}", timestamp, message)
-            .map_err(|e| TrackerError::IoError(e))?;
+        // 整行一次写入，轮转不会把一条警报拆到两个文件
+        file.write_all(format!("[{}] {}\n", timestamp, message).as_bytes())?;
+        file.flush()?;
 
         Ok(())
     }
@@ -371,6 +662,117 @@ impl AlertSystem {
         Ok(())
     }
 
+    async fn send_slack_alert(&self, alert: &Alert) -> TrackerResult<()> {
+        if self.config.slack_webhook_url.is_empty() {
+            return Ok(());
+        }
+
+        let payload = self.format_slack_message(alert);
+        let response = self.http_client
+            .post(&self.config.slack_webhook_url)
+            .json(&payload)
+            .send()
+            .await
+            .map_err(|e| TrackerError::network_error(format!("Slack webhook request failed: {}", e.without_url())))?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            return Err(TrackerError::network_error(format!(
+                "Slack webhook returned {}: {}", status, body
+            )));
+        }
+
+        log::debug!("Slack alert sent");
+        Ok(())
+    }
+
+    async fn send_telegram_alert(&self, alert: &Alert) -> TrackerResult<()> {
+        if self.config.telegram_bot_token.is_empty() || self.config.telegram_chat_id.is_empty() {
+            return Ok(());
+        }
+
+        let url = format!("{}/bot{}/sendMessage", TELEGRAM_API_BASE, self.config.telegram_bot_token);
+        let payload = self.format_telegram_message(alert);
+
+        // 不在错误信息中带上URL，避免泄露bot token
+        let response = self.http_client
+            .post(&url)
+            .json(&payload)
+            .send()
+            .await
+            .map_err(|e| TrackerError::network_error(format!("Telegram request failed: {}", e.without_url())))?;
+
+        let status = response.status();
+        let body: serde_json::Value = response.json().await.unwrap_or_default();
+        if !status.is_success() || body["ok"] != serde_json::Value::Bool(true) {
+            let description = body["description"].as_str().unwrap_or("unknown error");
+            return Err(TrackerError::network_error(format!(
+                "Telegram API error ({}): {}", status, description
+            )));
+        }
+
+        log::debug!("Telegram alert sent");
+        Ok(())
+    }
+
+    async fn send_webhook_alert(&self, alert: &Alert) -> TrackerResult<()> {
+        if self.config.webhook_url.is_empty() {
+            return Ok(());
+        }
+
+        let method = reqwest::Method::from_bytes(self.config.webhook_method.to_uppercase().as_bytes())
+            .map_err(|_| TrackerError::config_error(format!("Invalid webhook method: {}", self.config.webhook_method)))?;
+        let body = self.render_webhook_template(alert);
+
+        let response = self.http_client
+            .request(method, &self.config.webhook_url)
+            .header(reqwest::header::CONTENT_TYPE, &self.config.webhook_content_type)
+            .body(body)
+            .send()
+            .await
+            .map_err(|e| TrackerError::network_error(format!("Webhook request failed: {}", e.without_url())))?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            return Err(TrackerError::network_error(format!(
+                "Webhook returned {}: {}", status, body
+            )));
+        }
+
+        log::debug!("Webhook alert sent");
+        Ok(())
+    }
+
+    /// 渲染Webhook模板；JSON内容类型下对替换值做JSON字符串转义
+    fn render_webhook_template(&self, alert: &Alert) -> String {
+        let is_json = self.config.webhook_content_type.contains("json");
+        let escape = |value: &str| -> String {
+            if is_json {
+                let quoted = serde_json::to_string(value).unwrap_or_default();
+                quoted[1..quoted.len() - 1].to_string()
+            } else {
+                value.to_string()
+            }
+        };
+
+        self.config.webhook_template
+            .replace("{severity}", &escape(&self.severity_to_string(alert.severity())))
+            .replace("{type}", &escape(alert.alert_type()))
+            .replace("{timestamp}", &escape(&alert.timestamp().to_rfc3339()))
+            .replace("{message}", &escape(&self.format_alert_message(alert)))
+    }
+
+    /// 警报来自具名网络时附加的说明
+    fn network_suffix(network: &str) -> String {
+        if network.is_empty() {
+            String::new()
+        } else {
+            format!(" [network: {}]", network)
+        }
+    }
+
     fn format_alert_message(&self, alert: &Alert) -> String {
         match alert {
             Alert::LowBalance { address, balance, threshold, severity, .. } => {
@@ -380,21 +782,23 @@ impl AlertSystem {
                     self.format_amount(*balance), 
                     self.format_amount(*threshold))
             },
-            Alert::LargeTransfer { sender, recipient, amount, token_type, severity, .. } => {
-                format!("ALERT [{}]: Large transfer: {} → {} | Amount: {} {}", 
+            Alert::LargeTransfer { sender, recipient, amount, token_type, severity, network, .. } => {
+                format!("ALERT [{}]: Large transfer: {} → {} | Amount: {} {}{}", 
                     self.severity_to_string(severity),
                     self.truncate_address(sender), 
                     self.truncate_address(recipient), 
                     self.format_amount(*amount), 
-                    token_type)
+                    token_type,
+                    Self::network_suffix(network))
             },
-            Alert::SuspiciousActivity { address, activity_type, description, risk_level, severity, .. } => {
-                format!("ALERT [{}]: Suspicious activity detected for {}: {} - {} (Risk: {})", 
+            Alert::SuspiciousActivity { address, activity_type, description, risk_level, severity, network, .. } => {
+                format!("ALERT [{}]: Suspicious activity detected for {}: {} - {} (Risk: {}){}", 
                     self.severity_to_string(severity),
                     self.truncate_address(address), 
                     activity_type, 
                     description, 
-                    self.risk_level_to_string(risk_level))
+                    self.risk_level_to_string(risk_level),
+                    Self::network_suffix(network))
             },
             Alert::NetworkError { error, component, severity, .. } => {
                 format!("ALERT [{}]: Network error in {}: {}", 
@@ -432,6 +836,40 @@ impl AlertSystem {
         )
     }
 
+    fn format_slack_message(&self, alert: &Alert) -> serde_json::Value {
+        let color = match alert.severity() {
+            AlertSeverity::Info => "#3498db",
+            AlertSeverity::Warning => "#f39c12",
+            AlertSeverity::Error => "#e74c3c",
+            AlertSeverity::Critical => "#8b0000",
+        };
+
+        serde_json::json!({
+            "attachments": [{
+                "color": color,
+                "title": "SUI Tracker Alert",
+                "text": self.format_alert_message(alert),
+                "ts": alert.timestamp().timestamp(),
+            }]
+        })
+    }
+
+    fn format_telegram_message(&self, alert: &Alert) -> serde_json::Value {
+        let mut payload = serde_json::json!({
+            "chat_id": self.config.telegram_chat_id,
+            "disable_web_page_preview": true,
+        });
+
+        if self.config.telegram_use_markdown {
+            payload["text"] = serde_json::json!(format!("*SUI Tracker Alert*\n{}", self.format_alert_message(alert)));
+            payload["parse_mode"] = serde_json::json!("Markdown");
+        } else {
+            payload["text"] = serde_json::json!(format!("SUI Tracker Alert\n{}", self.format_alert_message(alert)));
+        }
+
+        payload
+    }
+
     fn severity_to_string(&self, severity: &AlertSeverity) -> String {
         match severity {
             AlertSeverity::Info => "INFO".to_string(),
@@ -451,7 +889,17 @@ impl AlertSystem {
     }
 
     fn format_amount(&self, amount: u64) -> String {
-        format!("{:.9} SUI", amount as f64 / 1_000_000_000.0)
+        format!("{:.9} SUI", mist_to_sui(amount, SUI_DECIMALS))
+    }
+
+    /// 按交易的代币类型显示金额：SUI 按 9 位小数换算，其他代币的小数位数未知，按最小单位显示并附代币类型
+    fn format_token_amount(amount: u64, token_type: &str) -> String {
+        let sui = crate::config::Config::normalize_coin_type("0x2::sui::SUI");
+        if crate::config::Config::normalize_coin_type(token_type) == sui {
+            format!("{:.9} SUI", mist_to_sui(amount, SUI_DECIMALS))
+        } else {
+            format!("{} {}", amount, token_type)
+        }
     }
 
     fn truncate_address(&self, address: &str) -> String {
@@ -462,26 +910,63 @@ impl AlertSystem {
         }
     }
 
+    /// 加入历史记录，同时淘汰超出条数上限或保留时间的旧警报
     async fn add_to_history(&self, alert: Alert) {
-        // 在实际应用中，你可能需要线程安全的历史记录
-        // 这里简化处理
-        log::debug!("Alert added to history: {:?}", alert);
+        let mut history = self.alert_history.write().await;
+        history.push_back(alert);
+
+        if let Some(cutoff) = self.history_cutoff() {
+            while history.front().is_some_and(|oldest| *oldest.timestamp() < cutoff) {
+                history.pop_front();
+            }
+        }
+        while history.len() > self.config.alert_history_max {
+            history.pop_front();
+        }
     }
 
-    pub async fn get_alert_history(&self, _limit: usize) -> Vec<Alert> {
-        // 简化版本，返回最近的一些警报
-        Vec::new()
+    /// 早于该时间的警报已超出保留窗口
+    fn history_cutoff(&self) -> Option<DateTime<Utc>> {
+        if self.config.alert_history_max_age_hours == 0 {
+            return None;
+        }
+        Some(Utc::now() - chrono::Duration::hours(self.config.alert_history_max_age_hours as i64))
     }
 
+    /// 最近的 `limit` 条警报，按时间从旧到新排列
+    pub async fn get_alert_history(&self, limit: usize) -> Vec<Alert> {
+        let history = self.alert_history.read().await;
+        let cutoff = self.history_cutoff();
+        let retained: Vec<&Alert> = history
+            .iter()
+            .filter(|alert| cutoff.is_none_or(|cutoff| *alert.timestamp() >= cutoff))
+            .collect();
+        retained[retained.len().saturating_sub(limit)..]
+            .iter()
+            .map(|alert| (*alert).clone())
+            .collect()
+    }
+
+    /// 警报统计，仅覆盖保留窗口内的警报（受 `alert_history_max` 和 `alert_history_max_age_hours` 限制），不是全部历史
     pub async fn get_alert_stats(&self) -> AlertStats {
-        AlertStats {
+        let history = self.alert_history.read().await;
+        let cutoff = self.history_cutoff();
+        let mut stats = AlertStats {
             total_alerts: 0,
             alerts_by_type: HashMap::new(),
             alerts_by_severity: HashMap::new(),
+        };
+
+        for alert in history.iter().filter(|alert| cutoff.is_none_or(|cutoff| *alert.timestamp() >= cutoff)) {
+            stats.total_alerts += 1;
+            *stats.alerts_by_type.entry(alert.alert_type().to_string()).or_insert(0) += 1;
+            *stats.alerts_by_severity.entry(self.severity_to_string(alert.severity())).or_insert(0) += 1;
         }
+        stats
     }
 }
 
+/// 保留窗口内的警报统计
 #[derive(Debug, Clone)]
 pub struct AlertStats {
     pub total_alerts: usize,
@@ -490,6 +975,17 @@ pub struct AlertStats {
 }
 
 impl Alert {
+    pub fn alert_type(&self) -> &'static str {
+        match self {
+            Alert::LowBalance { .. } => "low_balance",
+            Alert::LargeTransfer { .. } => "large_transfer",
+            Alert::SuspiciousActivity { .. } => "suspicious_activity",
+            Alert::NetworkError { .. } => "network_error",
+            Alert::SystemError { .. } => "system_error",
+            Alert::Custom { .. } => "custom",
+        }
+    }
+
     pub fn severity(&self) -> &AlertSeverity {
         match self {
             Alert::LowBalance { severity, .. } => severity,
@@ -513,11 +1009,24 @@ impl Alert {
     }
 }
 
+/// 粗略的剩余时间描述：不足一小时按分钟，不足两天按小时，否则按天
+fn format_duration_estimate(duration: std::time::Duration) -> String {
+    let minutes = duration.as_secs() / 60;
+    if minutes < 60 {
+        format!("{}m", minutes)
+    } else if minutes < 48 * 60 {
+        format!("{}h", (minutes + 30) / 60)
+    } else {
+        format!("{}d", (minutes + 12 * 60) / (24 * 60))
+    }
+}
+
 impl SuspiciousActivityDetector {
     pub fn new() -> Self {
         Self {
             transaction_counts: HashMap::new(),
             last_alert_times: HashMap::new(),
+            recent_transfers: Arc::new(RwLock::new(VecDeque::new())),
         }
     }
 
@@ -527,6 +1036,11 @@ impl SuspiciousActivityDetector {
         current_time: DateTime<Utc>,
         _config: &AlertConfig,
     ) -> Option<Alert> {
+        // 检查循环转账（先于其他规则执行，保证每笔交易都被记录）
+        if let Some(alert) = self.check_circular_transfer(_transaction, current_time, _config).await {
+            return Some(alert);
+        }
+
         // 检查高频交易
         if let Some(alert) = self.check_high_frequency_transactions(_transaction, current_time, _config).await {
             return Some(alert);
@@ -545,6 +1059,70 @@ impl SuspiciousActivityDetector {
         None
     }
 
+    /// 检测 A→B 后短时间内 B→A 回流近似金额的循环转账
+    async fn check_circular_transfer(
+        &self,
+        transaction: &Transaction,
+        current_time: DateTime<Utc>,
+        config: &AlertConfig,
+    ) -> Option<Alert> {
+        // 失败交易和自转账不涉及资金往来
+        if transaction.status == TransactionStatus::Failed || transaction.sender == transaction.recipient {
+            return None;
+        }
+
+        let mut recent = self.recent_transfers.write().await;
+
+        // 清理窗口外的记录
+        let window_start = transaction.timestamp.saturating_sub(config.circular_transfer_window_seconds);
+        recent.retain(|t| t.timestamp >= window_start);
+
+        let matched = recent.iter().position(|t| {
+            t.network == transaction.network
+                && t.sender == transaction.recipient
+                && t.recipient == transaction.sender
+                && t.amount.abs_diff(transaction.amount) <= config.circular_transfer_tolerance
+        });
+
+        let alert = matched.and_then(|index| recent.remove(index)).map(|original| {
+            Alert::SuspiciousActivity {
+                address: original.sender.clone(),
+                activity_type: "circular_transfer".to_string(),
+                description: format!(
+                    "Funds cycled {} -> {} -> {} within {}s ({:.9} SUI out, {:.9} SUI back)",
+                    original.sender,
+                    original.recipient,
+                    transaction.recipient,
+                    transaction.timestamp.saturating_sub(original.timestamp),
+                    mist_to_sui(original.amount, SUI_DECIMALS),
+                    mist_to_sui(transaction.amount, SUI_DECIMALS)
+                ),
+                risk_level: RiskLevel::High,
+                related_transactions: vec![original.transaction_id, transaction.id.clone()],
+                severity: AlertSeverity::Error,
+                timestamp: current_time,
+                network: transaction.network.clone(),
+            }
+        });
+
+        // 已匹配的回流交易不再作为新的起点
+        if alert.is_none() {
+            if recent.len() >= MAX_RECENT_TRANSFERS {
+                recent.pop_front();
+            }
+            recent.push_back(RecentTransfer {
+                transaction_id: transaction.id.clone(),
+                sender: transaction.sender.clone(),
+                recipient: transaction.recipient.clone(),
+                amount: transaction.amount,
+                timestamp: transaction.timestamp,
+                network: transaction.network.clone(),
+            });
+        }
+
+        alert
+    }
+
     async fn check_high_frequency_transactions(
         &self,
         _transaction: &Transaction,
@@ -565,6 +1143,7 @@ impl SuspiciousActivityDetector {
                 related_transactions: vec![_transaction.id.clone()],
                 severity: AlertSeverity::Warning,
                 timestamp: current_time,
+                network: _transaction.network.clone(),
             })
         } else {
             None
@@ -587,6 +1166,7 @@ impl SuspiciousActivityDetector {
                 related_transactions: vec![_transaction.id.clone()],
                 severity: AlertSeverity::Error,
                 timestamp: Utc::now(),
+                network: _transaction.network.clone(),
             })
         } else {
             None
@@ -659,6 +1239,7 @@ mod tests {
             gas_used: None,
             gas_price: None,
             status: crate::transaction_processor::TransactionStatus::Success,
+            network: String::new(),
         };
         
         alert_system.check_large_transfer(&transaction).await.unwrap();
@@ -706,4 +1287,350 @@ mod tests {
         assert!(message.contains("0.500000000 SUI"));
         assert!(message.contains("1.000000000 SUI"));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_slack_message_format() {
+        let (alert_system, _receiver) = AlertSystem::new();
+
+        let alert = Alert::Custom {
+            title: "Node \"lagging\"".to_string(),
+            message: "checkpoint behind".to_string(),
+            severity: AlertSeverity::Critical,
+            category: "ops".to_string(),
+            timestamp: Utc::now(),
+        };
+
+        let payload = alert_system.format_slack_message(&alert);
+        let attachment = &payload["attachments"][0];
+        assert_eq!(attachment["color"], "#8b0000");
+        assert_eq!(attachment["text"], alert_system.format_alert_message(&alert));
+    }
+
+    #[test]
+    fn test_telegram_message_format() {
+        let (alert_system, _receiver) = AlertSystem::with_config(AlertConfig {
+            telegram_chat_id: "-1001234567890".to_string(),
+            telegram_use_markdown: true,
+            ..Default::default()
+        });
+
+        let alert = Alert::NetworkError {
+            error: "timeout".to_string(),
+            component: "rpc".to_string(),
+            severity: AlertSeverity::Error,
+            timestamp: Utc::now(),
+        };
+
+        let payload = alert_system.format_telegram_message(&alert);
+        assert_eq!(payload["chat_id"], "-1001234567890");
+        assert_eq!(payload["parse_mode"], "Markdown");
+        assert!(payload["text"].as_str().unwrap().contains("Network error in rpc: timeout"));
+    }
+
+    #[test]
+    fn test_alert_channel_overflow() {
+        let (alert_system, mut receiver) = AlertSystem::with_config(AlertConfig {
+            channel_capacity: 4,
+            ..Default::default()
+        });
+        let alert = |severity: AlertSeverity| Alert::Custom {
+            title: "overflow".to_string(),
+            message: "test".to_string(),
+            severity,
+            category: "test".to_string(),
+            timestamp: Utc::now(),
+        };
+
+        for _ in 0..3 {
+            alert_system.enqueue_alert(alert(AlertSeverity::Info));
+        }
+        // 剩余空间为保留部分时低严重程度的警报被丢弃，高严重程度的仍可进入
+        alert_system.enqueue_alert(alert(AlertSeverity::Warning));
+        alert_system.enqueue_alert(alert(AlertSeverity::Critical));
+        assert_eq!(alert_system.queued_alerts(), 4);
+        assert_eq!(alert_system.dropped_alerts(), 1);
+
+        // 通道满时任何警报都被丢弃
+        alert_system.enqueue_alert(alert(AlertSeverity::Critical));
+        assert_eq!(alert_system.dropped_alerts(), 2);
+
+        receiver.try_recv().unwrap();
+        assert_eq!(alert_system.queued_alerts(), 3);
+    }
+
+    #[test]
+    fn test_severity_ordering() {
+        assert!(AlertSeverity::Info < AlertSeverity::Warning);
+        assert!(AlertSeverity::Warning < AlertSeverity::Error);
+        assert!(AlertSeverity::Error < AlertSeverity::Critical);
+    }
+
+    #[tokio::test]
+    async fn test_min_delivery_severity() {
+        let alert_file = std::env::temp_dir().join(format!("tracker_alerts_{}.log", std::process::id()));
+        let _ = std::fs::remove_file(&alert_file);
+
+        let (alert_system, mut receiver) = AlertSystem::with_config(AlertConfig {
+            enable_console_alerts: false,
+            enable_file_alerts: true,
+            alert_file_path: alert_file.to_string_lossy().to_string(),
+            min_delivery_severity: AlertSeverity::Warning,
+            ..Default::default()
+        });
+
+        // Info 警报仍然被记录，但不写入文件
+        alert_system.send_custom_alert("note".to_string(), "hello".to_string(), "test".to_string()).await.unwrap();
+        let alert = receiver.recv().await.unwrap();
+        assert!(matches!(alert.severity(), AlertSeverity::Info));
+        assert!(!alert_file.exists());
+
+        alert_system.send_system_error_alert("boom".to_string(), "test".to_string()).await.unwrap();
+        assert!(receiver.recv().await.is_some());
+        let contents = std::fs::read_to_string(&alert_file).unwrap();
+        std::fs::remove_file(&alert_file).unwrap();
+        assert!(contents.contains("System error in test: boom"));
+        assert!(!contents.contains("hello"));
+    }
+
+    #[tokio::test]
+    async fn test_failed_sink_still_records_alert() {
+        let missing_dir = std::env::temp_dir().join(format!("tracker_missing_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&missing_dir);
+
+        let (alert_system, mut receiver) = AlertSystem::with_config(AlertConfig {
+            enable_console_alerts: false,
+            enable_file_alerts: true,
+            alert_file_path: missing_dir.join("alerts.log").to_string_lossy().to_string(),
+            ..Default::default()
+        });
+
+        // 文件渠道失败时返回错误，但警报仍进入历史记录和通道
+        let result = alert_system.send_system_error_alert("boom".to_string(), "test".to_string()).await;
+        assert!(result.is_err());
+        assert!(receiver.recv().await.is_some());
+        assert_eq!(alert_system.get_alert_history(10).await.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_alert_file_rotation() {
+        let dir = std::env::temp_dir().join(format!("tracker_alert_rotation_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let alert_file = dir.join("alerts.log");
+        // 旧文件中的内容保留，新警报继续追加
+        std::fs::write(&alert_file, "x".repeat(1024 * 1024)).unwrap();
+
+        let (alert_system, _receiver) = AlertSystem::with_config(AlertConfig {
+            enable_console_alerts: false,
+            enable_file_alerts: true,
+            alert_file_path: alert_file.to_string_lossy().to_string(),
+            alert_file_max_size_mb: 1,
+            alert_file_rotate_count: 1,
+            ..Default::default()
+        });
+
+        alert_system.send_system_error_alert("first".to_string(), "test".to_string()).await.unwrap();
+        alert_system.send_system_error_alert("second".to_string(), "test".to_string()).await.unwrap();
+
+        let archived = std::fs::read_to_string(dir.join("alerts.log.1")).unwrap();
+        assert_eq!(archived.len(), 1024 * 1024);
+        let contents = std::fs::read_to_string(&alert_file).unwrap();
+        assert!(contents.contains("System error in test: first"));
+        assert!(contents.contains("System error in test: second"));
+        assert!(!dir.join("alerts.log.2").exists());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_console_alert_style() {
+        let alert = Alert::SystemError {
+            error: "boom".to_string(),
+            component: "rpc".to_string(),
+            severity: AlertSeverity::Warning,
+            timestamp: Utc::now(),
+        };
+        let system = |style| AlertSystem::with_config(AlertConfig { console_alert_style: style, ..Default::default() }).0;
+
+        let line = system(ConsoleAlertStyle::Emoji).format_console_alert(&alert);
+        assert!(line.starts_with("⚠️  "));
+
+        let line = system(ConsoleAlertStyle::Plain).format_console_alert(&alert);
+        assert_eq!(line, format!("[WARN] {}", system(ConsoleAlertStyle::Plain).format_alert_message(&alert)));
+
+        let line = system(ConsoleAlertStyle::Json).format_console_alert(&alert);
+        let parsed: serde_json::Value = serde_json::from_str(&line).unwrap();
+        assert_eq!(parsed["type"], "system_error");
+        assert_eq!(parsed["severity"], "warning");
+        assert!(!line.contains('\n'));
+        assert!(!line.contains('\x1b'));
+    }
+
+    #[test]
+    fn test_webhook_template_rendering() {
+        let (alert_system, _receiver) = AlertSystem::new();
+
+        let alert = Alert::SystemError {
+            error: "disk \"full\"".to_string(),
+            component: "storage".to_string(),
+            severity: AlertSeverity::Critical,
+            timestamp: Utc::now(),
+        };
+
+        // 默认模板渲染后是合法JSON，消息中的引号被转义
+        let body = alert_system.render_webhook_template(&alert);
+        let parsed: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(parsed["severity"], "CRITICAL");
+        assert_eq!(parsed["type"], "system_error");
+        assert_eq!(parsed["message"], alert_system.format_alert_message(&alert));
+
+        let (alert_system, _receiver) = AlertSystem::with_config(AlertConfig {
+            webhook_content_type: "text/plain".to_string(),
+            webhook_template: "[{severity}] {message}".to_string(),
+            ..Default::default()
+        });
+        let body = alert_system.render_webhook_template(&alert);
+        assert!(body.starts_with("[CRITICAL] "));
+        assert!(body.contains("disk \"full\""));
+    }
+
+    #[tokio::test]
+    async fn test_circular_transfer_detection() {
+        let (alert_system, mut receiver) = AlertSystem::with_config(AlertConfig {
+            enable_console_alerts: false,
+            ..Default::default()
+        });
+
+        let transfer = |id: &str, sender: &str, recipient: &str, amount: u64, timestamp: u64| Transaction {
+            id: id.to_string(),
+            sender: sender.to_string(),
+            recipient: recipient.to_string(),
+            amount,
+            token_type: "0x2::sui::SUI".to_string(),
+            timestamp,
+            block_number: 0,
+            gas_used: None,
+            gas_price: None,
+            status: TransactionStatus::Success,
+            network: String::new(),
+        };
+
+        // 回流金额少了手续费，但仍在容差内
+        alert_system.check_suspicious_activity(&[
+            transfer("0xout", "0xa", "0xb", 5_000_000_000, 1_700_000_000),
+            transfer("0xback", "0xb", "0xa", 4_995_000_000, 1_700_000_060),
+        ]).await.unwrap();
+
+        match receiver.try_recv() {
+            Ok(Alert::SuspiciousActivity { address, activity_type, risk_level, related_transactions, .. }) => {
+                assert_eq!(address, "0xa");
+                assert_eq!(activity_type, "circular_transfer");
+                assert!(matches!(risk_level, RiskLevel::High));
+                assert_eq!(related_transactions, vec!["0xout".to_string(), "0xback".to_string()]);
+            }
+            other => panic!("Expected circular_transfer alert, got {:?}", other),
+        }
+
+        // 金额相差过大或超出时间窗口时不报警
+        alert_system.check_suspicious_activity(&[
+            transfer("0x1", "0xc", "0xd", 5_000_000_000, 1_700_000_000),
+            transfer("0x2", "0xd", "0xc", 1_000_000_000, 1_700_000_010),
+            transfer("0x3", "0xe", "0xf", 5_000_000_000, 1_700_000_000),
+            transfer("0x4", "0xf", "0xe", 5_000_000_000, 1_700_010_000),
+        ]).await.unwrap();
+        assert!(receiver.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_dormant_reactivation() {
+        let (alert_system, mut receiver) = AlertSystem::with_config(AlertConfig {
+            enable_console_alerts: false,
+            dormancy_threshold_hours: 24 * 30,
+            ..Default::default()
+        });
+
+        let old = 1_600_000_000;
+        let transfer = |id: &str, timestamp: u64| Transaction {
+            id: id.to_string(),
+            sender: "0xcold".to_string(),
+            recipient: "0xb".to_string(),
+            amount: 7_000_000_000,
+            token_type: "0x2::sui::SUI".to_string(),
+            timestamp,
+            block_number: 0,
+            gas_used: None,
+            gas_price: None,
+            status: TransactionStatus::Success,
+            network: String::new(),
+        };
+
+        // 首笔交易和休眠期内的交易不报警
+        alert_system.check_dormant_reactivation(&transfer("0x1", old), None).await.unwrap();
+        alert_system.check_dormant_reactivation(&transfer("0x2", old + 10 * 86400), Some(old)).await.unwrap();
+        assert!(receiver.try_recv().is_err());
+
+        // 休眠90天后再次转出
+        alert_system.check_dormant_reactivation(&transfer("0x3", old + 90 * 86400), Some(old)).await.unwrap();
+        match receiver.try_recv() {
+            Ok(Alert::SuspiciousActivity { address, activity_type, description, related_transactions, .. }) => {
+                assert_eq!(address, "0xcold");
+                assert_eq!(activity_type, "dormant_reactivation");
+                assert!(description.contains("dormant for 90d"), "{}", description);
+                assert!(description.contains("sent 7.000000000 SUI"), "{}", description);
+                assert_eq!(related_transactions, vec!["0x3".to_string()]);
+            }
+            other => panic!("Expected dormant_reactivation alert, got {:?}", other),
+        }
+
+        // 其他代币的金额不按 SUI 换算
+        let token = Transaction { token_type: "0xabc::usdc::USDC".to_string(), amount: 2_500_000, ..transfer("0x4", old + 90 * 86400) };
+        alert_system.check_dormant_reactivation(&token, Some(old)).await.unwrap();
+        match receiver.try_recv() {
+            Ok(Alert::SuspiciousActivity { description, .. }) => {
+                assert!(description.contains("sent 2500000 0xabc::usdc::USDC"), "{}", description);
+                assert!(!description.contains("SUI"), "{}", description);
+            }
+            other => panic!("Expected dormant_reactivation alert, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_alert_history_eviction() {
+        let (alert_system, _receiver) = AlertSystem::with_config(AlertConfig {
+            enable_console_alerts: false,
+            alert_history_max: 3,
+            cooldown_period_seconds: 0,
+            ..Default::default()
+        });
+
+        for i in 0..5 {
+            alert_system.send_custom_alert(format!("alert {}", i), "msg".to_string(), "test".to_string()).await.unwrap();
+        }
+
+        // 超出上限时最旧的警报被淘汰
+        let history = alert_system.get_alert_history(10).await;
+        let titles: Vec<String> = history.iter().map(|alert| match alert {
+            Alert::Custom { title, .. } => title.clone(),
+            _ => panic!("Expected Custom alert"),
+        }).collect();
+        assert_eq!(titles, vec!["alert 2", "alert 3", "alert 4"]);
+        assert_eq!(alert_system.get_alert_history(1).await.len(), 1);
+
+        let stats = alert_system.get_alert_stats().await;
+        assert_eq!(stats.total_alerts, 3);
+        assert_eq!(stats.alerts_by_type.get("custom"), Some(&3));
+
+        // 超过保留时间的警报在插入时被淘汰
+        alert_system.alert_history.write().await.push_front(Alert::Custom {
+            title: "stale".to_string(),
+            message: "msg".to_string(),
+            severity: AlertSeverity::Info,
+            category: "test".to_string(),
+            timestamp: Utc::now() - chrono::Duration::hours(48),
+        });
+        alert_system.send_custom_alert("alert 5".to_string(), "msg".to_string(), "test".to_string()).await.unwrap();
+        let history = alert_system.get_alert_history(10).await;
+        assert_eq!(history.len(), 3);
+        assert!(history.iter().all(|alert| !matches!(alert, Alert::Custom { title, .. } if title == "stale")));
+    }
+}
@@ -1,6 +1,15 @@
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
 use crate::error::{TrackerError, TrackerResult};
+use crate::output_formatter::OutputDestination;
+use crate::units::MIST_PER_SUI;
+
+/// 每次查询的交易数上限，与节点的分页上限一致
+pub const MAX_BATCH_SIZE: u32 = 50;
+
+/// 单次事件查询的重试次数上限，避免退避延迟无限增长
+pub const MAX_QUERY_RETRIES: u32 = 10;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
@@ -10,13 +19,48 @@ pub struct Config {
     pub alerts: AlertConfig,
     pub output: OutputConfig,
     pub logging: LoggingConfig,
+    /// 与 `network` 同时监控的其他网络，每个网络需要唯一的 `name`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub networks: Vec<NetworkConfig>,
+    /// SUI/USD 价格源，未配置时不显示美元金额
+    #[serde(default)]
+    pub price: PriceConfig,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NetworkConfig {
+    /// 网络名称，用于标记事件、交易和警报
+    #[serde(default = "default_network_name")]
+    pub name: String,
     pub rpc_url: String,
     pub websocket_url: String,
     pub timeout_seconds: u64,
+    /// 余额查询结果的缓存时间，0 表示不缓存
+    #[serde(default = "default_balance_cache_ttl_seconds")]
+    pub balance_cache_ttl_seconds: u64,
+    /// 只读RPC响应的缓存时间（秒），0 表示不缓存
+    #[serde(default = "default_rpc_cache_ttl_seconds")]
+    pub rpc_cache_ttl_seconds: u64,
+    /// 允许缓存响应的RPC方法，需要最新结果的方法不要加入
+    #[serde(default = "default_rpc_cache_methods")]
+    pub rpc_cache_methods: Vec<String>,
+    /// 单次RPC请求超过该耗时（毫秒）时记录警告，0 表示不警告
+    #[serde(default = "default_slow_rpc_threshold_ms")]
+    pub slow_rpc_threshold_ms: u64,
+    /// 时间窗口内连续失败达到该次数时熔断RPC请求，0 表示不熔断
+    #[serde(default = "default_circuit_failure_threshold")]
+    pub circuit_failure_threshold: u32,
+    #[serde(default = "default_circuit_failure_window_seconds")]
+    pub circuit_failure_window_seconds: u64,
+    /// 熔断后等待多久放行一次试探请求
+    #[serde(default = "default_circuit_cooldown_seconds")]
+    pub circuit_cooldown_seconds: u64,
+    /// 在该网络上监控的地址（仅用于 `networks` 中的条目），为空时使用 `addresses.monitored`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub addresses: Vec<String>,
+    /// 每个JSON-RPC请求附带的请求头，例如商业节点的API密钥
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub rpc_headers: HashMap<String, String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -25,20 +69,193 @@ pub struct MonitoringConfig {
     pub max_history_records: u32,
     pub batch_size: u32,
     pub cleanup_interval_hours: u64,
+    #[serde(default = "default_reconcile_tolerance")]
+    pub reconcile_tolerance: u64,
+    /// 与链上余额对账的间隔（秒），每次对每个地址的每种代币各查询一次；0 表示不对账
+    #[serde(default = "default_reconcile_interval_seconds")]
+    pub reconcile_interval_seconds: u64,
+    #[serde(default)]
+    pub monitored_coin_types: Vec<String>,
+    /// 每轮轮询中同时查询的地址数上限
+    #[serde(default = "default_max_concurrent_checks")]
+    pub max_concurrent_checks: usize,
+    /// 事件通道容量，满时轮询等待处理器消费（背压）
+    #[serde(default = "default_event_channel_capacity")]
+    pub event_channel_capacity: usize,
+    /// 处理循环中维护任务（清理、失败地址和熔断警报等）的执行间隔（秒）；余额对账见 `reconcile_interval_seconds`
+    #[serde(default = "default_maintenance_interval_seconds")]
+    pub maintenance_interval_seconds: u64,
+    /// 地址连续查询失败达到该次数时发出一次系统警报，恢复后再次达到才重新警报；0 表示不发警报
+    #[serde(default = "default_failure_alert_threshold")]
+    pub failure_alert_threshold: u32,
+    /// 失败的交易不计入地址统计和交易量
+    #[serde(default = "default_exclude_failed_from_volume")]
+    pub exclude_failed_from_volume: bool,
+    /// 单次事件查询失败后的重试次数
+    #[serde(default = "default_query_max_retries")]
+    pub query_max_retries: u32,
+    /// 事件查询重试的基础延迟（毫秒），每次重试翻倍
+    #[serde(default = "default_query_retry_base_delay_ms")]
+    pub query_retry_base_delay_ms: u64,
+    /// 只消耗gas、没有代币转移的交易是否作为 `gas` 事件记录（默认跳过）
+    #[serde(default)]
+    pub record_gas_only_transactions: bool,
+    /// 交易所在检查点之后至少还有这么多检查点才视为最终确认，之前记为 Pending；0 表示不等待
+    #[serde(default)]
+    pub finality_depth: u64,
+    /// 重新查询待确认交易状态的间隔（秒），0 表示不查询
+    #[serde(default = "default_pending_confirmation_interval_seconds")]
+    pub pending_confirmation_interval_seconds: u64,
+    /// 单笔待确认交易最多成功查询的次数，达到后按最后观察到的链上状态结束
+    #[serde(default = "default_pending_confirmation_max_checks")]
+    pub pending_confirmation_max_checks: u32,
+    /// 待确认交易从首次成功查询起超过该时长（秒）仍未确认时按最后观察到的链上状态结束
+    #[serde(default = "default_pending_confirmation_timeout_seconds")]
+    pub pending_confirmation_timeout_seconds: u64,
+    /// 每轮最多查询的待确认交易数，最久未查询的优先，避免大量待确认交易阻塞事件处理
+    #[serde(default = "default_pending_confirmation_batch_size")]
+    pub pending_confirmation_batch_size: usize,
+    /// 监控地址数上限，防止误导入大量地址导致并发轮询压垮节点
+    #[serde(default = "default_max_monitored_addresses")]
+    pub max_monitored_addresses: usize,
+    /// 按包（`0xPACKAGE`）或模块（`0xPACKAGE::module`）监控转移事件，只用于主网络 `[network]`
+    #[serde(default)]
+    pub monitored_packages: Vec<String>,
+    /// 保存各地址查询进度的状态文件，重启后从上次的位置继续轮询；为空时不持久化
+    #[serde(default)]
+    pub cursor_state_file: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AddressConfig {
     pub monitored: Vec<String>,
+    /// 保存运行时增删地址的状态文件，为空时不持久化
+    #[serde(default)]
+    pub state_file: String,
+    /// 地址分组（组名 -> 地址），用于按组汇总余额和交易量
+    #[serde(default)]
+    pub groups: HashMap<String, Vec<String>>,
+    /// 单个地址的低余额阈值（地址 -> 金额，格式同 `alerts.low_balance_threshold`），优先于全局阈值；0 表示不检查
+    #[serde(default, deserialize_with = "deserialize_amount_map")]
+    pub low_balance_thresholds: HashMap<String, u64>,
+    /// 地址标签（地址 -> 标签），`ignore-low-balance` 标签关闭该地址的低余额警报
+    #[serde(default)]
+    pub tags: HashMap<String, Vec<String>>,
+}
+
+/// 解析金额阈值：整数视为 MIST（兼容旧配置），字符串视为十进制 SUI
+fn deserialize_amount<'de, D>(deserializer: D) -> Result<u64, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Amount {
+        Mist(u64),
+        Sui(String),
+    }
+
+    match Amount::deserialize(deserializer) {
+        Ok(Amount::Mist(mist)) => Ok(mist),
+        Ok(Amount::Sui(sui)) => crate::units::parse_sui_amount(&sui).ok_or_else(|| {
+            serde::de::Error::custom(format!(
+                "invalid SUI amount '{}' (expected a non-negative decimal with at most {} places, e.g. \"10.5\")",
+                sui,
+                crate::units::SUI_DECIMALS
+            ))
+        }),
+        Err(_) => Err(serde::de::Error::custom(
+            "expected a non-negative integer in MIST or a decimal SUI string such as \"10.5\"",
+        )),
+    }
+}
+
+/// 按 `deserialize_amount` 的规则解析金额表的每个值
+fn deserialize_amount_map<'de, D>(deserializer: D) -> Result<HashMap<String, u64>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct Amount(#[serde(deserialize_with = "deserialize_amount")] u64);
+
+    let amounts = HashMap::<String, Amount>::deserialize(deserializer)?;
+    Ok(amounts.into_iter().map(|(address, Amount(amount))| (address, amount)).collect())
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AlertConfig {
+    /// 低余额阈值（MIST）；配置文件中整数按 MIST、字符串按 SUI 解析（如 "10.5"）
+    #[serde(deserialize_with = "deserialize_amount")]
     pub low_balance_threshold: u64,
+    /// 大额转账阈值（MIST），解析规则同上
+    #[serde(deserialize_with = "deserialize_amount")]
     pub large_transfer_threshold: u64,
     pub enable_console_alerts: bool,
     pub enable_file_alerts: bool,
     pub alert_file_path: String,
+    /// 警报文件超过该大小（MB）时轮转为 alerts.log.1，0 表示不轮转
+    #[serde(default = "default_alert_file_max_size_mb")]
+    pub alert_file_max_size_mb: u32,
+    /// 保留的警报文件归档数（0 表示轮转时直接清空）
+    #[serde(default = "default_alert_file_rotate_count")]
+    pub alert_file_rotate_count: u32,
+    #[serde(default)]
+    pub enable_slack_alerts: bool,
+    #[serde(default)]
+    pub slack_webhook_url: String,
+    #[serde(default)]
+    pub enable_telegram_alerts: bool,
+    #[serde(default)]
+    pub telegram_bot_token: String,
+    #[serde(default)]
+    pub telegram_chat_id: String,
+    #[serde(default)]
+    pub telegram_use_markdown: bool,
+    #[serde(default)]
+    pub enable_webhook_alerts: bool,
+    #[serde(default)]
+    pub webhook_url: String,
+    #[serde(default = "default_webhook_method")]
+    pub webhook_method: String,
+    #[serde(default = "default_webhook_content_type")]
+    pub webhook_content_type: String,
+    #[serde(default = "default_webhook_template")]
+    pub webhook_template: String,
+    #[serde(default = "default_min_delivery_severity")]
+    pub min_delivery_severity: crate::alert_system::AlertSeverity,
+    /// 控制台警报样式：emoji、plain 或 json
+    #[serde(default)]
+    pub console_alert_style: crate::alert_system::ConsoleAlertStyle,
+    /// 预计在该时间内余额耗尽时发出警报，0 表示关闭
+    #[serde(default = "default_depletion_horizon_hours")]
+    pub depletion_horizon_hours: u64,
+    /// 计算平均净流出速率的时间窗口
+    #[serde(default = "default_depletion_window_hours")]
+    pub depletion_window_hours: u64,
+    /// 循环转账检测中回流金额允许的偏差（MIST）
+    #[serde(default = "default_circular_transfer_tolerance")]
+    pub circular_transfer_tolerance: u64,
+    /// 循环转账检测的时间窗口（秒）
+    #[serde(default = "default_circular_transfer_window_seconds")]
+    pub circular_transfer_window_seconds: u64,
+    /// 休眠超过该时长（小时）的地址再次转出时发出警报，0 表示关闭
+    #[serde(default = "default_dormancy_threshold_hours")]
+    pub dormancy_threshold_hours: u64,
+    /// 警报历史最多保留的条数，警报统计只覆盖保留的警报
+    #[serde(default = "default_alert_history_max")]
+    pub alert_history_max: usize,
+    /// 警报历史最长保留时间（小时），0 表示不按时间淘汰
+    #[serde(default = "default_alert_history_max_age_hours")]
+    pub alert_history_max_age_hours: u64,
+    /// 警报通道容量，满时丢弃新警报并计数（先丢弃低严重程度的警报）
+    #[serde(default = "default_alert_channel_capacity")]
+    pub alert_channel_capacity: usize,
+    /// 定期发送活动汇总的间隔（小时），0 表示不发送
+    #[serde(default = "default_digest_interval_hours")]
+    pub digest_interval_hours: u64,
+    /// 每分钟事件数超过基线的该倍数时发出速率突增警报，0 表示关闭
+    #[serde(default = "default_rate_spike_multiplier")]
+    pub rate_spike_multiplier: f64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -46,7 +263,61 @@ pub struct OutputConfig {
     pub use_colors: bool,
     pub show_timestamps: bool,
     pub max_recent_transactions: u32,
+    /// 定期余额摘要的间隔（秒），0 表示不输出
     pub balance_summary_interval: u64,
+    /// 定期余额摘要中是否包含逐地址余额表
+    #[serde(default = "default_summary_show_balances")]
+    pub summary_show_balances: bool,
+    /// 余额表只显示余额最高的N个地址，0 表示全部显示
+    #[serde(default)]
+    pub summary_top_n: usize,
+    /// 定期余额摘要中是否包含系统统计
+    #[serde(default = "default_summary_show_system_stats")]
+    pub summary_show_system_stats: bool,
+    /// 实时输出中只打印金额不低于该值（MIST）的交易，0 表示全部打印；不影响处理和警报
+    #[serde(default)]
+    pub min_display_amount: u64,
+    #[serde(default = "default_display_decimals")]
+    pub display_decimals: u8,
+    #[serde(default)]
+    pub group_digits: bool,
+    #[serde(default)]
+    pub compact: bool,
+    #[serde(default)]
+    pub csv_header: bool,
+    /// 运行时输出（交易、余额摘要、警报）另存的文件
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output_file: Option<String>,
+    #[serde(default)]
+    pub output_destination: OutputDestination,
+    /// 交易着色的金额分档（MIST）
+    #[serde(default = "default_color_tier_high")]
+    pub color_tier_high: u64,
+    #[serde(default = "default_color_tier_medium")]
+    pub color_tier_medium: u64,
+    #[serde(default = "default_color_tier_low")]
+    pub color_tier_low: u64,
+    /// 格式化输出的JSON是否缩进
+    #[serde(default)]
+    pub json_pretty: bool,
+    /// 导出文件的JSON是否缩进
+    #[serde(default = "default_export_json_pretty")]
+    pub export_json_pretty: bool,
+    /// 只输出数据和错误，不显示欢迎信息、标题和进度提示
+    #[serde(default)]
+    pub quiet: bool,
+    /// 监控时只输出警报，不打印交易和定期余额摘要（统计照常更新）
+    #[serde(default)]
+    pub alerts_only: bool,
+    /// 配置了价格源时在金额后附加美元估值
+    #[serde(default)]
+    pub show_usd_value: bool,
+    /// 查询命令提示文本的语言：en 或 zh
+    #[serde(default)]
+    pub language: crate::messages::Language,
+    /// 提示文本行首是否显示 emoji
+    #[serde(default = "default_use_emoji")]
+    pub use_emoji: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -57,6 +328,227 @@ pub struct LoggingConfig {
     pub rotate_files: u32,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PriceConfig {
+    /// 返回SUI价格JSON的地址，为空时不查询价格
+    #[serde(default)]
+    pub feed_url: String,
+    /// 价格在响应JSON中的位置（JSON指针）
+    #[serde(default = "default_price_json_pointer")]
+    pub json_pointer: String,
+    #[serde(default = "default_price_cache_ttl_seconds")]
+    pub cache_ttl_seconds: u64,
+    #[serde(default = "default_price_timeout_seconds")]
+    pub timeout_seconds: u64,
+}
+
+impl Default for PriceConfig {
+    fn default() -> Self {
+        Self {
+            feed_url: String::new(),
+            json_pointer: default_price_json_pointer(),
+            cache_ttl_seconds: default_price_cache_ttl_seconds(),
+            timeout_seconds: default_price_timeout_seconds(),
+        }
+    }
+}
+
+fn default_price_json_pointer() -> String {
+    "/sui/usd".to_string()
+}
+
+fn default_price_cache_ttl_seconds() -> u64 {
+    60
+}
+
+fn default_price_timeout_seconds() -> u64 {
+    10
+}
+
+fn default_network_name() -> String {
+    "default".to_string()
+}
+
+fn default_balance_cache_ttl_seconds() -> u64 {
+    5
+}
+
+fn default_rpc_cache_ttl_seconds() -> u64 {
+    2
+}
+
+fn default_rpc_cache_methods() -> Vec<String> {
+    crate::sui_client::DEFAULT_CACHED_RPC_METHODS.iter().map(|method| method.to_string()).collect()
+}
+
+fn default_slow_rpc_threshold_ms() -> u64 {
+    crate::sui_client::DEFAULT_SLOW_RPC_THRESHOLD.as_millis() as u64
+}
+
+fn default_circuit_failure_threshold() -> u32 {
+    5
+}
+
+fn default_circuit_failure_window_seconds() -> u64 {
+    60
+}
+
+fn default_circuit_cooldown_seconds() -> u64 {
+    30
+}
+
+fn default_display_decimals() -> u8 {
+    9
+}
+
+fn default_color_tier_high() -> u64 {
+    10 * MIST_PER_SUI
+}
+
+fn default_color_tier_medium() -> u64 {
+    MIST_PER_SUI
+}
+
+fn default_color_tier_low() -> u64 {
+    MIST_PER_SUI / 10
+}
+
+fn default_summary_show_balances() -> bool {
+    true
+}
+
+fn default_summary_show_system_stats() -> bool {
+    true
+}
+
+fn default_use_emoji() -> bool {
+    true
+}
+
+fn default_export_json_pretty() -> bool {
+    true
+}
+
+fn default_webhook_method() -> String {
+    "POST".to_string()
+}
+
+fn default_webhook_content_type() -> String {
+    "application/json".to_string()
+}
+
+fn default_webhook_template() -> String {
+    crate::alert_system::DEFAULT_WEBHOOK_TEMPLATE.to_string()
+}
+
+fn default_min_delivery_severity() -> crate::alert_system::AlertSeverity {
+    crate::alert_system::AlertSeverity::Info
+}
+
+fn default_depletion_horizon_hours() -> u64 {
+    24
+}
+
+fn default_depletion_window_hours() -> u64 {
+    24
+}
+
+fn default_circular_transfer_tolerance() -> u64 {
+    10_000_000
+}
+
+fn default_circular_transfer_window_seconds() -> u64 {
+    600
+}
+
+fn default_dormancy_threshold_hours() -> u64 {
+    720
+}
+
+fn default_alert_history_max() -> usize {
+    1000
+}
+
+fn default_alert_history_max_age_hours() -> u64 {
+    24
+}
+
+fn default_digest_interval_hours() -> u64 {
+    24
+}
+
+fn default_rate_spike_multiplier() -> f64 {
+    5.0
+}
+
+fn default_max_concurrent_checks() -> usize {
+    crate::event_monitor::DEFAULT_MAX_CONCURRENT_CHECKS
+}
+
+fn default_event_channel_capacity() -> usize {
+    crate::event_monitor::DEFAULT_EVENT_CHANNEL_CAPACITY
+}
+
+fn default_maintenance_interval_seconds() -> u64 {
+    30
+}
+
+fn default_alert_file_max_size_mb() -> u32 {
+    10
+}
+
+fn default_alert_file_rotate_count() -> u32 {
+    5
+}
+
+fn default_alert_channel_capacity() -> usize {
+    crate::alert_system::DEFAULT_ALERT_CHANNEL_CAPACITY
+}
+
+fn default_query_max_retries() -> u32 {
+    crate::event_monitor::DEFAULT_QUERY_MAX_RETRIES
+}
+
+fn default_query_retry_base_delay_ms() -> u64 {
+    crate::event_monitor::DEFAULT_QUERY_RETRY_BASE_DELAY_MS
+}
+
+fn default_pending_confirmation_interval_seconds() -> u64 {
+    30
+}
+
+fn default_pending_confirmation_max_checks() -> u32 {
+    20
+}
+
+fn default_pending_confirmation_timeout_seconds() -> u64 {
+    600
+}
+
+fn default_pending_confirmation_batch_size() -> usize {
+    100
+}
+
+fn default_max_monitored_addresses() -> usize {
+    10_000
+}
+
+fn default_failure_alert_threshold() -> u32 {
+    5
+}
+
+fn default_exclude_failed_from_volume() -> bool {
+    true
+}
+
+fn default_reconcile_tolerance() -> u64 {
+    1_000_000 // 0.001 SUI
+}
+
+fn default_reconcile_interval_seconds() -> u64 {
+    300
+}
+
 impl Config {
     pub fn load(config_path: Option<&str>) -> TrackerResult<Self> {
         match config_path {
@@ -73,6 +565,75 @@ impl Config {
         }
     }
 
+    /// 加载配置文件后应用 `SUI_TRACKER_*` 环境变量覆盖
+    ///
+    /// 优先级：配置文件 < 环境变量 < 命令行参数（由 `merge_with_args` 处理）
+    pub fn load_with_env(config_path: Option<&str>) -> TrackerResult<Self> {
+        let mut config = Self::load(config_path)?;
+        config.apply_env_overrides()?;
+        Ok(config)
+    }
+
+    /// 从进程环境变量应用覆盖
+    ///
+    /// 支持的变量：
+    /// - `SUI_TRACKER_RPC_URL`
+    /// - `SUI_TRACKER_WEBSOCKET_URL`
+    /// - `SUI_TRACKER_POLL_INTERVAL`（秒）
+    /// - `SUI_TRACKER_LOW_BALANCE_THRESHOLD`（MIST）
+    /// - `SUI_TRACKER_LARGE_TRANSFER_THRESHOLD`（MIST）
+    /// - `SUI_TRACKER_ADDRESSES`（逗号分隔）
+    /// - `SUI_TRACKER_LOG_LEVEL`
+    pub fn apply_env_overrides(&mut self) -> TrackerResult<()> {
+        self.apply_env_overrides_from(|key| std::env::var(key).ok())
+    }
+
+    fn apply_env_overrides_from<F>(&mut self, lookup: F) -> TrackerResult<()>
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        if let Some(rpc_url) = lookup("SUI_TRACKER_RPC_URL") {
+            self.network.rpc_url = rpc_url;
+        }
+
+        if let Some(websocket_url) = lookup("SUI_TRACKER_WEBSOCKET_URL") {
+            self.network.websocket_url = websocket_url;
+        }
+
+        if let Some(value) = lookup("SUI_TRACKER_POLL_INTERVAL") {
+            self.monitoring.poll_interval_seconds = Self::parse_env_u64("SUI_TRACKER_POLL_INTERVAL", &value)?;
+        }
+
+        if let Some(value) = lookup("SUI_TRACKER_LOW_BALANCE_THRESHOLD") {
+            self.alerts.low_balance_threshold = Self::parse_env_u64("SUI_TRACKER_LOW_BALANCE_THRESHOLD", &value)?;
+        }
+
+        if let Some(value) = lookup("SUI_TRACKER_LARGE_TRANSFER_THRESHOLD") {
+            self.alerts.large_transfer_threshold = Self::parse_env_u64("SUI_TRACKER_LARGE_TRANSFER_THRESHOLD", &value)?;
+        }
+
+        if let Some(value) = lookup("SUI_TRACKER_ADDRESSES") {
+            self.addresses.monitored = value
+                .split(',')
+                .map(|address| address.trim())
+                .filter(|address| !address.is_empty())
+                .map(|address| address.to_string())
+                .collect();
+        }
+
+        if let Some(log_level) = lookup("SUI_TRACKER_LOG_LEVEL") {
+            self.logging.level = log_level;
+        }
+
+        Ok(())
+    }
+
+    fn parse_env_u64(name: &str, value: &str) -> TrackerResult<u64> {
+        value.trim().parse().map_err(|_| TrackerError::Configuration(
+            format!("Invalid value for {}: '{}' (expected a non-negative integer)", name, value)
+        ))
+    }
+
     pub fn save(&self, path: &Path) -> TrackerResult<()> {
         let content = toml::to_string_pretty(self)
             .map_err(|e| TrackerError::TomlSerializeError(e))?;
@@ -84,12 +645,60 @@ impl Config {
     }
 
     pub fn validate(&self) -> TrackerResult<()> {
+        for (_, result) in self.validation_report() {
+            result?;
+        }
+        Ok(())
+    }
+
+    /// 按配置段分别校验，返回每段的结果（用于 `--config-check` 报告）
+    pub fn validation_report(&self) -> Vec<(&'static str, TrackerResult<()>)> {
+        vec![
+            ("network", self.validate_network()),
+            ("monitoring", self.validate_monitoring()),
+            ("addresses", self.validate_addresses()),
+            ("alerts", self.validate_alerts()),
+            ("output", self.validate_output()),
+            ("logging", self.validate_logging()),
+            ("price", self.validate_price()),
+        ]
+    }
+
+    /// `network` 和 `networks`
+    fn validate_network(&self) -> TrackerResult<()> {
         if self.network.rpc_url.is_empty() {
             return Err(TrackerError::validation_error(
                 "RPC URL cannot be empty"
             ));
         }
 
+        Self::validate_url_scheme("network.rpc_url", &self.network.rpc_url, &["http", "https"])?;
+
+        if !self.network.websocket_url.is_empty() {
+            Self::validate_url_scheme("network.websocket_url", &self.network.websocket_url, &["ws", "wss"])?;
+        }
+
+        let mut network_names = HashSet::from([self.network.name.as_str()]);
+        for network in &self.networks {
+            if network.name.is_empty() || !network_names.insert(network.name.as_str()) {
+                return Err(TrackerError::validation_error(format!(
+                    "Each entry in networks needs a unique, non-empty name (got '{}')",
+                    network.name
+                )));
+            }
+            Self::validate_url_scheme(&format!("networks.{}.rpc_url", network.name), &network.rpc_url, &["http", "https"])?;
+            if let Some(address) = network.addresses.iter().find(|a| Self::normalize_address(a).is_none()) {
+                return Err(TrackerError::invalid_address(
+                    format!("Invalid SUI address in network '{}': {}", network.name, address)
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// `monitoring`
+    fn validate_monitoring(&self) -> TrackerResult<()> {
         if self.monitoring.poll_interval_seconds == 0 {
             return Err(TrackerError::validation_error(
                 "Poll interval must be greater than 0"
@@ -108,6 +717,129 @@ impl Config {
             ));
         }
 
+        if self.monitoring.batch_size > MAX_BATCH_SIZE {
+            return Err(TrackerError::validation_error(format!(
+                "Batch size must be at most {} (the RPC page size limit)", MAX_BATCH_SIZE
+            )));
+        }
+
+        if self.monitoring.max_concurrent_checks == 0 {
+            return Err(TrackerError::validation_error(
+                "max_concurrent_checks must be greater than 0"
+            ));
+        }
+
+        if self.monitoring.event_channel_capacity == 0 {
+            return Err(TrackerError::validation_error(
+                "event_channel_capacity must be greater than 0"
+            ));
+        }
+
+        if self.monitoring.maintenance_interval_seconds == 0 {
+            return Err(TrackerError::validation_error(
+                "maintenance_interval_seconds must be greater than 0"
+            ));
+        }
+
+        for coin_type in &self.monitoring.monitored_coin_types {
+            if Self::normalize_coin_type(coin_type).is_none() {
+                return Err(TrackerError::validation_error(format!(
+                    "Invalid coin type in monitored_coin_types: {}", coin_type
+                )));
+            }
+        }
+
+        if self.monitoring.query_retry_base_delay_ms == 0 {
+            return Err(TrackerError::validation_error(
+                "query_retry_base_delay_ms must be greater than 0"
+            ));
+        }
+
+        if self.monitoring.query_max_retries > MAX_QUERY_RETRIES {
+            return Err(TrackerError::validation_error(format!(
+                "query_max_retries must be at most {}", MAX_QUERY_RETRIES
+            )));
+        }
+
+        for package in &self.monitoring.monitored_packages {
+            if crate::sui_client::PackageFilter::parse(package).is_none() {
+                return Err(TrackerError::validation_error(format!(
+                    "Invalid package filter in monitored_packages: {} (expected 0xPACKAGE or 0xPACKAGE::module)", package
+                )));
+            }
+        }
+
+        if self.monitoring.max_monitored_addresses == 0 {
+            return Err(TrackerError::validation_error(
+                "max_monitored_addresses must be greater than 0"
+            ));
+        }
+
+        if self.monitoring.pending_confirmation_interval_seconds > 0
+            && self.monitoring.pending_confirmation_max_checks == 0
+        {
+            return Err(TrackerError::validation_error(
+                "pending_confirmation_max_checks must be greater than 0"
+            ));
+        }
+
+        if self.monitoring.pending_confirmation_interval_seconds > 0
+            && self.monitoring.pending_confirmation_batch_size == 0
+        {
+            return Err(TrackerError::validation_error(
+                "pending_confirmation_batch_size must be greater than 0"
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// 监控地址格式，以及分组中的地址都在监控列表中
+    fn validate_addresses(&self) -> TrackerResult<()> {
+        for address in &self.addresses.monitored {
+            if Self::normalize_address(address).is_none() {
+                return Err(TrackerError::invalid_address(
+                    format!("Invalid SUI address: {}", address)
+                ));
+            }
+        }
+
+        let monitored: HashSet<String> = self.addresses.monitored.iter()
+            .filter_map(|address| Self::normalize_address(address))
+            .collect();
+        if monitored.len() > self.monitoring.max_monitored_addresses {
+            return Err(TrackerError::validation_error(format!(
+                "{} addresses configured but max_monitored_addresses is {}",
+                monitored.len(), self.monitoring.max_monitored_addresses
+            )));
+        }
+
+        let per_address = self.addresses.low_balance_thresholds.keys().chain(self.addresses.tags.keys());
+        for address in per_address {
+            if Self::normalize_address(address).is_none() {
+                return Err(TrackerError::invalid_address(
+                    format!("Invalid SUI address in addresses.low_balance_thresholds or addresses.tags: {}", address)
+                ));
+            }
+        }
+
+        for (group, addresses) in &self.addresses.groups {
+            let not_monitored = |address: &&String| {
+                Self::normalize_address(address).is_none_or(|address| !monitored.contains(&address))
+            };
+            if let Some(address) = addresses.iter().find(not_monitored) {
+                return Err(TrackerError::validation_error(format!(
+                    "Address {} in group '{}' is not in addresses.monitored",
+                    address, group
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// 警报阈值，以及已启用的警报渠道配置完整
+    fn validate_alerts(&self) -> TrackerResult<()> {
         if self.alerts.low_balance_threshold == 0 {
             return Err(TrackerError::validation_error(
                 "Low balance threshold must be greater than 0"
@@ -120,14 +852,119 @@ impl Config {
             ));
         }
 
-        for address in &self.addresses.monitored {
-            if !Self::is_valid_sui_address(address) {
-                return Err(TrackerError::invalid_address(
-                    format!("Invalid SUI address: {}", address)
+        if self.alerts.alert_channel_capacity == 0 {
+            return Err(TrackerError::validation_error(
+                "alert_channel_capacity must be greater than 0"
+            ));
+        }
+
+        let multiplier = self.alerts.rate_spike_multiplier;
+        if !multiplier.is_finite() || (multiplier != 0.0 && multiplier <= 1.0) {
+            return Err(TrackerError::validation_error(
+                "rate_spike_multiplier must be greater than 1 (or 0 to disable)"
+            ));
+        }
+
+        if self.alerts.enable_slack_alerts {
+            if self.alerts.slack_webhook_url.is_empty() {
+                return Err(TrackerError::validation_error(
+                    "alerts.slack_webhook_url must be set when Slack alerts are enabled"
+                ));
+            }
+            Self::validate_url_scheme("alerts.slack_webhook_url", &self.alerts.slack_webhook_url, &["http", "https"])?;
+        }
+
+        if self.alerts.enable_telegram_alerts
+            && (self.alerts.telegram_bot_token.is_empty() || self.alerts.telegram_chat_id.is_empty())
+        {
+            return Err(TrackerError::validation_error(
+                "alerts.telegram_bot_token and alerts.telegram_chat_id must be set when Telegram alerts are enabled"
+            ));
+        }
+
+        if self.alerts.enable_webhook_alerts {
+            if self.alerts.webhook_url.is_empty() {
+                return Err(TrackerError::validation_error(
+                    "alerts.webhook_url must be set when webhook alerts are enabled"
+                ));
+            }
+            Self::validate_url_scheme("alerts.webhook_url", &self.alerts.webhook_url, &["http", "https"])?;
+
+            if !["POST", "PUT", "PATCH"].contains(&self.alerts.webhook_method.to_uppercase().as_str()) {
+                return Err(TrackerError::validation_error(format!(
+                    "alerts.webhook_method must be POST, PUT or PATCH, got '{}'",
+                    self.alerts.webhook_method
+                )));
+            }
+
+            if !self.alerts.webhook_template.contains("{message}") {
+                return Err(TrackerError::validation_error(
+                    "alerts.webhook_template must contain the {message} placeholder"
                 ));
             }
         }
 
+
+        if self.alerts.enable_file_alerts && self.alerts.alert_file_path.is_empty() {
+            return Err(TrackerError::validation_error(
+                "alerts.alert_file_path must be set when file alerts are enabled"
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// `output`
+    fn validate_output(&self) -> TrackerResult<()> {
+        if self.output.output_destination == OutputDestination::File && self.output.output_file.is_none() {
+            return Err(TrackerError::validation_error(
+                "output.output_file must be set when output.output_destination is \"file\""
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// `logging`
+    fn validate_logging(&self) -> TrackerResult<()> {
+        if !["trace", "debug", "info", "warn", "error"].contains(&self.logging.level.as_str()) {
+            return Err(TrackerError::validation_error(format!(
+                "logging.level must be one of trace, debug, info, warn, error, got '{}'",
+                self.logging.level
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// `price`
+    fn validate_price(&self) -> TrackerResult<()> {
+        if !self.price.feed_url.is_empty() {
+            Self::validate_url_scheme("price.feed_url", &self.price.feed_url, &["http", "https"])?;
+            if !self.price.json_pointer.starts_with('/') {
+                return Err(TrackerError::validation_error(
+                    "price.json_pointer must be a JSON pointer starting with '/'"
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn validate_url_scheme(field: &str, value: &str, allowed_schemes: &[&str]) -> TrackerResult<()> {
+        let url = reqwest::Url::parse(value).map_err(|e| TrackerError::validation_error(
+            format!("Invalid {} '{}': {}", field, value, e)
+        ))?;
+
+        if !allowed_schemes.contains(&url.scheme()) {
+            return Err(TrackerError::validation_error(format!(
+                "Invalid {} '{}': scheme must be one of {}",
+                field,
+                value,
+                allowed_schemes.join("/")
+            )));
+        }
+
         Ok(())
     }
 
@@ -136,6 +973,53 @@ impl Config {
         address[2..].chars().all(|c| c.is_ascii_hexdigit())
     }
 
+    /// 宽松校验：允许省略前导零的短地址（如 `0x2`），`0x` 后为 1–64 位十六进制
+    pub fn is_valid_sui_address_lenient(address: &str) -> bool {
+        address.strip_prefix("0x").is_some_and(|hex| {
+            !hex.is_empty() && hex.len() <= 64 && hex.chars().all(|c| c.is_ascii_hexdigit())
+        })
+    }
+
+    /// 规范化代币类型中的包地址（`0x2::sui::SUI` -> `0x00..02::sui::SUI`），格式无效时返回 None
+    pub fn normalize_coin_type(coin_type: &str) -> Option<String> {
+        let coin_type = coin_type.trim();
+        let (package, rest) = coin_type.split_once("::")?;
+        let (module, name) = rest.split_once("::")?;
+        if !Self::is_valid_sui_address_lenient(package) || module.is_empty() || name.is_empty() {
+            return None;
+        }
+        Some(format!("{}::{}::{}", Self::normalize_address(package)?, module, name))
+    }
+
+    /// 将地址规范化为小写、补齐前导零的 32 字节形式（`0x` + 64 位十六进制），无法解析时返回 None
+    pub fn normalize_address(address: &str) -> Option<String> {
+        let address = address.trim();
+        let hex = address.strip_prefix("0x").or_else(|| address.strip_prefix("0X"))?;
+        if hex.is_empty() || hex.len() > 64 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+        Some(format!("0x{:0>64}", hex.to_ascii_lowercase()))
+    }
+
+    /// `addresses.low_balance_thresholds` 中为该地址单独设置的阈值
+    pub fn address_low_balance_threshold(&self, address: &str) -> Option<u64> {
+        let address = Self::normalize_address(address)?;
+        self.addresses.low_balance_thresholds.iter()
+            .find(|(configured, _)| Self::normalize_address(configured).as_deref() == Some(address.as_str()))
+            .map(|(_, threshold)| *threshold)
+    }
+
+    /// `addresses.tags` 中为该地址设置的标签
+    pub fn address_tags(&self, address: &str) -> Vec<String> {
+        let Some(address) = Self::normalize_address(address) else {
+            return Vec::new();
+        };
+        self.addresses.tags.iter()
+            .filter(|(configured, _)| Self::normalize_address(configured).as_deref() == Some(address.as_str()))
+            .flat_map(|(_, tags)| tags.iter().cloned())
+            .collect()
+    }
+
     pub fn merge_with_args(&mut self, args: &ConfigArgs) {
         if let Some(rpc_url) = &args.rpc_url {
             self.network.rpc_url = rpc_url.clone();
@@ -161,6 +1045,18 @@ impl Config {
             self.output.show_timestamps = show_timestamps;
         }
 
+        if let Some(quiet) = args.quiet {
+            self.output.quiet = quiet;
+        }
+
+        if let Some(alerts_only) = args.alerts_only {
+            self.output.alerts_only = alerts_only;
+        }
+
+        if args.no_summary == Some(true) {
+            self.output.balance_summary_interval = 0;
+        }
+
         if let Some(log_level) = &args.log_level {
             self.logging.level = log_level.clone();
         }
@@ -179,18 +1075,51 @@ impl Default for Config {
     fn default() -> Self {
         Self {
             network: NetworkConfig {
+                name: default_network_name(),
                 rpc_url: "https://fullnode.mainnet.sui.io:443".to_string(),
                 websocket_url: "wss://fullnode.mainnet.sui.io".to_string(),
                 timeout_seconds: 30,
+                balance_cache_ttl_seconds: default_balance_cache_ttl_seconds(),
+                rpc_cache_ttl_seconds: default_rpc_cache_ttl_seconds(),
+                rpc_cache_methods: default_rpc_cache_methods(),
+                slow_rpc_threshold_ms: default_slow_rpc_threshold_ms(),
+                circuit_failure_threshold: default_circuit_failure_threshold(),
+                circuit_failure_window_seconds: default_circuit_failure_window_seconds(),
+                circuit_cooldown_seconds: default_circuit_cooldown_seconds(),
+                addresses: Vec::new(),
+                rpc_headers: HashMap::new(),
             },
             monitoring: MonitoringConfig {
                 poll_interval_seconds: 10,
                 max_history_records: 1000,
                 batch_size: 50,
                 cleanup_interval_hours: 24,
+                reconcile_tolerance: default_reconcile_tolerance(),
+                reconcile_interval_seconds: default_reconcile_interval_seconds(),
+                monitored_coin_types: Vec::new(),
+                max_concurrent_checks: default_max_concurrent_checks(),
+                event_channel_capacity: default_event_channel_capacity(),
+                maintenance_interval_seconds: default_maintenance_interval_seconds(),
+                failure_alert_threshold: default_failure_alert_threshold(),
+                exclude_failed_from_volume: default_exclude_failed_from_volume(),
+                query_max_retries: default_query_max_retries(),
+                query_retry_base_delay_ms: default_query_retry_base_delay_ms(),
+                record_gas_only_transactions: false,
+                finality_depth: 0,
+                pending_confirmation_interval_seconds: default_pending_confirmation_interval_seconds(),
+                pending_confirmation_max_checks: default_pending_confirmation_max_checks(),
+                pending_confirmation_timeout_seconds: default_pending_confirmation_timeout_seconds(),
+                pending_confirmation_batch_size: default_pending_confirmation_batch_size(),
+                max_monitored_addresses: default_max_monitored_addresses(),
+                monitored_packages: Vec::new(),
+                cursor_state_file: String::new(),
             },
             addresses: AddressConfig {
                 monitored: Vec::new(),
+                state_file: String::new(),
+                groups: HashMap::new(),
+                low_balance_thresholds: HashMap::new(),
+                tags: HashMap::new(),
             },
             alerts: AlertConfig {
                 low_balance_threshold: 1000000000,
@@ -198,12 +1127,57 @@ impl Default for Config {
                 enable_console_alerts: true,
                 enable_file_alerts: false,
                 alert_file_path: "alerts.log".to_string(),
+                alert_file_max_size_mb: default_alert_file_max_size_mb(),
+                alert_file_rotate_count: default_alert_file_rotate_count(),
+                enable_slack_alerts: false,
+                slack_webhook_url: String::new(),
+                enable_telegram_alerts: false,
+                telegram_bot_token: String::new(),
+                telegram_chat_id: String::new(),
+                telegram_use_markdown: false,
+                enable_webhook_alerts: false,
+                webhook_url: String::new(),
+                webhook_method: default_webhook_method(),
+                webhook_content_type: default_webhook_content_type(),
+                webhook_template: default_webhook_template(),
+                min_delivery_severity: default_min_delivery_severity(),
+                console_alert_style: Default::default(),
+                depletion_horizon_hours: default_depletion_horizon_hours(),
+                depletion_window_hours: default_depletion_window_hours(),
+                circular_transfer_tolerance: default_circular_transfer_tolerance(),
+                circular_transfer_window_seconds: default_circular_transfer_window_seconds(),
+                dormancy_threshold_hours: default_dormancy_threshold_hours(),
+                alert_history_max: default_alert_history_max(),
+                alert_history_max_age_hours: default_alert_history_max_age_hours(),
+                alert_channel_capacity: default_alert_channel_capacity(),
+                digest_interval_hours: default_digest_interval_hours(),
+                rate_spike_multiplier: default_rate_spike_multiplier(),
             },
             output: OutputConfig {
                 use_colors: true,
                 show_timestamps: true,
                 max_recent_transactions: 10,
                 balance_summary_interval: 300,
+                summary_show_balances: default_summary_show_balances(),
+                summary_top_n: 0,
+                summary_show_system_stats: default_summary_show_system_stats(),
+                min_display_amount: 0,
+                display_decimals: default_display_decimals(),
+                group_digits: false,
+                compact: false,
+                csv_header: false,
+                output_file: None,
+                output_destination: OutputDestination::default(),
+                color_tier_high: default_color_tier_high(),
+                color_tier_medium: default_color_tier_medium(),
+                color_tier_low: default_color_tier_low(),
+                json_pretty: false,
+                export_json_pretty: default_export_json_pretty(),
+                quiet: false,
+                alerts_only: false,
+                show_usd_value: false,
+                language: crate::messages::Language::default(),
+                use_emoji: default_use_emoji(),
             },
             logging: LoggingConfig {
                 level: "info".to_string(),
@@ -211,6 +1185,8 @@ impl Default for Config {
                 max_file_size_mb: 10,
                 rotate_files: 5,
             },
+            networks: Vec::new(),
+            price: PriceConfig::default(),
         }
     }
 }
@@ -223,6 +1199,10 @@ pub struct ConfigArgs {
     pub large_transfer_threshold: Option<u64>,
     pub use_colors: Option<bool>,
     pub show_timestamps: Option<bool>,
+    pub quiet: Option<bool>,
+    pub alerts_only: Option<bool>,
+    /// 关闭定期余额摘要
+    pub no_summary: Option<bool>,
     pub log_level: Option<String>,
     pub addresses: Vec<String>,
 }
@@ -236,6 +1216,9 @@ impl Default for ConfigArgs {
             large_transfer_threshold: None,
             use_colors: None,
             show_timestamps: None,
+            quiet: None,
+            alerts_only: None,
+            no_summary: None,
             log_level: None,
             addresses: Vec::new(),
         }
@@ -264,6 +1247,44 @@ mod tests {
         assert!(!Config::is_valid_sui_address("0xzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzz")); // 包含非十六进制字符
     }
 
+    #[test]
+    fn test_address_validation_lenient() {
+        let full = format!("0x{}", "ab".repeat(32));
+        assert!(Config::is_valid_sui_address_lenient("0x2"));
+        assert!(Config::is_valid_sui_address_lenient(&full));
+        assert!(Config::is_valid_sui_address(&full));
+        assert!(!Config::is_valid_sui_address("0x2"));
+
+        assert!(!Config::is_valid_sui_address_lenient("0x"));
+        assert!(!Config::is_valid_sui_address_lenient("2"));
+        assert!(!Config::is_valid_sui_address_lenient("0xg2"));
+        assert!(!Config::is_valid_sui_address_lenient(&format!("0x{}", "1".repeat(65))));
+
+        let sui = format!("0x{:0>64}::sui::SUI", "2");
+        assert_eq!(Config::normalize_coin_type("0x2::sui::SUI").as_deref(), Some(sui.as_str()));
+        assert_eq!(Config::normalize_coin_type(&sui).as_deref(), Some(sui.as_str()));
+        assert!(Config::normalize_coin_type("0x2::sui").is_none());
+        assert!(Config::normalize_coin_type("sui::sui::SUI").is_none());
+    }
+
+    #[test]
+    fn test_normalize_address() {
+        let canonical = format!("0x{}abc", "0".repeat(61));
+        assert_eq!(Config::normalize_address("0xabc").as_deref(), Some(canonical.as_str()));
+        assert_eq!(Config::normalize_address("0xABC").as_deref(), Some(canonical.as_str()));
+        assert_eq!(Config::normalize_address(" 0X0aBc ").as_deref(), Some(canonical.as_str()));
+        assert_eq!(Config::normalize_address(&canonical).as_deref(), Some(canonical.as_str()));
+        assert!(Config::is_valid_sui_address(&canonical));
+
+        let mixed = "0xAbCdEf1234567890aBcDeF1234567890AbCdEf1234567890aBcDeF1234567890";
+        assert_eq!(Config::normalize_address(mixed), Some(mixed.to_lowercase()));
+
+        assert!(Config::normalize_address("abc").is_none()); // 缺少0x前缀
+        assert!(Config::normalize_address("0x").is_none());
+        assert!(Config::normalize_address("0xzz").is_none());
+        assert!(Config::normalize_address(&format!("0x{}", "1".repeat(65))).is_none()); // 超过32字节
+    }
+
     #[test]
     fn test_config_validation() {
         let mut config = Config::default();
@@ -277,10 +1298,155 @@ mod tests {
         assert!(config.validate().is_err());
 
         config.monitoring.poll_interval_seconds = 10;
+        config.monitoring.batch_size = MAX_BATCH_SIZE + 1;
+        assert!(config.validate().is_err());
+
+        config.monitoring.batch_size = MAX_BATCH_SIZE;
+        config.monitoring.query_max_retries = MAX_QUERY_RETRIES + 1;
+        assert!(config.validate().is_err());
+
+        config.monitoring.query_max_retries = MAX_QUERY_RETRIES;
+        config.monitoring.maintenance_interval_seconds = 0;
+        assert!(config.validate().is_err());
+
+        config.monitoring.maintenance_interval_seconds = 30;
+        config.monitoring.max_monitored_addresses = 1;
+        config.addresses.monitored = vec![
+            "0x1234567890abcdef1234567890abcdef12345678".to_string(),
+            "0xabcdef1234567890abcdef1234567890abcdef12".to_string(),
+        ];
+        assert!(config.validate().unwrap_err().to_string().contains("max_monitored_addresses is 1"));
+
+        config.addresses.monitored.clear();
         config.alerts.low_balance_threshold = 0;
         assert!(config.validate().is_err());
     }
 
+    #[test]
+    fn test_url_validation() {
+        let mut config = Config::default();
+        config.network.rpc_url = "http://localhost:9000".to_string();
+        config.network.websocket_url = String::new();
+        assert!(config.validate().is_ok());
+
+        config.network.rpc_url = "htps://fullnode.mainnet.sui.io:443".to_string();
+        let err = config.validate().unwrap_err();
+        assert!(matches!(err, TrackerError::ValidationError(_)));
+        assert!(err.to_string().contains("network.rpc_url"));
+
+        config.network.rpc_url = "not a url".to_string();
+        let err = config.validate().unwrap_err();
+        assert!(err.to_string().contains("network.rpc_url"));
+
+        config.network.rpc_url = "https://fullnode.mainnet.sui.io:443".to_string();
+        config.network.websocket_url = "https://fullnode.mainnet.sui.io".to_string();
+        let err = config.validate().unwrap_err();
+        assert!(err.to_string().contains("network.websocket_url"));
+    }
+
+    #[test]
+    fn test_webhook_validation() {
+        let mut config = Config::default();
+        config.alerts.enable_webhook_alerts = true;
+        config.alerts.webhook_url = "https://alerts.example.com/hook".to_string();
+        assert!(config.validate().is_ok());
+
+        config.alerts.webhook_template = r#"{"text": "{severity}"}"#.to_string();
+        let err = config.validate().unwrap_err();
+        assert!(err.to_string().contains("{message}"));
+
+        config.alerts.webhook_template = r#"{"text": "{message}"}"#.to_string();
+        config.alerts.webhook_method = "GET".to_string();
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_validation_report() {
+        let mut config = Config::default();
+        assert!(config.validation_report().iter().all(|(_, result)| result.is_ok()));
+
+        config.alerts.enable_slack_alerts = true;
+        config.logging.level = "verbose".to_string();
+        let failed: Vec<&str> = config.validation_report()
+            .into_iter()
+            .filter(|(_, result)| result.is_err())
+            .map(|(section, _)| section)
+            .collect();
+        assert_eq!(failed, vec!["alerts", "logging"]);
+
+        config.alerts.enable_slack_alerts = false;
+        config.alerts.enable_file_alerts = true;
+        config.alerts.alert_file_path = String::new();
+        assert!(config.validate_alerts().unwrap_err().to_string().contains("alert_file_path"));
+    }
+
+    #[test]
+    fn test_group_validation() {
+        let address = "0x".to_string() + &"a".repeat(64);
+        let mut config = Config::default();
+        config.addresses.monitored = vec![address.clone()];
+        config.addresses.groups.insert("treasury".to_string(), vec![address.clone()]);
+        assert!(config.validate().is_ok());
+
+        config.addresses.groups.insert("ops".to_string(), vec!["0x".to_string() + &"b".repeat(64)]);
+        let err = config.validate().unwrap_err();
+        assert!(err.to_string().contains("'ops'"));
+    }
+
+    #[test]
+    fn test_per_address_low_balance_settings() {
+        let address = "0x".to_string() + &"a".repeat(64);
+        let addresses_str = format!(
+            "monitored = []\n\n[low_balance_thresholds]\n\"0xAAA\" = \"2.5\"\n\"{}\" = 0\n\n[tags]\n\"0xaaa\" = [\"ignore-low-balance\"]",
+            address
+        );
+        let config = Config {
+            addresses: toml::from_str(&addresses_str).unwrap(),
+            ..Config::default()
+        };
+        assert!(config.validate().is_ok());
+
+        assert_eq!(config.address_low_balance_threshold("0xaaa"), Some(2_500_000_000));
+        assert_eq!(config.address_low_balance_threshold(&address), Some(0));
+        assert_eq!(config.address_low_balance_threshold("0xbbb"), None);
+        assert_eq!(config.address_tags("0x0aaa"), vec!["ignore-low-balance".to_string()]);
+        assert!(config.address_tags(&address).is_empty());
+
+        let mut config = config;
+        config.addresses.tags.insert("not-an-address".to_string(), Vec::new());
+        assert!(config.validate().unwrap_err().to_string().contains("not-an-address"));
+    }
+
+    #[test]
+    fn test_networks_config() {
+        let toml_str = r#"
+            [network]
+            name = "mainnet"
+            rpc_url = "https://fullnode.mainnet.sui.io:443"
+            websocket_url = ""
+            timeout_seconds = 30
+
+            [[networks]]
+            name = "testnet"
+            rpc_url = "https://fullnode.testnet.sui.io:443"
+            websocket_url = ""
+            timeout_seconds = 30
+        "#;
+        let parsed: toml::Value = toml::from_str(toml_str).unwrap();
+        let mut config = Config {
+            network: parsed["network"].clone().try_into().unwrap(),
+            networks: parsed["networks"].clone().try_into().unwrap(),
+            ..Config::default()
+        };
+        assert_eq!(config.networks[0].name, "testnet");
+        assert!(config.networks[0].addresses.is_empty());
+        assert!(config.validate().is_ok());
+
+        // 网络名称必须唯一
+        config.networks[0].name = "mainnet".to_string();
+        assert!(config.validate().is_err());
+    }
+
     #[test]
     fn test_load_and_save_config() {
         // TODO: This test requires tempfile dependency
@@ -307,6 +1473,64 @@ mod tests {
         assert_eq!(config.network.rpc_url, "https://custom.rpc");
         assert_eq!(config.monitoring.poll_interval_seconds, 5);
         assert_eq!(config.addresses.monitored.len(), 1);
+        assert_eq!(config.output.balance_summary_interval, 300);
+
+        config.merge_with_args(&ConfigArgs { no_summary: Some(true), ..Default::default() });
+        assert_eq!(config.output.balance_summary_interval, 0);
+    }
+
+    #[test]
+    fn test_env_overrides() {
+        let env: std::collections::HashMap<&str, &str> = [
+            ("SUI_TRACKER_RPC_URL", "https://fullnode.testnet.sui.io:443"),
+            ("SUI_TRACKER_POLL_INTERVAL", "30"),
+            ("SUI_TRACKER_LOW_BALANCE_THRESHOLD", "5000"),
+            ("SUI_TRACKER_ADDRESSES", "0xaaa, 0xbbb,"),
+        ].into_iter().collect();
+
+        let mut config = Config::default();
+        config.apply_env_overrides_from(|key| env.get(key).map(|v| v.to_string())).unwrap();
+
+        assert_eq!(config.network.rpc_url, "https://fullnode.testnet.sui.io:443");
+        assert_eq!(config.monitoring.poll_interval_seconds, 30);
+        assert_eq!(config.alerts.low_balance_threshold, 5000);
+        assert_eq!(config.addresses.monitored, vec!["0xaaa".to_string(), "0xbbb".to_string()]);
+
+        // 命令行参数优先于环境变量
+        config.merge_with_args(&ConfigArgs {
+            poll_interval: Some(5),
+            ..Default::default()
+        });
+        assert_eq!(config.monitoring.poll_interval_seconds, 5);
+    }
+
+    #[test]
+    fn test_threshold_units() {
+        let config_str = Config::generate_default_config()
+            .replace("low_balance_threshold = 1000000000", "low_balance_threshold = \"10.5\"")
+            .replace("large_transfer_threshold = 10000000000", "large_transfer_threshold = 25000000000");
+        let config: Config = toml::from_str(&config_str).unwrap();
+        assert_eq!(config.alerts.low_balance_threshold, 10_500_000_000);
+        // 整数仍按 MIST 解析
+        assert_eq!(config.alerts.large_transfer_threshold, 25_000_000_000);
+
+        for invalid in ["\"-1\"", "\"nan\"", "-1", "1.5"] {
+            let config_str = Config::generate_default_config()
+                .replace("low_balance_threshold = 1000000000", &format!("low_balance_threshold = {}", invalid));
+            assert!(toml::from_str::<Config>(&config_str).is_err(), "{} should be rejected", invalid);
+        }
+    }
+
+    #[test]
+    fn test_env_override_parse_error() {
+        let mut config = Config::default();
+        let result = config.apply_env_overrides_from(|key| {
+            (key == "SUI_TRACKER_POLL_INTERVAL").then(|| "ten".to_string())
+        });
+
+        let err = result.unwrap_err();
+        assert!(matches!(err, TrackerError::Configuration(_)));
+        assert!(err.to_string().contains("SUI_TRACKER_POLL_INTERVAL"));
     }
 
     #[test]
@@ -39,6 +39,16 @@ pub enum TrackerError {
     
     #[error("Database error: {0}")]
     DatabaseError(String),
+
+    #[error("Export error: {0}")]
+    ExportError(String),
+
+    #[error("Circuit open: {0}")]
+    CircuitOpen(String),
+
+    /// 节点返回的JSON-RPC错误对象（参数错误、交易不存在等），节点本身可用
+    #[error("RPC error: {0}")]
+    RpcError(String),
 }
 
 impl TrackerError {
@@ -66,6 +76,10 @@ impl TrackerError {
         TrackerError::DatabaseError(msg.into())
     }
 
+    pub fn export_error(msg: impl Into<String>) -> Self {
+        TrackerError::ExportError(msg.into())
+    }
+
     pub fn invalid_address(msg: impl Into<String>) -> Self {
         TrackerError::InvalidAddress(msg.into())
     }
@@ -74,6 +88,14 @@ impl TrackerError {
         TrackerError::TimeoutError(msg.into())
     }
 
+    pub fn circuit_open(msg: impl Into<String>) -> Self {
+        TrackerError::CircuitOpen(msg.into())
+    }
+
+    pub fn rpc_error(msg: impl Into<String>) -> Self {
+        TrackerError::RpcError(msg.into())
+    }
+
     pub fn is_retriable(&self) -> bool {
         match self {
             TrackerError::NetworkError(_) => true,
@@ -87,12 +109,15 @@ impl TrackerError {
         match self {
             TrackerError::NetworkError(_) => 1001,
             TrackerError::SuiClientError(_) => 1002,
+            TrackerError::CircuitOpen(_) => 1003,
+            TrackerError::RpcError(_) => 1004,
             TrackerError::ParseError(_) => 2001,
             TrackerError::Configuration(_) => 2002,
             TrackerError::IoError(_) => 3001,
             TrackerError::SerializationError(_) => 3002,
             TrackerError::TomlError(_) => 3003,
             TrackerError::TomlSerializeError(_) => 3004,
+            TrackerError::ExportError(_) => 3005,
             TrackerError::InvalidAddress(_) => 4001,
             TrackerError::TimeoutError(_) => 4002,
             TrackerError::ValidationError(_) => 4003,
@@ -106,6 +131,16 @@ pub mod utils {
     use tokio::time::{sleep, Duration};
     use std::time::Instant;
 
+    /// 重试之间的最长等待时间（毫秒）
+    pub const MAX_RETRY_DELAY_MS: u64 = 60_000;
+
+    /// 第 `retry` 次重试（从 1 开始）前的等待时间：基础延迟逐次翻倍，不超过 `MAX_RETRY_DELAY_MS`
+    pub fn retry_delay_ms(base_delay_ms: u64, retry: u32) -> u64 {
+        2u64.checked_pow(retry.saturating_sub(1))
+            .map_or(u64::MAX, |factor| base_delay_ms.saturating_mul(factor))
+            .min(MAX_RETRY_DELAY_MS)
+    }
+
     pub async fn retry_operation<T, F, Fut>(
         mut operation: F,
         max_retries: u32,
@@ -122,7 +157,7 @@ pub mod utils {
                 Ok(result) => return Ok(result),
                 Err(e) if retries < max_retries && e.is_retriable() => {
                     retries += 1;
-                    let delay_ms = base_delay_ms * 2u64.pow(retries - 1);
+                    let delay_ms = retry_delay_ms(base_delay_ms, retries);
                     log::warn!("Operation failed (attempt {}/{}): {}, retrying in {}ms", 
                         retries, max_retries, e, delay_ms);
                     sleep(Duration::from_millis(delay_ms)).await;
@@ -187,6 +222,16 @@ mod tests {
         assert!(TrackerError::timeout_error("test").is_retriable());
         assert!(!TrackerError::config_error("test").is_retriable());
         assert!(!TrackerError::invalid_address("test").is_retriable());
+        assert!(!TrackerError::rpc_error("test").is_retriable());
+    }
+
+    #[test]
+    fn test_retry_delay_is_capped() {
+        assert_eq!(utils::retry_delay_ms(1000, 1), 1000);
+        assert_eq!(utils::retry_delay_ms(1000, 3), 4000);
+        assert_eq!(utils::retry_delay_ms(1000, 20), utils::MAX_RETRY_DELAY_MS);
+        assert_eq!(utils::retry_delay_ms(u64::MAX, 2), utils::MAX_RETRY_DELAY_MS);
+        assert_eq!(utils::retry_delay_ms(1, 100), utils::MAX_RETRY_DELAY_MS);
     }
 
     #[tokio::test]
@@ -1,20 +1,133 @@
-use tokio::sync::{mpsc, RwLock};
-use tokio::time::{interval, Duration};
+use tokio::sync::{mpsc, RwLock, Semaphore};
+use tokio::time::{interval, Duration, Instant};
 use std::collections::{HashSet, HashMap};
 use std::sync::Arc;
-use crate::sui_client::{SuiClient, SuiEvent};
+use std::sync::atomic::{AtomicU64, Ordering};
+use crate::sui_client::{PackageFilter, SuiEvent, SuiRpc};
 use crate::error::{TrackerError, TrackerResult, utils};
+use crate::transaction_processor::TransactionStatus;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::future::Future;
+
+/// 同时查询的地址数默认上限
+pub const DEFAULT_MAX_CONCURRENT_CHECKS: usize = 16;
+
+/// 单次事件查询失败后的默认重试次数
+pub const DEFAULT_QUERY_MAX_RETRIES: u32 = 3;
+
+/// 事件查询重试的默认基础延迟（毫秒），每次重试翻倍
+pub const DEFAULT_QUERY_RETRY_BASE_DELAY_MS: u64 = 1000;
+
+/// 连续失败时轮询间隔的最大倍数
+const MAX_BACKOFF_MULTIPLIER: u32 = 32;
+
+/// 每个地址每轮轮询最多翻页数，积压更多时留到下一轮
+const MAX_PAGES_PER_POLL: usize = 5;
+
+/// 未设置 batch_size 时每页查询的交易数
+const DEFAULT_BATCH_SIZE: u32 = 10;
+
+/// 事件通道默认容量
+pub const DEFAULT_EVENT_CHANNEL_CAPACITY: usize = 1000;
+
+/// 有界事件通道的发送端
+///
+/// 通道满时等待消费者腾出空间，背压传导到轮询循环（轮询随之变慢），不丢弃事件。
+/// 克隆共享同一等待计数，多个网络的监控器共用一条管道时计数合并。
+#[derive(Debug, Clone)]
+pub struct EventSender {
+    sender: mpsc::Sender<TransferEvent>,
+    backpressure_waits: Arc<AtomicU64>,
+}
+
+/// 创建容量为 `capacity`（至少为 1）的事件通道
+pub fn event_channel(capacity: usize) -> (EventSender, mpsc::Receiver<TransferEvent>) {
+    let (sender, receiver) = mpsc::channel(capacity.max(1));
+    let sender = EventSender {
+        sender,
+        backpressure_waits: Arc::new(AtomicU64::new(0)),
+    };
+    (sender, receiver)
+}
+
+impl EventSender {
+    /// 发送事件，通道满时等待；接收端已关闭时返回错误
+    pub async fn send(&self, event: TransferEvent) -> TrackerResult<()> {
+        let event = match self.sender.try_send(event) {
+            Ok(()) => return Ok(()),
+            Err(mpsc::error::TrySendError::Full(event)) => event,
+            Err(mpsc::error::TrySendError::Closed(_)) => {
+                return Err(TrackerError::sui_client_error("Event channel closed"));
+            }
+        };
+
+        let waits = self.backpressure_waits.fetch_add(1, Ordering::Relaxed) + 1;
+        log::debug!("Event channel full ({} queued), waiting for the processor ({} waits so far)", self.queued(), waits);
+        self.sender.send(event).await
+            .map_err(|_| TrackerError::sui_client_error("Event channel closed"))
+    }
+
+    /// 通道中等待处理的事件数
+    pub fn queued(&self) -> usize {
+        self.sender.max_capacity() - self.sender.capacity()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.sender.max_capacity()
+    }
+
+    /// 因通道已满而等待的次数
+    pub fn backpressure_waits(&self) -> u64 {
+        self.backpressure_waits.load(Ordering::Relaxed)
+    }
+}
+
+/// 地址的查询进度
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AddressCursor {
+    /// 最后处理的交易digest，下次只查询其后的交易
+    pub last_digest: Option<String>,
+    /// 最后一次成功轮询的时间（Unix秒）
+    pub last_checked: u64,
+}
+
+/// 事件查询的重试策略
+#[derive(Debug, Clone, Copy)]
+struct QueryRetry {
+    max_retries: u32,
+    base_delay_ms: u64,
+}
+
+/// 地址连续查询失败的退避状态，查询成功后清除
+#[derive(Debug, Clone)]
+pub struct AddressBackoff {
+    pub consecutive_failures: u32,
+    pub next_check: Instant,
+    pub last_error: String,
+    /// 是否已通过 [`EventMonitor::newly_failing_addresses`] 报告过，查询成功后随状态一起清除
+    pub alerted: bool,
+}
 
 #[derive(Debug, Clone)]
 pub struct EventMonitor {
-    sui_client: Arc<SuiClient>,
+    sui_client: Arc<dyn SuiRpc>,
     poll_interval: Duration,
     addresses: Arc<RwLock<HashSet<String>>>,
-    event_sender: mpsc::UnboundedSender<TransferEvent>,
-    address_last_checked: Arc<RwLock<HashMap<String, u64>>>,
+    event_sender: EventSender,
+    address_cursors: Arc<RwLock<HashMap<String, AddressCursor>>>,
+    /// 按包/模块监控的过滤条件及各自的事件游标
+    package_filters: Arc<RwLock<HashMap<PackageFilter, Option<String>>>>,
     running: Arc<RwLock<bool>>,
+    /// 暂停时轮询循环跳过查询，进度与统计保持不变
+    paused: Arc<RwLock<bool>>,
+    check_permits: Arc<Semaphore>,
+    address_backoff: Arc<RwLock<HashMap<String, AddressBackoff>>>,
+    query_retry: QueryRetry,
+    /// 交易所在检查点距最新检查点不足该深度时标记为 Pending，0 表示不检查
+    finality_depth: u64,
+    /// 每个地址每次查询的交易数
+    batch_size: u32,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -29,6 +142,15 @@ pub struct TransferEvent {
     pub timestamp: u64,
     pub block_number: u64,
     pub event_type: String,
+    /// 发送方支付的gas（MIST），未知时为 None
+    #[serde(default)]
+    pub gas_used: Option<u64>,
+    /// 链上执行结果
+    #[serde(default)]
+    pub status: TransactionStatus,
+    /// 来源网络名称
+    #[serde(default)]
+    pub network: String,
 }
 
 #[derive(Debug, Clone)]
@@ -42,27 +164,81 @@ pub struct MonitorStats {
 
 impl EventMonitor {
     pub async fn new(
-        sui_client: Arc<SuiClient>,
+        sui_client: Arc<dyn SuiRpc>,
+        poll_interval: Duration,
+    ) -> (Self, mpsc::Receiver<TransferEvent>) {
+        Self::with_capacity(sui_client, poll_interval, DEFAULT_EVENT_CHANNEL_CAPACITY).await
+    }
+
+    /// 创建监控器及容量为 `capacity` 的事件通道
+    pub async fn with_capacity(
+        sui_client: Arc<dyn SuiRpc>,
+        poll_interval: Duration,
+        capacity: usize,
+    ) -> (Self, mpsc::Receiver<TransferEvent>) {
+        let (event_sender, event_receiver) = event_channel(capacity);
+        let monitor = Self::with_sender(sui_client, poll_interval, event_sender).await;
+        (monitor, event_receiver)
+    }
+
+    /// 创建向已有通道发送事件的监控器，用于多个网络共用一条事件管道
+    pub async fn with_sender(
+        sui_client: Arc<dyn SuiRpc>,
         poll_interval: Duration,
-    ) -> (Self, mpsc::UnboundedReceiver<TransferEvent>) {
-        let (event_sender, event_receiver) = mpsc::unbounded_channel();
-        let monitor = Self {
+        event_sender: EventSender,
+    ) -> Self {
+        Self {
             sui_client,
             poll_interval,
             addresses: Arc::new(RwLock::new(HashSet::new())),
             event_sender,
-            address_last_checked: Arc::new(RwLock::new(HashMap::new())),
+            address_cursors: Arc::new(RwLock::new(HashMap::new())),
+            package_filters: Arc::new(RwLock::new(HashMap::new())),
             running: Arc::new(RwLock::new(false)),
-        };
-        (monitor, event_receiver)
+            paused: Arc::new(RwLock::new(false)),
+            check_permits: Arc::new(Semaphore::new(DEFAULT_MAX_CONCURRENT_CHECKS)),
+            address_backoff: Arc::new(RwLock::new(HashMap::new())),
+            query_retry: QueryRetry {
+                max_retries: DEFAULT_QUERY_MAX_RETRIES,
+                base_delay_ms: DEFAULT_QUERY_RETRY_BASE_DELAY_MS,
+            },
+            finality_depth: 0,
+            batch_size: DEFAULT_BATCH_SIZE,
+        }
+    }
+
+    pub fn event_sender(&self) -> EventSender {
+        self.event_sender.clone()
+    }
+
+    pub fn sui_client(&self) -> &Arc<dyn SuiRpc> {
+        &self.sui_client
+    }
+
+    /// 限制每轮轮询中同时查询的地址数，需在 start_monitoring 之前设置
+    pub fn set_max_concurrent_checks(&mut self, max_concurrent_checks: usize) {
+        self.check_permits = Arc::new(Semaphore::new(max_concurrent_checks.max(1)));
+    }
+
+    /// 设置事件查询的重试次数和基础延迟，需在 start_monitoring 之前设置
+    pub fn set_query_retry(&mut self, max_retries: u32, base_delay_ms: u64) {
+        self.query_retry = QueryRetry { max_retries, base_delay_ms };
+    }
+
+    /// 设置最终性深度（检查点数），需在 start_monitoring 之前设置
+    pub fn set_finality_depth(&mut self, finality_depth: u64) {
+        self.finality_depth = finality_depth;
+    }
+
+    /// 设置每个地址每次查询的交易数，需在 start_monitoring 之前设置
+    pub fn set_batch_size(&mut self, batch_size: u32) {
+        self.batch_size = batch_size.max(1);
     }
 
     pub async fn add_address(&self, address: String) -> TrackerResult<()> {
-        if !crate::config::Config::is_valid_sui_address(&address) {
-            return Err(TrackerError::invalid_address(
-                format!("Invalid SUI address: {}", address)
-            ));
-        }
+        let address = crate::config::Config::normalize_address(&address).ok_or_else(|| {
+            TrackerError::invalid_address(format!("Invalid SUI address: {}", address))
+        })?;
 
         let mut addresses = self.addresses.write().await;
         let was_new = addresses.insert(address.clone());
@@ -70,34 +246,114 @@ impl EventMonitor {
         if was_new {
             log::info!("Added new address to monitor: {}", address);
             
-            // 初始化最后检查时间
-            let mut last_checked = self.address_last_checked.write().await;
-            last_checked.insert(address, 0);
+            // 初始化查询进度
+            let mut cursors = self.address_cursors.write().await;
+            cursors.insert(address, AddressCursor::default());
         }
 
         Ok(())
     }
 
+    /// 监控包（`0xPACKAGE`）或模块（`0xPACKAGE::module`）发出的所有转移事件，与地址监控同时进行
+    pub async fn add_package_filter(&self, filter: String) -> TrackerResult<()> {
+        let parsed = PackageFilter::parse(&filter).ok_or_else(|| {
+            TrackerError::validation_error(format!("Invalid package filter: {}", filter))
+        })?;
+
+        if let std::collections::hash_map::Entry::Vacant(entry) = self.package_filters.write().await.entry(parsed) {
+            log::info!("Added package filter to monitor: {}", entry.key());
+            entry.insert(None);
+        }
+        Ok(())
+    }
+
+    pub async fn remove_package_filter(&self, filter: &str) -> TrackerResult<()> {
+        if let Some(parsed) = PackageFilter::parse(filter) {
+            if self.package_filters.write().await.remove(&parsed).is_some() {
+                log::info!("Removed package filter from monitoring: {}", parsed);
+            }
+        }
+        Ok(())
+    }
+
+    pub async fn get_package_filters(&self) -> Vec<String> {
+        let mut filters: Vec<String> = self.package_filters.read().await.keys().map(|f| f.to_string()).collect();
+        filters.sort();
+        filters
+    }
+
     pub async fn remove_address(&self, address: &str) -> TrackerResult<()> {
+        let address = &crate::config::Config::normalize_address(address).unwrap_or_else(|| address.to_string());
         let mut addresses = self.addresses.write().await;
         let removed = addresses.remove(address);
         
         if removed {
             log::info!("Removed address from monitoring: {}", address);
             
-            // 移除最后检查时间
-            let mut last_checked = self.address_last_checked.write().await;
-            last_checked.remove(address);
+            // 移除查询进度
+            let mut cursors = self.address_cursors.write().await;
+            cursors.remove(address);
+            self.address_backoff.write().await.remove(address);
         }
 
         Ok(())
     }
 
+    /// 地址是否在监控中，地址按规范形式比较
+    pub async fn is_monitoring(&self, address: &str) -> bool {
+        let address = crate::config::Config::normalize_address(address).unwrap_or_else(|| address.to_string());
+        self.addresses.read().await.contains(&address)
+    }
+
     pub async fn get_monitored_addresses(&self) -> Vec<String> {
         let addresses = self.addresses.read().await;
         addresses.iter().cloned().collect()
     }
 
+    /// 当前各地址的查询进度
+    pub async fn address_cursors(&self) -> HashMap<String, AddressCursor> {
+        self.address_cursors.read().await.clone()
+    }
+
+    /// 各包/模块过滤条件的事件游标，尚未查询过的条件不包含在内
+    pub async fn package_cursors(&self) -> HashMap<String, String> {
+        self.package_filters.read().await
+            .iter()
+            .filter_map(|(filter, cursor)| cursor.clone().map(|cursor| (filter.to_string(), cursor)))
+            .collect()
+    }
+
+    /// 恢复上次运行保存的查询进度，只应用于已在监控的地址和过滤条件，返回恢复的条目数
+    pub async fn restore_cursors(
+        &self,
+        address_cursors: &HashMap<String, AddressCursor>,
+        package_cursors: &HashMap<String, String>,
+    ) -> usize {
+        let mut restored = 0;
+
+        let addresses = self.addresses.read().await;
+        let mut cursors = self.address_cursors.write().await;
+        for (address, cursor) in address_cursors {
+            let Some(address) = crate::config::Config::normalize_address(address) else {
+                continue;
+            };
+            if addresses.contains(&address) {
+                cursors.insert(address, cursor.clone());
+                restored += 1;
+            }
+        }
+
+        let mut package_filters = self.package_filters.write().await;
+        for (filter, cursor) in package_cursors {
+            if let Some(entry) = PackageFilter::parse(filter).and_then(|filter| package_filters.get_mut(&filter)) {
+                *entry = Some(cursor.clone());
+                restored += 1;
+            }
+        }
+
+        restored
+    }
+
     pub async fn start_monitoring(&self) {
         let mut running = self.running.write().await;
         if *running {
@@ -113,22 +369,40 @@ impl EventMonitor {
         let sui_client = self.sui_client.clone();
         let event_sender = self.event_sender.clone();
         let poll_interval = self.poll_interval;
-        let address_last_checked = self.address_last_checked.clone();
+        let address_cursors = self.address_cursors.clone();
+        let package_filters = self.package_filters.clone();
+        let check_permits = self.check_permits.clone();
+        let address_backoff = self.address_backoff.clone();
+        let query_retry = self.query_retry;
+        let finality_depth = self.finality_depth;
+        let batch_size = self.batch_size;
+        let paused = self.paused.clone();
 
         tokio::spawn(async move {
             let mut interval_timer = interval(poll_interval);
             
             loop {
                 interval_timer.tick().await;
+                if *paused.read().await {
+                    continue;
+                }
                 
                 if let Err(e) = Self::check_new_events_for_addresses(
                     &sui_client,
                     &addresses,
                     &event_sender,
-                    &address_last_checked,
+                    &address_cursors,
+                    &check_permits,
+                    &address_backoff,
+                    poll_interval,
+                    query_retry,
+                    finality_depth,
+                    batch_size,
                 ).await {
                     log::error!("Error checking new events: {}", e);
                 }
+
+                Self::check_package_events(&sui_client, &package_filters, &event_sender, query_retry, batch_size).await;
             }
         });
     }
@@ -143,67 +417,277 @@ impl EventMonitor {
         *self.running.read().await
     }
 
+    /// 暂停轮询，不清除查询进度，恢复后从上次的游标继续
+    pub async fn pause_monitoring(&self) {
+        let mut paused = self.paused.write().await;
+        if !*paused {
+            *paused = true;
+            log::info!("Event monitoring paused");
+        }
+    }
+
+    pub async fn resume_monitoring(&self) {
+        let mut paused = self.paused.write().await;
+        if *paused {
+            *paused = false;
+            log::info!("Event monitoring resumed");
+        }
+    }
+
+    pub async fn is_paused(&self) -> bool {
+        *self.paused.read().await
+    }
+
+    #[allow(clippy::too_many_arguments)]
     async fn check_new_events_for_addresses(
-        sui_client: &Arc<SuiClient>,
+        sui_client: &Arc<dyn SuiRpc>,
         addresses: &Arc<RwLock<HashSet<String>>>,
-        event_sender: &mpsc::UnboundedSender<TransferEvent>,
-        address_last_checked: &Arc<RwLock<HashMap<String, u64>>>,
+        event_sender: &EventSender,
+        address_cursors: &Arc<RwLock<HashMap<String, AddressCursor>>>,
+        check_permits: &Arc<Semaphore>,
+        address_backoff: &Arc<RwLock<HashMap<String, AddressBackoff>>>,
+        poll_interval: Duration,
+        query_retry: QueryRetry,
+        finality_depth: u64,
+        batch_size: u32,
     ) -> TrackerResult<()> {
+        // 跳过仍处于退避期的地址
         let addresses_list = {
             let addresses = addresses.read().await;
-            addresses.iter().cloned().collect::<Vec<_>>()
+            let backoff = address_backoff.read().await;
+            let now = Instant::now();
+            addresses.iter()
+                .filter(|address| backoff.get(*address).is_none_or(|b| b.next_check <= now))
+                .cloned()
+                .collect::<Vec<_>>()
         };
 
         if addresses_list.is_empty() {
             return Ok(());
         }
 
-        // 并行检查所有地址
-        let mut tasks = Vec::new();
-        for address in addresses_list {
+        let latest_checkpoint = Self::latest_checkpoint(sui_client.as_ref(), finality_depth).await;
+
+        // 并行检查所有地址，同时进行的查询数受信号量限制
+        let sui_client = sui_client.clone();
+        let event_sender = event_sender.clone();
+        let address_cursors = address_cursors.clone();
+        let address_backoff = address_backoff.clone();
+
+        Self::run_bounded(addresses_list, check_permits.clone(), move |address| {
             let sui_client = sui_client.clone();
             let event_sender = event_sender.clone();
-            let address_last_checked = address_last_checked.clone();
+            let address_cursors = address_cursors.clone();
+            let address_backoff = address_backoff.clone();
 
-            let task = tokio::spawn(async move {
-                let result = utils::retry_operation(
-                    || {
-                        sui_client.query_transfer_events(&address, 10)
-                    },
-                    3,
-                    1000,
-                ).await;
+            async move {
+                let cursor = address_cursors.read().await
+                    .get(&address)
+                    .and_then(|c| c.last_digest.clone());
+                let result = Self::fetch_events_since(sui_client.as_ref(), &address, cursor, query_retry, batch_size).await;
 
                 match result {
-                    Ok(events) => {
-                        let mut last_checked = address_last_checked.write().await;
-                        let current_time = Utc::now().timestamp() as u64;
-                        let last_time = last_checked.get(&address).copied().unwrap_or(0);
-                        
+                    Ok((events, cursor)) => {
+                        address_backoff.write().await.remove(&address);
+
                         let mut new_events = 0;
                         for event in events {
-                            if event.timestamp > last_time {
-                                if let Ok(transfer_event) = Self::parse_transfer_event(event) {
-                                    if let Err(e) = event_sender.send(transfer_event) {
-                                        log::error!("Failed to send transfer event: {}", e);
-                                    }
-                                    new_events += 1;
+                            if let Some(mut transfer_event) = Self::parse_transfer_event(event) {
+                                Self::apply_finality(&mut transfer_event, latest_checkpoint, finality_depth);
+                                if let Err(e) = event_sender.send(transfer_event).await {
+                                    log::error!("Failed to send transfer event: {}", e);
                                 }
+                                new_events += 1;
                             }
                         }
-                        
+
+                        // 无论是否有新事件都推进进度
+                        address_cursors.write().await.insert(address.clone(), AddressCursor {
+                            last_digest: cursor,
+                            last_checked: Utc::now().timestamp() as u64,
+                        });
+
                         if new_events > 0 {
-                            last_checked.insert(address.clone(), current_time);
                             log::debug!("Found {} new events for address {}", new_events, address);
                         }
                     }
                     Err(e) => {
-                        log::error!("Failed to query events for address {}: {}", address, e);
+                        let mut backoff = address_backoff.write().await;
+                        let state = backoff.entry(address.clone()).or_insert_with(|| AddressBackoff {
+                            consecutive_failures: 0,
+                            next_check: Instant::now(),
+                            last_error: String::new(),
+                            alerted: false,
+                        });
+                        state.consecutive_failures += 1;
+                        let delay = Self::backoff_delay(poll_interval, state.consecutive_failures);
+                        state.next_check = Instant::now() + delay;
+                        state.last_error = e.to_string();
+                        log::error!(
+                            "Failed to query events for address {} ({} consecutive failures, next check in {:?}): {}",
+                            address, state.consecutive_failures, delay, e
+                        );
                     }
                 }
-            });
+            }
+        }).await;
+
+        Ok(())
+    }
+
+    /// 按包/模块过滤条件逐页查询新事件并发送到事件通道，单个过滤条件失败不影响其他条件
+    async fn check_package_events(
+        sui_client: &Arc<dyn SuiRpc>,
+        package_filters: &Arc<RwLock<HashMap<PackageFilter, Option<String>>>>,
+        event_sender: &EventSender,
+        query_retry: QueryRetry,
+        batch_size: u32,
+    ) {
+        let filters: Vec<(PackageFilter, Option<String>)> = package_filters.read().await
+            .iter()
+            .map(|(filter, cursor)| (filter.clone(), cursor.clone()))
+            .collect();
+
+        for (filter, mut cursor) in filters {
+            let mut new_events = 0;
+            for _ in 0..MAX_PAGES_PER_POLL {
+                let page = match utils::retry_operation(
+                    || sui_client.query_package_events_after(&filter, cursor.as_deref(), batch_size),
+                    query_retry.max_retries,
+                    query_retry.base_delay_ms,
+                ).await {
+                    Ok(page) => page,
+                    Err(e) => {
+                        log::error!("Failed to query events for package filter {}: {}", filter, e);
+                        break;
+                    }
+                };
+
+                for raw in &page.events {
+                    if let Some(mut transfer_event) = Self::parse_raw_event(raw) {
+                        transfer_event.network = sui_client.network_name().to_string();
+                        if let Err(e) = event_sender.send(transfer_event).await {
+                            log::error!("Failed to send transfer event: {}", e);
+                        }
+                        new_events += 1;
+                    }
+                }
+
+                cursor = page.cursor;
+                if !page.has_next_page {
+                    break;
+                }
+            }
+
+            // 查询期间过滤条件可能已被移除
+            if let Some(entry) = package_filters.write().await.get_mut(&filter) {
+                *entry = cursor;
+            }
+            if new_events > 0 {
+                log::debug!("Found {} new events for package filter {}", new_events, filter);
+            }
+        }
+    }
+
+    /// 从游标之后逐页查询事件，返回按时间排列的事件和新的游标
+    async fn fetch_events_since(
+        sui_client: &dyn SuiRpc,
+        address: &str,
+        mut cursor: Option<String>,
+        query_retry: QueryRetry,
+        batch_size: u32,
+    ) -> TrackerResult<(Vec<SuiEvent>, Option<String>)> {
+        let mut events = Vec::new();
+        for _ in 0..MAX_PAGES_PER_POLL {
+            let page = utils::retry_operation(
+                || sui_client.query_transfer_events_after(address, cursor.as_deref(), batch_size),
+                query_retry.max_retries,
+                query_retry.base_delay_ms,
+            ).await?;
+
+            events.extend(page.events);
+            cursor = page.cursor;
+            if !page.has_next_page {
+                break;
+            }
+        }
+        Ok((events, cursor))
+    }
 
-            tasks.push(task);
+    /// 需要检查最终性时查询最新检查点，查询失败返回 None（本轮事件都视为未最终确认）
+    async fn latest_checkpoint(sui_client: &dyn SuiRpc, finality_depth: u64) -> Option<u64> {
+        if finality_depth == 0 {
+            return None;
+        }
+        match sui_client.get_latest_checkpoint().await {
+            Ok(checkpoint) => Some(checkpoint),
+            Err(e) => {
+                log::warn!("Failed to get latest checkpoint, treating new transfers as pending: {}", e);
+                None
+            }
+        }
+    }
+
+    /// 检查点距最新检查点不足 `finality_depth` 的事件（或检查点未知）标记为 Pending
+    fn apply_finality(event: &mut TransferEvent, latest_checkpoint: Option<u64>, finality_depth: u64) {
+        if finality_depth == 0 {
+            return;
+        }
+        let is_final = event.block_number > 0
+            && latest_checkpoint.is_some_and(|latest| latest >= event.block_number.saturating_add(finality_depth));
+        if !is_final {
+            event.status = TransactionStatus::Pending;
+        }
+    }
+
+    /// 连续失败 n 次后的下次检查延迟：轮询间隔的 2^n 倍，最多 MAX_BACKOFF_MULTIPLIER 倍
+    fn backoff_delay(poll_interval: Duration, consecutive_failures: u32) -> Duration {
+        let multiplier = 2u32
+            .checked_pow(consecutive_failures)
+            .unwrap_or(u32::MAX)
+            .min(MAX_BACKOFF_MULTIPLIER);
+        poll_interval * multiplier
+    }
+
+    /// 连续失败次数达到 min_failures 的地址
+    pub async fn failing_addresses(&self, min_failures: u32) -> Vec<(String, AddressBackoff)> {
+        let backoff = self.address_backoff.read().await;
+        backoff.iter()
+            .filter(|(_, state)| state.consecutive_failures >= min_failures)
+            .map(|(address, state)| (address.clone(), state.clone()))
+            .collect()
+    }
+
+    /// 连续失败次数首次达到 min_failures 的地址，每段连续失败只返回一次
+    pub async fn newly_failing_addresses(&self, min_failures: u32) -> Vec<(String, AddressBackoff)> {
+        let mut backoff = self.address_backoff.write().await;
+        backoff.iter_mut()
+            .filter(|(_, state)| !state.alerted && state.consecutive_failures >= min_failures)
+            .map(|(address, state)| {
+                state.alerted = true;
+                (address.clone(), state.clone())
+            })
+            .collect()
+    }
+
+    /// 为每个地址启动一个任务，任务在执行 check 前先获取许可
+    async fn run_bounded<F, Fut>(addresses: Vec<String>, permits: Arc<Semaphore>, check: F)
+    where
+        F: Fn(String) -> Fut,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let mut tasks = Vec::new();
+        for address in addresses {
+            let permits = permits.clone();
+            let check = check(address);
+
+            tasks.push(tokio::spawn(async move {
+                let _permit = match permits.acquire_owned().await {
+                    Ok(permit) => permit,
+                    Err(_) => return, // 信号量已关闭
+                };
+                check.await;
+            }));
         }
 
         // 等待所有任务完成
@@ -212,37 +696,112 @@ impl EventMonitor {
                 log::error!("Task execution failed: {}", e);
             }
         }
-
-        Ok(())
     }
 
-    fn parse_transfer_event(event: SuiEvent) -> TrackerResult<TransferEvent> {
-        // 使用新的SuiEvent结构，直接获取字段
+    /// 将 SuiEvent 转换为转移事件，没有接收方的事件不是转移，返回 None
+    pub(crate) fn parse_transfer_event(event: SuiEvent) -> Option<TransferEvent> {
         let amount = event.amount;
-        let recipient = event.recipient.clone();
+        // 地址统一为规范形式，避免大小写或省略前导零导致同一地址被分开统计
+        let canonical = |address: &str| crate::config::Config::normalize_address(address).unwrap_or_else(|| address.to_string());
+        let sender = canonical(&event.sender);
+        let recipient = canonical(&event.recipient);
         let token_type = event.token_type.clone();
-        let event_type = "transfer".to_string();
+        let event_type = if event.gas_only { "gas" } else { "transfer" }.to_string();
 
         if recipient.is_empty() {
-            return Err(TrackerError::parse_error(
-                "Could not extract recipient from event"
-            ));
+            log::debug!("Skipping event {} without a recipient", event.id);
+            return None;
         }
 
-        Ok(TransferEvent {
+        Some(TransferEvent {
             transaction_id: event.id.clone(),
             package_id: event.package_id,
             transaction_module: event.transaction_module,
-            sender: event.sender,
+            sender,
             recipient,
             amount,
             token_type,
             timestamp: event.timestamp,
             block_number: event.block_number,
             event_type,
+            gas_used: event.gas_used,
+            status: event.status,
+            network: event.network,
+        })
+    }
+
+    /// 解析 suix_queryEvents 返回的原始事件 JSON
+    ///
+    /// 支持以下 parsedJson 形式，非转移事件返回 None：
+    /// - `{"value": {"amount", "recipient", "type"}}`（pay 模块事件）
+    /// - `{"amount", "recipient" | "to" | "receiver"}`，代币类型取自事件类型的泛型参数（coin 事件）
+    /// - `{"balance": {"value"}, "recipient" | "to" | "owner"}`（balance 事件）
+    pub fn parse_raw_event(raw: &serde_json::Value) -> Option<TransferEvent> {
+        let parsed = raw.get("parsedJson")?;
+        let event_type = raw.get("type").and_then(|t| t.as_str()).unwrap_or_default();
+        let str_field = |value: &serde_json::Value, keys: &[&str]| {
+            keys.iter().find_map(|key| value.get(*key).and_then(|v| v.as_str()).map(str::to_string))
+        };
+
+        let (amount, recipient, token_type) = if let Some(value) = parsed.get("value").filter(|v| v.is_object()) {
+            (
+                Self::json_amount(value.get("amount")?)?,
+                str_field(value, &["recipient"])?,
+                str_field(value, &["type", "coin_type"]),
+            )
+        } else if let Some(balance) = parsed.get("balance") {
+            (
+                Self::json_amount(balance)?,
+                str_field(parsed, &["recipient", "to", "owner"])?,
+                str_field(parsed, &["coin_type"]),
+            )
+        } else {
+            (
+                Self::json_amount(parsed.get("amount")?)?,
+                str_field(parsed, &["recipient", "to", "receiver"])?,
+                str_field(parsed, &["coin_type", "type"]),
+            )
+        };
+
+        let token_type = token_type
+            .or_else(|| Self::type_parameter(event_type).map(str::to_string))
+            .unwrap_or_else(|| "0x2::sui::SUI".to_string());
+
+        Self::parse_transfer_event(SuiEvent {
+            id: raw.pointer("/id/txDigest").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+            package_id: str_field(raw, &["packageId"]).unwrap_or_default(),
+            transaction_module: str_field(raw, &["transactionModule"]).unwrap_or_default(),
+            sender: str_field(raw, &["sender"]).unwrap_or_default(),
+            recipient,
+            amount,
+            token_type,
+            // timestampMs 为毫秒
+            timestamp: raw.get("timestampMs").and_then(Self::json_amount).map(|ms| ms / 1000).unwrap_or(0),
+            block_number: 0,
+            gas_used: None,
+            status: TransactionStatus::Success,
+            network: String::new(),
+            gas_only: false,
         })
     }
 
+    /// 金额可能是数字、字符串，或 Balance 形式的 `{"value": ...}`
+    fn json_amount(value: &serde_json::Value) -> Option<u64> {
+        match value {
+            serde_json::Value::Number(n) => n.as_u64(),
+            serde_json::Value::String(s) => s.parse().ok(),
+            serde_json::Value::Object(_) => value.get("value").and_then(Self::json_amount),
+            _ => None,
+        }
+    }
+
+    /// 事件类型的泛型参数，如 `0x2::coin::Transfer<0x2::sui::SUI>` 中的 `0x2::sui::SUI`
+    fn type_parameter(event_type: &str) -> Option<&str> {
+        let start = event_type.find('<')?;
+        let end = event_type.rfind('>')?;
+        (end > start + 1).then(|| &event_type[start + 1..end])
+    }
+
     pub async fn get_stats(&self) -> MonitorStats {
         // 这是一个简化的统计信息实现
         // 在实际应用中，你可能需要维护更详细的统计信息
@@ -256,16 +815,28 @@ impl EventMonitor {
         }
     }
 
+    /// 立即查询所有地址；事件通道满时会等待，需有消费者在接收事件
+    ///
+    /// 与轮询一样从各地址的查询进度之后查询并推进进度，不会重复发送已处理的交易。
     pub async fn force_check_all_addresses(&self) -> TrackerResult<u64> {
         let addresses = self.addresses.read().await;
         let mut total_events = 0;
+        let latest_checkpoint = Self::latest_checkpoint(self.sui_client.as_ref(), self.finality_depth).await;
 
         for address in addresses.iter() {
-            match self.sui_client.query_transfer_events(address, 50).await {
-                Ok(events) => {
+            let cursor = self.address_cursors.read().await
+                .get(address)
+                .and_then(|c| c.last_digest.clone());
+            match Self::fetch_events_since(self.sui_client.as_ref(), address, cursor, self.query_retry, self.batch_size).await {
+                Ok((events, cursor)) => {
+                    self.address_cursors.write().await.insert(address.clone(), AddressCursor {
+                        last_digest: cursor,
+                        last_checked: Utc::now().timestamp() as u64,
+                    });
                     for event in events {
-                        if let Ok(transfer_event) = Self::parse_transfer_event(event) {
-                            if let Err(e) = self.event_sender.send(transfer_event) {
+                        if let Some(mut transfer_event) = Self::parse_transfer_event(event) {
+                            Self::apply_finality(&mut transfer_event, latest_checkpoint, self.finality_depth);
+                            if let Err(e) = self.event_sender.send(transfer_event).await {
                                 log::error!("Failed to send transfer event: {}", e);
                             } else {
                                 total_events += 1;
@@ -306,6 +877,7 @@ impl EventMonitor {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::sui_client::SuiClient;
     use tokio::time::sleep;
 
     #[tokio::test]
@@ -341,6 +913,22 @@ mod tests {
         assert_eq!(monitor.get_monitored_addresses().await.len(), 0);
     }
 
+    #[tokio::test]
+    async fn test_add_address_normalizes() {
+        let sui_client = Arc::new(
+            SuiClient::new("https://fullnode.mainnet.sui.io:443").await.unwrap()
+        );
+        let (monitor, _receiver) = EventMonitor::new(sui_client, Duration::from_secs(10)).await;
+
+        // 省略前导零和大小写不同的写法视为同一地址
+        monitor.add_address("0xABC".to_string()).await.unwrap();
+        monitor.add_address(format!("0x{}abc", "0".repeat(61))).await.unwrap();
+        assert_eq!(monitor.get_monitored_addresses().await, vec![format!("0x{}abc", "0".repeat(61))]);
+
+        monitor.remove_address("0xaBc").await.unwrap();
+        assert!(monitor.get_monitored_addresses().await.is_empty());
+    }
+
     #[tokio::test]
     async fn test_validate_addresses() {
         let sui_client = Arc::new(
@@ -356,34 +944,141 @@ mod tests {
         assert_eq!(invalid.len(), 0); // 因为无效地址不会被添加
     }
 
+    #[tokio::test]
+    async fn test_run_bounded_limits_concurrency() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let active = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+        let completed = Arc::new(AtomicUsize::new(0));
+        let addresses: Vec<String> = (0..50).map(|i| format!("0x{:064x}", i)).collect();
+
+        let (active_ref, peak_ref, completed_ref) = (active.clone(), peak.clone(), completed.clone());
+        EventMonitor::run_bounded(addresses, Arc::new(Semaphore::new(4)), move |_address| {
+            let active = active_ref.clone();
+            let peak = peak_ref.clone();
+            let completed = completed_ref.clone();
+            async move {
+                let now_active = active.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now_active, Ordering::SeqCst);
+                sleep(Duration::from_millis(5)).await;
+                active.fetch_sub(1, Ordering::SeqCst);
+                completed.fetch_add(1, Ordering::SeqCst);
+            }
+        }).await;
+
+        assert_eq!(completed.load(Ordering::SeqCst), 50);
+        assert!(peak.load(Ordering::SeqCst) <= 4);
+        assert!(peak.load(Ordering::SeqCst) > 1);
+    }
+
+    #[tokio::test]
+    async fn test_newly_failing_addresses_reported_once() {
+        let client = Arc::new(crate::mock_sui_client::MockSuiClient::new());
+        let (monitor, _receiver) = EventMonitor::new(client, Duration::from_secs(10)).await;
+        let failing = |consecutive_failures| AddressBackoff {
+            consecutive_failures,
+            next_check: Instant::now(),
+            last_error: "boom".to_string(),
+            alerted: false,
+        };
+        monitor.address_backoff.write().await.insert("0xa".to_string(), failing(4));
+        assert!(monitor.newly_failing_addresses(5).await.is_empty());
+
+        monitor.address_backoff.write().await.get_mut("0xa").unwrap().consecutive_failures = 5;
+        assert_eq!(monitor.newly_failing_addresses(5).await.len(), 1);
+        // 仍在失败但已报告过
+        monitor.address_backoff.write().await.get_mut("0xa").unwrap().consecutive_failures = 6;
+        assert!(monitor.newly_failing_addresses(5).await.is_empty());
+        assert_eq!(monitor.failing_addresses(5).await.len(), 1);
+
+        // 查询成功后状态清除，再次达到阈值时重新报告
+        monitor.address_backoff.write().await.remove("0xa");
+        monitor.address_backoff.write().await.insert("0xa".to_string(), failing(5));
+        assert_eq!(monitor.newly_failing_addresses(5).await.len(), 1);
+    }
+
+    #[test]
+    fn test_backoff_delay() {
+        let poll = Duration::from_secs(10);
+        assert_eq!(EventMonitor::backoff_delay(poll, 1), Duration::from_secs(20));
+        assert_eq!(EventMonitor::backoff_delay(poll, 3), Duration::from_secs(80));
+        assert_eq!(EventMonitor::backoff_delay(poll, 5), Duration::from_secs(320));
+        assert_eq!(EventMonitor::backoff_delay(poll, 6), Duration::from_secs(320));
+        assert_eq!(EventMonitor::backoff_delay(poll, 40), Duration::from_secs(320));
+    }
+
     #[test]
     fn test_parse_transfer_event() {
-        // 创建一个模拟的SuiEvent进行测试
-        let event = SuiEvent {
-            id: crate::sui_client::EventId {
-                tx_digest: "0x1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef".to_string(),
-                event_seq: 1,
+        // pay 模块事件：字段嵌套在 value 中
+        let event = serde_json::json!({
+            "id": {
+                "txDigest": "0x1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef",
+                "eventSeq": "1"
             },
-            package_id: "0x0000000000000000000000000000000000000000000000000000000000000002".to_string(),
-            transaction_module: "pay".to_string(),
-            sender: "0x1234567890abcdef1234567890abcdef12345678".to_string(),
-            timestamp: 1634567890,
-            parsed_json: serde_json::json!({
+            "packageId": "0x0000000000000000000000000000000000000000000000000000000000000002",
+            "transactionModule": "pay",
+            "sender": "0x1234567890abcdef1234567890abcdef12345678",
+            "type": "0x2::pay::PayEvent",
+            "timestampMs": "1634567890000",
+            "parsedJson": {
                 "value": {
                     "amount": "1000000000",
                     "recipient": "0xabcdef1234567890abcdef1234567890abcdef12",
                     "type": "0x2::sui::SUI"
                 }
-            }),
-        };
+            }
+        });
 
-        let result = EventMonitor::parse_transfer_event(event);
-        assert!(result.is_ok());
-        
-        let transfer_event = result.unwrap();
+        let transfer_event = EventMonitor::parse_raw_event(&event).unwrap();
         assert_eq!(transfer_event.amount, 1000000000);
-        assert_eq!(transfer_event.sender, "0x1234567890abcdef1234567890abcdef12345678");
-        assert_eq!(transfer_event.recipient, "0xabcdef1234567890abcdef1234567890abcdef12");
+        assert_eq!(transfer_event.sender, format!("0x{:0>64}", "1234567890abcdef1234567890abcdef12345678"));
+        assert_eq!(transfer_event.recipient, format!("0x{:0>64}", "abcdef1234567890abcdef1234567890abcdef12"));
+        assert_eq!(transfer_event.token_type, "0x2::sui::SUI");
+        assert_eq!(transfer_event.transaction_module, "pay");
+        assert_eq!(transfer_event.timestamp, 1634567890);
+    }
+
+    #[test]
+    fn test_parse_raw_event_shapes() {
+        let recipient = format!("0x{}", "b".repeat(64));
+
+        // coin 事件：扁平字段，代币类型取自泛型参数
+        let coin_event = serde_json::json!({
+            "id": { "txDigest": "digest-coin", "eventSeq": "0" },
+            "packageId": "0x2",
+            "transactionModule": "coin",
+            "sender": format!("0x{}", "a".repeat(64)),
+            "type": "0x2::coin::CoinTransferred<0xdead::usdc::USDC>",
+            "parsedJson": { "amount": 2500, "to": recipient }
+        });
+        let parsed = EventMonitor::parse_raw_event(&coin_event).unwrap();
+        assert_eq!(parsed.amount, 2500);
+        assert_eq!(parsed.recipient, recipient);
+        assert_eq!(parsed.token_type, "0xdead::usdc::USDC");
+        assert_eq!(parsed.transaction_id, "digest-coin");
+
+        // balance 事件：金额为 Balance 结构
+        let balance_event = serde_json::json!({
+            "id": { "txDigest": "digest-balance", "eventSeq": "0" },
+            "packageId": "0x2",
+            "transactionModule": "balance",
+            "sender": format!("0x{}", "a".repeat(64)),
+            "type": "0x2::balance::BalanceTransferred<0x2::sui::SUI>",
+            "parsedJson": { "balance": { "value": "42" }, "owner": recipient }
+        });
+        let parsed = EventMonitor::parse_raw_event(&balance_event).unwrap();
+        assert_eq!(parsed.amount, 42);
+        assert_eq!(parsed.recipient, recipient);
+        assert_eq!(parsed.token_type, "0x2::sui::SUI");
+
+        // 非转移事件被跳过
+        let other_event = serde_json::json!({
+            "id": { "txDigest": "digest-other", "eventSeq": "0" },
+            "type": "0x3::validator::StakingRequestEvent",
+            "parsedJson": { "pool_id": "0x1", "validator_address": recipient }
+        });
+        assert!(EventMonitor::parse_raw_event(&other_event).is_none());
     }
 
     #[tokio::test]
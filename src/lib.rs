@@ -5,12 +5,18 @@ pub mod transaction_processor;
 pub mod alert_system;
 pub mod output_formatter;
 pub mod error;
+pub mod units;
+pub mod mock_sui_client;
+pub mod rotating_file;
+pub mod price_client;
+pub mod messages;
 
 use std::collections::HashMap;
 use tokio::sync::{RwLock, mpsc, Mutex};
 use tokio::time::{Duration, interval};
 use std::sync::Arc;
-use crate::{sui_client::SuiClient, event_monitor::EventMonitor, transaction_processor::TransactionProcessor, alert_system::{AlertSystem, AlertConfig}, output_formatter::OutputFormatter};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use crate::{sui_client::{SuiClient, SuiRpc}, event_monitor::EventMonitor, transaction_processor::TransactionProcessor, alert_system::{AlertSystem, AlertConfig}, output_formatter::{OutputFormatter, OutputSink}};
 use crate::event_monitor::TransferEvent;
 use crate::alert_system::Alert;
 
@@ -19,18 +25,41 @@ pub use crate::config::Config;
 pub use crate::error::{TrackerError, TrackerResult};
 pub use crate::output_formatter::OutputFormat;
 
+#[cfg(unix)]
+type SignalStream = tokio::signal::unix::Signal;
+#[cfg(not(unix))]
+type SignalStream = ();
+
 pub struct TokenTransferTracker {
     config: crate::config::Config,
-    sui_client: Arc<SuiClient>,
+    config_path: Option<String>,
+    /// 启动时的命令行覆盖，重新加载配置后再次应用
+    config_args: crate::config::ConfigArgs,
+    sui_client: Arc<dyn SuiRpc>,
     event_monitor: EventMonitor,
-    event_receiver: Mutex<mpsc::UnboundedReceiver<TransferEvent>>,
+    /// `config.networks` 中其他网络的监控器
+    network_monitors: Vec<EventMonitor>,
+    event_receiver: Mutex<mpsc::Receiver<TransferEvent>>,
     pub transaction_processor: TransactionProcessor,
     alert_system: AlertSystem,
-    alert_receiver: Mutex<mpsc::UnboundedReceiver<Alert>>,
+    alert_receiver: Mutex<mpsc::Receiver<Alert>>,
     pub output_formatter: OutputFormatter,
+    /// 运行时输出（交易、余额摘要、警报）的去向
+    output_sink: OutputSink,
     monitored_addresses: RwLock<HashMap<String, AddressInfo>>,
+    address_state: RwLock<AddressState>,
+    groups: RwLock<HashMap<String, Vec<String>>>,
     running: RwLock<bool>,
-    stats: RwLock<TrackerStats>,
+    stats: StatsCounters,
+    rate_baseline: Mutex<RateBaseline>,
+    /// 待确认交易的查询次数和首次查询时间
+    pending_checks: Mutex<HashMap<String, PendingCheck>>,
+    /// 已发送耗尽预测警报的地址，预测回到时限之外后移除，再次进入时限才重新警报
+    depletion_alerted: RwLock<std::collections::HashSet<String>>,
+    /// SUI/USD 价格源，未配置 `price.feed_url` 时为 None
+    price_client: Option<Arc<crate::price_client::PriceClient>>,
+    /// 后台刷新价格的任务，停止监控时中止
+    price_refresh: std::sync::Mutex<Option<tokio::task::JoinHandle<()>>>,
 }
 
 #[derive(Debug, Clone)]
@@ -41,6 +70,18 @@ pub struct AddressInfo {
     pub total_transactions: u64,
     pub first_seen: u64,
     pub last_seen: u64,
+    /// `addresses.tags` 中配置的标签
+    pub tags: Vec<String>,
+}
+
+/// 带有该标签的地址不发送低余额警报
+pub const IGNORE_LOW_BALANCE_TAG: &str = "ignore-low-balance";
+
+impl AddressInfo {
+    /// 阈值为 0 或带有 `ignore-low-balance` 标签时不检查低余额
+    pub fn low_balance_alerts_enabled(&self) -> bool {
+        self.alert_threshold != Some(0) && !self.tags.iter().any(|tag| tag == IGNORE_LOW_BALANCE_TAG)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -52,6 +93,231 @@ pub struct TrackerStats {
     pub total_errors: u64,
     pub uptime_seconds: u64,
     pub addresses_monitored: usize,
+    pub balances_reconciled: u64,
+    pub total_balance_drift: u64,
+    pub events_filtered: u64,
+    /// 事件通道中等待处理的事件数
+    pub events_queued: usize,
+    /// 事件通道满、轮询等待处理器的次数
+    pub event_backpressure_waits: u64,
+    /// 警报通道中等待处理的警报数
+    pub alerts_queued: usize,
+    /// 警报通道满时丢弃的警报数
+    pub alerts_dropped: u64,
+}
+
+/// EWMA 基线中新速率的权重
+const RATE_EWMA_ALPHA: f64 = 0.2;
+
+/// 低于该速率（事件/分钟）时不判定为突增，避免低流量下的误报
+const MIN_RATE_SPIKE_EVENTS_PER_MINUTE: f64 = 10.0;
+
+/// suix_getCoins 每页的 Coin 对象数量
+const COIN_PAGE_SIZE: u32 = 50;
+
+/// Coin 分布报告最多读取的页数，避免极度碎片化的地址查询过久
+const MAX_COIN_PAGES: usize = 100;
+
+/// 全局事件处理速率的 EWMA 基线，每次维护任务时更新
+#[derive(Debug)]
+struct RateBaseline {
+    last_tick: std::time::Instant,
+    last_count: u64,
+    ewma: Option<f64>,
+}
+
+impl RateBaseline {
+    fn new(now: std::time::Instant) -> Self {
+        Self { last_tick: now, last_count: 0, ewma: None }
+    }
+
+    /// 记录当前的事件总数；速率超过基线的 multiplier 倍时返回 (当前速率, 基线)，单位为事件/分钟
+    fn observe(&mut self, total_events: u64, now: std::time::Instant, multiplier: f64) -> Option<(f64, f64)> {
+        let elapsed_minutes = now.duration_since(self.last_tick).as_secs_f64() / 60.0;
+        if elapsed_minutes <= 0.0 {
+            return None;
+        }
+
+        let rate = total_events.saturating_sub(self.last_count) as f64 / elapsed_minutes;
+        self.last_tick = now;
+        self.last_count = total_events;
+
+        let spike = self.ewma.and_then(|baseline| {
+            let is_spike = multiplier > 0.0
+                && rate >= MIN_RATE_SPIKE_EVENTS_PER_MINUTE
+                && rate > baseline * multiplier;
+            is_spike.then_some((rate, baseline))
+        });
+
+        self.ewma = Some(match self.ewma {
+            Some(baseline) => RATE_EWMA_ALPHA * rate + (1.0 - RATE_EWMA_ALPHA) * baseline,
+            None => rate,
+        });
+
+        spike
+    }
+}
+
+/// 运行时统计计数器，使用原子类型避免热路径上的写锁
+struct StatsCounters {
+    start_time: std::time::SystemTime,
+    total_events_processed: AtomicU64,
+    total_transactions_processed: AtomicU64,
+    total_alerts_sent: AtomicU64,
+    total_errors: AtomicU64,
+    addresses_monitored: AtomicUsize,
+    balances_reconciled: AtomicU64,
+    total_balance_drift: AtomicU64,
+    events_filtered: AtomicU64,
+}
+
+impl StatsCounters {
+    fn new(addresses_monitored: usize) -> Self {
+        Self {
+            start_time: std::time::SystemTime::now(),
+            total_events_processed: AtomicU64::new(0),
+            total_transactions_processed: AtomicU64::new(0),
+            total_alerts_sent: AtomicU64::new(0),
+            total_errors: AtomicU64::new(0),
+            addresses_monitored: AtomicUsize::new(addresses_monitored),
+            balances_reconciled: AtomicU64::new(0),
+            total_balance_drift: AtomicU64::new(0),
+            events_filtered: AtomicU64::new(0),
+        }
+    }
+
+    /// 读取当前计数生成快照，运行时间在此时计算
+    fn snapshot(&self) -> TrackerStats {
+        TrackerStats {
+            start_time: self.start_time,
+            total_events_processed: self.total_events_processed.load(Ordering::Relaxed),
+            total_transactions_processed: self.total_transactions_processed.load(Ordering::Relaxed),
+            total_alerts_sent: self.total_alerts_sent.load(Ordering::Relaxed),
+            total_errors: self.total_errors.load(Ordering::Relaxed),
+            uptime_seconds: self.start_time.elapsed().unwrap_or_default().as_secs(),
+            addresses_monitored: self.addresses_monitored.load(Ordering::Relaxed),
+            balances_reconciled: self.balances_reconciled.load(Ordering::Relaxed),
+            total_balance_drift: self.total_balance_drift.load(Ordering::Relaxed),
+            events_filtered: self.events_filtered.load(Ordering::Relaxed),
+            events_queued: 0,
+            event_backpressure_waits: 0,
+            alerts_queued: 0,
+            alerts_dropped: 0,
+        }
+    }
+}
+
+/// 追加导出的进度，保存在导出文件旁的 `.cursor` 文件中
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+struct ExportCursor {
+    last_timestamp: u64,
+    /// 时间戳等于 last_timestamp 的已导出交易，避免同一秒内的交易重复或遗漏
+    last_ids: Vec<String>,
+}
+
+/// 运行时相对于配置文件增删的监控地址，保存在 `addresses.state_file`
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+struct AddressState {
+    #[serde(default)]
+    added: std::collections::BTreeSet<String>,
+    #[serde(default)]
+    removed: std::collections::BTreeSet<String>,
+}
+
+impl AddressState {
+    fn load(path: &str) -> crate::error::TrackerResult<Self> {
+        if path.is_empty() || !std::path::Path::new(path).exists() {
+            return Ok(Self::default());
+        }
+        let contents = std::fs::read_to_string(path)?;
+        serde_json::from_str(&contents)
+            .map_err(|e| TrackerError::config_error(format!("Invalid address state file {}: {}", path, e)))
+    }
+
+    /// 配置中的地址加上运行时添加的地址，再去掉运行时移除的地址
+    ///
+    /// 地址按规范形式比较和返回，无法解析的地址原样保留，由调用方报告。
+    fn apply(&self, configured: &[String]) -> Vec<String> {
+        let canonical = |address: &String| config::Config::normalize_address(address).unwrap_or_else(|| address.clone());
+        let removed: std::collections::HashSet<String> = self.removed.iter().map(canonical).collect();
+
+        let mut addresses: Vec<String> = Vec::new();
+        for address in configured.iter().chain(&self.added).map(canonical) {
+            if !addresses.contains(&address) && !removed.contains(&address) {
+                addresses.push(address);
+            }
+        }
+        addresses
+    }
+}
+
+/// 各网络的轮询进度，保存在 `monitoring.cursor_state_file`，重启后从上次的位置继续
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+struct CursorState {
+    #[serde(default)]
+    networks: std::collections::BTreeMap<String, NetworkCursors>,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+struct NetworkCursors {
+    #[serde(default)]
+    addresses: HashMap<String, crate::event_monitor::AddressCursor>,
+    #[serde(default)]
+    packages: HashMap<String, String>,
+}
+
+impl CursorState {
+    fn load(path: &str) -> crate::error::TrackerResult<Self> {
+        if path.is_empty() || !std::path::Path::new(path).exists() {
+            return Ok(Self::default());
+        }
+        let contents = std::fs::read_to_string(path)?;
+        serde_json::from_str(&contents)
+            .map_err(|e| TrackerError::config_error(format!("Invalid cursor state file {}: {}", path, e)))
+    }
+}
+
+/// 地址分组的汇总统计
+#[derive(Debug, Clone, Default)]
+pub struct GroupStats {
+    pub name: String,
+    pub addresses: usize,
+    pub total_balance: u64,
+    pub total_transactions: u64,
+    pub total_sent: u64,
+    pub total_received: u64,
+    pub total_gas_spent: u64,
+}
+
+/// 待确认交易的查询进度
+///
+/// 只有节点给出回答的查询计入 `attempts` 和 `first_checked`，RPC 故障不会让交易过期。
+#[derive(Debug, Clone, Default)]
+struct PendingCheck {
+    attempts: u32,
+    first_checked: Option<std::time::Instant>,
+    last_checked: Option<std::time::Instant>,
+    /// 最后观察到的链上状态（Success/Failed），可能尚未达到 finality_depth
+    last_status: Option<crate::transaction_processor::TransactionStatus>,
+}
+
+/// 一轮待确认交易查询的结果
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PendingConfirmationReport {
+    pub checked: usize,
+    pub confirmed: usize,
+    pub failed: usize,
+    /// 超过查询次数或时限，按最后观察到的链上状态（未观察到时为 Failed）结束的交易
+    pub expired: usize,
+}
+
+/// 一次余额对账的结果
+#[derive(Debug, Clone, Default)]
+pub struct ReconciliationReport {
+    pub checked: usize,
+    pub reconciled: usize,
+    pub total_drift: u64,
+    pub max_drift: u64,
 }
 
 impl TokenTransferTracker {
@@ -60,14 +326,23 @@ impl TokenTransferTracker {
         
         // 验证配置
         config.validate()?;
-        
-        // 初始化日志
-        Self::init_logging(&config.logging);
 
         // 创建SUI客户端
-        let sui_client = Arc::new(
-            SuiClient::with_timeout(&config.network.rpc_url, config.network.timeout_seconds).await?
-        );
+        let sui_client = Arc::new(Self::create_client(&config.network).await?);
+        Self::with_rpc_client(config, sui_client).await
+    }
+
+    /// 使用指定的链上查询客户端创建跟踪器，测试中可传入 `MockSuiClient`
+    ///
+    /// `config.networks` 中的其他网络仍通过 JSON-RPC 连接。
+    pub async fn with_rpc_client(
+        config: crate::config::Config,
+        sui_client: Arc<dyn SuiRpc>,
+    ) -> crate::error::TrackerResult<Self> {
+        config.validate()?;
+
+        // 初始化日志
+        Self::init_logging(&config.logging);
 
         // 健康检查
         if !sui_client.is_healthy().await {
@@ -75,31 +350,61 @@ impl TokenTransferTracker {
         }
 
         // 创建事件监控器
-        let (event_monitor, event_receiver) = EventMonitor::new(
+        let (mut event_monitor, event_receiver) = EventMonitor::with_capacity(
             sui_client.clone(),
             Duration::from_secs(config.monitoring.poll_interval_seconds),
+            config.monitoring.event_channel_capacity,
         ).await;
+        event_monitor.set_max_concurrent_checks(config.monitoring.max_concurrent_checks);
+        event_monitor.set_query_retry(config.monitoring.query_max_retries, config.monitoring.query_retry_base_delay_ms);
+        event_monitor.set_finality_depth(config.monitoring.finality_depth);
+        event_monitor.set_batch_size(config.monitoring.batch_size);
 
         // 创建交易处理器
         let transaction_processor = TransactionProcessor::with_config(crate::transaction_processor::ProcessorConfig {
             max_history_records: config.monitoring.max_history_records,
             cleanup_interval_hours: config.monitoring.cleanup_interval_hours,
             enable_detailed_stats: true,
+            exclude_failed_from_volume: config.monitoring.exclude_failed_from_volume,
+            export_json_pretty: config.output.export_json_pretty,
         });
 
         // 创建警报系统
         let alert_config = AlertConfig {
             low_balance_threshold: config.alerts.low_balance_threshold,
             large_transfer_threshold: config.alerts.large_transfer_threshold,
-            enable_console_alerts: config.alerts.enable_console_alerts,
+            // 只输出到文件时控制台不显示警报
+            enable_console_alerts: config.alerts.enable_console_alerts
+                && config.output.output_destination != crate::output_formatter::OutputDestination::File,
             enable_file_alerts: config.alerts.enable_file_alerts,
             alert_file_path: config.alerts.alert_file_path.clone(),
+            alert_file_max_size_mb: config.alerts.alert_file_max_size_mb,
+            alert_file_rotate_count: config.alerts.alert_file_rotate_count,
             enable_email_alerts: false, // 简化版本
             email_smtp_server: String::new(),
             email_sender: String::new(),
             email_recipients: Vec::new(),
             enable_discord_alerts: false,
             discord_webhook_url: String::new(),
+            enable_slack_alerts: config.alerts.enable_slack_alerts,
+            slack_webhook_url: config.alerts.slack_webhook_url.clone(),
+            enable_telegram_alerts: config.alerts.enable_telegram_alerts,
+            telegram_bot_token: config.alerts.telegram_bot_token.clone(),
+            telegram_chat_id: config.alerts.telegram_chat_id.clone(),
+            telegram_use_markdown: config.alerts.telegram_use_markdown,
+            enable_webhook_alerts: config.alerts.enable_webhook_alerts,
+            webhook_url: config.alerts.webhook_url.clone(),
+            webhook_method: config.alerts.webhook_method.clone(),
+            webhook_content_type: config.alerts.webhook_content_type.clone(),
+            webhook_template: config.alerts.webhook_template.clone(),
+            min_delivery_severity: config.alerts.min_delivery_severity.clone(),
+            console_alert_style: config.alerts.console_alert_style,
+            circular_transfer_tolerance: config.alerts.circular_transfer_tolerance,
+            circular_transfer_window_seconds: config.alerts.circular_transfer_window_seconds,
+            dormancy_threshold_hours: config.alerts.dormancy_threshold_hours,
+            alert_history_max: config.alerts.alert_history_max,
+            alert_history_max_age_hours: config.alerts.alert_history_max_age_hours,
+            channel_capacity: config.alerts.alert_channel_capacity,
             cooldown_period_seconds: 300,
         };
         
@@ -114,11 +419,27 @@ impl TokenTransferTracker {
             table_width: 80,
             enable_json_output: false,
             enable_csv_output: false,
+            display_decimals: config.output.display_decimals,
+            group_digits: config.output.group_digits,
+            compact: config.output.compact,
+            csv_header: config.output.csv_header,
+            color_tier_high: config.output.color_tier_high,
+            color_tier_medium: config.output.color_tier_medium,
+            color_tier_low: config.output.color_tier_low,
+            json_pretty: config.output.json_pretty,
+            quiet: config.output.quiet,
+            show_usd_value: config.output.show_usd_value,
+            language: config.output.language,
+            use_emoji: config.output.use_emoji,
         });
+        let price_client = crate::price_client::PriceClient::from_config(&config.price)?.map(Arc::new);
+
+        let output_sink = OutputSink::open(config.output.output_destination, config.output.output_file.as_deref())?;
 
-        // 初始化监控地址
+        // 初始化监控地址（合并状态文件中运行时增删的地址）
+        let address_state = AddressState::load(&config.addresses.state_file)?;
         let mut monitored_addresses = HashMap::new();
-        for address in &config.addresses.monitored {
+        for address in &address_state.apply(&config.addresses.monitored) {
             if !config::Config::is_valid_sui_address(address) {
                 log::warn!("Invalid address format: {}", address);
                 continue;
@@ -131,44 +452,131 @@ impl TokenTransferTracker {
                 .unwrap()
                 .as_secs();
             
+            let alert_threshold = config.address_low_balance_threshold(address)
+                .unwrap_or(config.alerts.low_balance_threshold);
             monitored_addresses.insert(address.clone(), AddressInfo {
                 balance,
                 last_checked: current_time,
-                alert_threshold: Some(config.alerts.low_balance_threshold),
+                alert_threshold: Some(alert_threshold),
                 total_transactions: 0,
                 first_seen: current_time,
                 last_seen: current_time,
+                tags: config.address_tags(address),
             });
+            alert_system.set_threshold(address.clone(), alert_threshold).await;
 
             // 添加到监控器
             event_monitor.add_address(address.clone()).await?;
         }
 
-        log::info!("Initialized with {} addresses to monitor", monitored_addresses.len());
+        // 包ID因网络而异，按包/模块监控只用于主网络
+        for package in &config.monitoring.monitored_packages {
+            event_monitor.add_package_filter(package.clone()).await?;
+        }
+
+        let addresses_monitored = monitored_addresses.len();
+        log::info!("Initialized with {} addresses to monitor", addresses_monitored);
+        let groups = config.addresses.groups.clone();
+
+        // 其他网络的监控器共用同一条事件管道，事件带有各自的网络名称
+        let mut network_monitors = Vec::new();
+        for network in &config.networks {
+            let client = Arc::new(Self::create_client(network).await?);
+            if !client.is_healthy().await {
+                log::warn!("Network '{}' ({}) is not reachable yet, monitoring it anyway", network.name, network.rpc_url);
+            }
+
+            let mut monitor = EventMonitor::with_sender(
+                client,
+                Duration::from_secs(config.monitoring.poll_interval_seconds),
+                event_monitor.event_sender(),
+            ).await;
+            monitor.set_max_concurrent_checks(config.monitoring.max_concurrent_checks);
+            monitor.set_query_retry(config.monitoring.query_max_retries, config.monitoring.query_retry_base_delay_ms);
+            monitor.set_finality_depth(config.monitoring.finality_depth);
+            monitor.set_batch_size(config.monitoring.batch_size);
+
+            let addresses: Vec<String> = if network.addresses.is_empty() {
+                monitored_addresses.keys().cloned().collect()
+            } else {
+                network.addresses.clone()
+            };
+            for address in addresses {
+                monitor.add_address(address).await?;
+            }
+
+            log::info!("Monitoring network '{}' with {} addresses", network.name, monitor.get_monitored_addresses().await.len());
+            network_monitors.push(monitor);
+        }
+
+        // 恢复上次运行的轮询进度，避免重启后重复处理已处理过的交易
+        let cursor_state = CursorState::load(&config.monitoring.cursor_state_file)?;
+        for monitor in std::iter::once(&event_monitor).chain(&network_monitors) {
+            if let Some(cursors) = cursor_state.networks.get(monitor.sui_client().network_name()) {
+                let restored = monitor.restore_cursors(&cursors.addresses, &cursors.packages).await;
+                log::info!("Restored {} polling cursors for network '{}'", restored, monitor.sui_client().network_name());
+            }
+        }
 
         Ok(Self {
             config,
+            config_path: None,
+            config_args: crate::config::ConfigArgs::default(),
             sui_client,
             event_monitor,
+            network_monitors,
             event_receiver: Mutex::new(event_receiver),
             transaction_processor,
             alert_system,
             alert_receiver: Mutex::new(alert_receiver),
             output_formatter,
+            output_sink,
             monitored_addresses: RwLock::new(monitored_addresses),
+            address_state: RwLock::new(address_state),
+            groups: RwLock::new(groups),
             running: RwLock::new(false),
-            stats: RwLock::new(TrackerStats {
-                start_time: std::time::SystemTime::now(),
-                total_events_processed: 0,
-                total_transactions_processed: 0,
-                total_alerts_sent: 0,
-                total_errors: 0,
-                uptime_seconds: 0,
-                addresses_monitored: 0, // TODO: Fix borrow checker issue
-            }),
+            stats: StatsCounters::new(addresses_monitored),
+            rate_baseline: Mutex::new(RateBaseline::new(std::time::Instant::now())),
+            pending_checks: Mutex::new(HashMap::new()),
+            price_client,
+            price_refresh: std::sync::Mutex::new(None),
+            depletion_alerted: RwLock::new(std::collections::HashSet::new()),
         })
     }
 
+    /// 按网络配置创建客户端，客户端查询到的事件带有该网络的名称
+    pub async fn create_client(network: &crate::config::NetworkConfig) -> crate::error::TrackerResult<SuiClient> {
+        let mut sui_client = SuiClient::with_timeout(&network.rpc_url, network.timeout_seconds).await?;
+        sui_client.set_rpc_headers(&network.rpc_headers)?;
+        sui_client.set_network_name(network.name.clone());
+        sui_client.set_balance_cache_ttl(Duration::from_secs(network.balance_cache_ttl_seconds));
+        sui_client.set_response_cache(Duration::from_secs(network.rpc_cache_ttl_seconds), &network.rpc_cache_methods);
+        sui_client.set_slow_rpc_threshold(Duration::from_millis(network.slow_rpc_threshold_ms));
+        sui_client.set_circuit_breaker(crate::sui_client::CircuitBreakerConfig {
+            failure_threshold: network.circuit_failure_threshold,
+            failure_window: Duration::from_secs(network.circuit_failure_window_seconds),
+            cooldown: Duration::from_secs(network.circuit_cooldown_seconds),
+        });
+        Ok(sui_client)
+    }
+
+    /// 所有被监控网络的名称，主网络在前
+    pub fn network_names(&self) -> Vec<String> {
+        std::iter::once(self.config.network.name.clone())
+            .chain(self.config.networks.iter().map(|network| network.name.clone()))
+            .collect()
+    }
+
+    /// 设置配置文件路径，收到 SIGHUP 时从该文件重新加载配置
+    pub fn set_config_path(&mut self, path: impl Into<String>) {
+        self.config_path = Some(path.into());
+    }
+
+    /// 设置命令行覆盖（如 `--threshold`、`--poll-interval`），重新加载配置时不会被文件中的值还原
+    pub fn set_config_args(&mut self, args: crate::config::ConfigArgs) {
+        self.config_args = args;
+    }
+
     pub async fn start_monitoring(&mut self) -> crate::error::TrackerResult<()> {
         let mut running = self.running.write().await;
         if *running {
@@ -177,13 +585,30 @@ impl TokenTransferTracker {
         }
 
         *running = true;
+        // 处理循环会定期读取运行状态，持有写锁会使其永久阻塞
+        drop(running);
         log::info!("Starting SUI Token Transfer Tracker");
 
         // 启动事件监控
-        let event_monitor = self.event_monitor.clone();
-        tokio::spawn(async move {
-            event_monitor.start_monitoring().await;
-        });
+        for event_monitor in std::iter::once(&self.event_monitor).chain(&self.network_monitors) {
+            let event_monitor = event_monitor.clone();
+            tokio::spawn(async move {
+                event_monitor.start_monitoring().await;
+            });
+        }
+
+        // 价格在后台按缓存时间刷新，交易输出、摘要和汇总只读取最近一次的价格
+        if let Some(price_client) = self.price_client.clone() {
+            let output_formatter = self.output_formatter.clone();
+            let period = price_client.cache_ttl().max(Duration::from_secs(1));
+            *self.price_refresh.lock().unwrap() = Some(tokio::spawn(async move {
+                let mut refresh_interval = interval(period);
+                loop {
+                    refresh_interval.tick().await;
+                    output_formatter.set_usd_price(price_client.refresh().await);
+                }
+            }));
+        }
 
         // 启动主处理循环
         self.processing_loop().await?;
@@ -200,6 +625,15 @@ impl TokenTransferTracker {
 
         *running = false;
         self.event_monitor.stop_monitoring().await;
+        for monitor in &self.network_monitors {
+            monitor.stop_monitoring().await;
+        }
+        if let Some(price_refresh) = self.price_refresh.lock().unwrap().take() {
+            price_refresh.abort();
+        }
+        if let Err(e) = self.save_cursor_state().await {
+            log::error!("Failed to save cursor state: {}", e);
+        }
         log::info!("Tracker stopped");
 
         Ok(())
@@ -209,11 +643,60 @@ impl TokenTransferTracker {
         *self.running.read().await
     }
 
+    /// 暂停所有网络的轮询，历史、统计和余额保持不变
+    pub async fn pause_monitoring(&self) {
+        for monitor in std::iter::once(&self.event_monitor).chain(&self.network_monitors) {
+            monitor.pause_monitoring().await;
+        }
+    }
+
+    /// 恢复轮询，从暂停前的进度继续，不重新查询初始余额
+    pub async fn resume_monitoring(&self) {
+        for monitor in std::iter::once(&self.event_monitor).chain(&self.network_monitors) {
+            monitor.resume_monitoring().await;
+        }
+    }
+
+    pub async fn is_paused(&self) -> bool {
+        self.event_monitor.is_paused().await
+    }
+
+    /// 在暂停与恢复之间切换，返回切换后是否处于暂停状态
+    pub async fn toggle_pause(&self) -> bool {
+        if self.is_paused().await {
+            self.resume_monitoring().await;
+            false
+        } else {
+            self.pause_monitoring().await;
+            true
+        }
+    }
+
     async fn processing_loop(&self) -> crate::error::TrackerResult<()> {
         log::info!("Starting processing loop");
 
-        let mut interval_timer = interval(Duration::from_secs(30)); // 维护任务间隔
-        let mut balance_summary_interval = interval(Duration::from_secs(self.config.output.balance_summary_interval));
+        let mut interval_timer = interval(Duration::from_secs(self.config.monitoring.maintenance_interval_seconds.max(1)));
+        // 间隔为 0 时不输出摘要（interval 不接受零时长）
+        let summary_seconds = self.config.output.balance_summary_interval;
+        let mut balance_summary_interval = interval(Duration::from_secs(summary_seconds.max(1)));
+        let mut hangup_signal = Self::listen_for_hangup();
+        let mut dump_signal = Self::listen_for_stats_dump();
+        let mut pause_signal = Self::listen_for_pause_toggle();
+        // 只输出警报时不做定期余额摘要
+        let show_summary = !self.config.output.alerts_only && summary_seconds > 0;
+
+        // 活动汇总，首次在一个周期后发送
+        let digest_hours = self.config.alerts.digest_interval_hours;
+        let digest_period = Duration::from_secs(digest_hours.max(1).saturating_mul(3600));
+        let mut digest_interval = tokio::time::interval_at(tokio::time::Instant::now() + digest_period, digest_period);
+
+        let pending_seconds = self.config.monitoring.pending_confirmation_interval_seconds;
+        let mut pending_confirmation_interval = interval(Duration::from_secs(pending_seconds.max(1)));
+
+        // 余额对账，首次在一个周期后进行
+        let reconcile_seconds = self.config.monitoring.reconcile_interval_seconds;
+        let reconcile_period = Duration::from_secs(reconcile_seconds.max(1));
+        let mut reconcile_interval = tokio::time::interval_at(tokio::time::Instant::now() + reconcile_period, reconcile_period);
 
         loop {
             let mut event_receiver = self.event_receiver.lock().await;
@@ -221,18 +704,18 @@ impl TokenTransferTracker {
             
             tokio::select! {
                 // 事件处理
-                _ = event_receiver.recv() => {
-                    if let Err(e) = self.handle_events().await {
+                Some(event) = event_receiver.recv() => {
+                    if let Err(e) = self.handle_event(event).await {
                         log::error!("Error handling events: {}", e);
-                        self.increment_errors().await;
+                        self.increment_errors();
                     }
                 }
                 
                 // 警报处理
-                _ = alert_receiver.recv() => {
-                    if let Err(e) = self.handle_alerts().await {
+                Some(alert) = alert_receiver.recv() => {
+                    if let Err(e) = self.handle_alerts(alert).await {
                         log::error!("Error handling alerts: {}", e);
-                        self.increment_errors().await;
+                        self.increment_errors();
                     }
                 }
 
@@ -240,18 +723,76 @@ impl TokenTransferTracker {
                 _ = interval_timer.tick() => {
                     if let Err(e) = self.maintenance_tasks().await {
                         log::error!("Error in maintenance tasks: {}", e);
-                        self.increment_errors().await;
+                        self.increment_errors();
                     }
                 }
 
                 // 余额摘要输出
-                _ = balance_summary_interval.tick() => {
+                _ = balance_summary_interval.tick(), if show_summary => {
                     if let Err(e) = self.output_balance_summary().await {
                         log::error!("Error outputting balance summary: {}", e);
-                        self.increment_errors().await;
+                        self.increment_errors();
+                    }
+                }
+
+                // 定期活动汇总
+                _ = digest_interval.tick(), if digest_hours > 0 => {
+                    if let Err(e) = self.send_activity_digest(digest_hours).await {
+                        log::error!("Error sending activity digest: {}", e);
+                        self.increment_errors();
+                    }
+                }
+
+                // 重新查询待确认交易
+                _ = pending_confirmation_interval.tick(), if pending_seconds > 0 => {
+                    if let Err(e) = self.confirm_pending_transactions().await {
+                        log::error!("Error confirming pending transactions: {}", e);
+                        self.increment_errors();
+                    }
+                }
+
+                // 与链上余额对账
+                _ = reconcile_interval.tick(), if reconcile_seconds > 0 => {
+                    match self.reconcile_balances().await {
+                        Ok(report) if report.reconciled > 0 => log::info!(
+                            "Reconciled {}/{} balances, total drift {}, max drift {}",
+                            report.reconciled,
+                            report.checked,
+                            self.output_formatter.format_amount(report.total_drift),
+                            self.output_formatter.format_amount(report.max_drift)
+                        ),
+                        Ok(_) => {}
+                        Err(e) => {
+                            log::error!("Error reconciling balances: {}", e);
+                            self.increment_errors();
+                        }
+                    }
+                }
+
+                // 配置热重载
+                _ = Self::recv_signal(&mut hangup_signal) => {
+                    log::info!("Received SIGHUP, reloading configuration");
+                    if let Err(e) = self.reload_config().await {
+                        log::error!("Error reloading configuration: {}", e);
+                        self.increment_errors();
+                    }
+                }
+
+                // 按需输出统计
+                _ = Self::recv_signal(&mut dump_signal) => {
+                    log::info!("Received SIGUSR1, dumping stats");
+                    if let Err(e) = self.dump_stats().await {
+                        log::error!("Error dumping stats: {}", e);
+                        self.increment_errors();
                     }
                 }
 
+                // 暂停/恢复轮询
+                _ = Self::recv_signal(&mut pause_signal) => {
+                    let paused = self.toggle_pause().await;
+                    log::info!("Received SIGUSR2, monitoring {}", if paused { "paused" } else { "resumed" });
+                }
+
                 // 检查是否应该停止
                 _ = tokio::time::sleep(Duration::from_millis(100)) => {
                     if !*self.running.read().await {
@@ -263,51 +804,261 @@ impl TokenTransferTracker {
         }
     }
 
-    async fn handle_events(&self) -> crate::error::TrackerResult<()> {
-        // 这里需要从事件监控器获取事件
-        // 这是一个简化的实现
+    #[cfg(unix)]
+    fn listen_for_hangup() -> Option<SignalStream> {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::hangup()) {
+            Ok(signal) => Some(signal),
+            Err(e) => {
+                log::warn!("Failed to install SIGHUP handler, config reload disabled: {}", e);
+                None
+            }
+        }
+    }
+
+    #[cfg(not(unix))]
+    fn listen_for_hangup() -> Option<SignalStream> {
+        None
+    }
+
+    #[cfg(unix)]
+    fn listen_for_stats_dump() -> Option<SignalStream> {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::user_defined1()) {
+            Ok(signal) => Some(signal),
+            Err(e) => {
+                log::warn!("Failed to install SIGUSR1 handler, on-demand stats dump disabled: {}", e);
+                None
+            }
+        }
+    }
+
+    #[cfg(not(unix))]
+    fn listen_for_stats_dump() -> Option<SignalStream> {
+        None
+    }
+
+    #[cfg(unix)]
+    fn listen_for_pause_toggle() -> Option<SignalStream> {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::user_defined2()) {
+            Ok(signal) => Some(signal),
+            Err(e) => {
+                log::warn!("Failed to install SIGUSR2 handler, pause/resume disabled: {}", e);
+                None
+            }
+        }
+    }
+
+    #[cfg(not(unix))]
+    fn listen_for_pause_toggle() -> Option<SignalStream> {
+        None
+    }
+
+    /// 等待信号；未安装处理器时永不返回
+    async fn recv_signal(signal: &mut Option<SignalStream>) {
+        match signal {
+            #[cfg(unix)]
+            Some(signal) => {
+                signal.recv().await;
+            }
+            #[cfg(not(unix))]
+            Some(_) => std::future::pending::<()>().await,
+            None => std::future::pending::<()>().await,
+        }
+    }
+
+    /// 从配置文件重新加载监控地址与警报阈值，不中断处理循环
+    pub async fn reload_config(&self) -> crate::error::TrackerResult<()> {
+        let path = match &self.config_path {
+            Some(path) => path.clone(),
+            None => {
+                log::warn!("No config file was given at startup, nothing to reload");
+                return Ok(());
+            }
+        };
+
+        let mut new_config = crate::config::Config::load_with_env(Some(&path))?;
+        new_config.merge_with_args(&self.config_args);
+        new_config.validate()?;
+
+        // 网络相关配置需要重启才能生效
+        if new_config.network.rpc_url != self.config.network.rpc_url
+            || new_config.network.websocket_url != self.config.network.websocket_url
+            || new_config.network.timeout_seconds != self.config.network.timeout_seconds
+        {
+            log::warn!("Network configuration changed in {}; restart the tracker to apply it", path);
+        }
+
+        // 更新警报阈值
+        let low_balance_threshold = new_config.alerts.low_balance_threshold;
+        self.alert_system
+            .update_default_thresholds(low_balance_threshold, new_config.alerts.large_transfer_threshold)
+            .await;
+
+        // 对比监控地址
+        let current: std::collections::HashSet<String> = self.get_all_addresses().await.into_iter().collect();
+        let configured = self.address_state.read().await.apply(&new_config.addresses.monitored);
+        let desired: std::collections::HashSet<String> = configured.iter()
+            .filter(|address| {
+                let valid = crate::config::Config::is_valid_sui_address(address);
+                if !valid {
+                    log::warn!("Invalid address format: {}", address);
+                }
+                valid
+            })
+            .cloned()
+            .collect();
+
+        for address in current.difference(&desired) {
+            if let Err(e) = self.unmonitor_address(address).await {
+                log::error!("Failed to remove address {} during reload: {}", address, e);
+            }
+        }
+
+        for address in desired.difference(&current) {
+            if let Err(e) = self.monitor_address(address.clone()).await {
+                log::error!("Failed to add address {} during reload: {}", address, e);
+            }
+        }
+
+        *self.groups.write().await = new_config.addresses.groups.clone();
+
+        // 已有地址使用新的低余额阈值和标签
+        {
+            let mut addresses = self.monitored_addresses.write().await;
+            for (address, info) in addresses.iter_mut() {
+                let threshold = new_config.address_low_balance_threshold(address).unwrap_or(low_balance_threshold);
+                info.alert_threshold = Some(threshold);
+                info.tags = new_config.address_tags(address);
+                self.alert_system.set_threshold(address.clone(), threshold).await;
+            }
+        }
+
+        log::info!(
+            "Configuration reloaded from {}: {} addresses monitored",
+            path,
+            desired.len()
+        );
+
         Ok(())
     }
 
-    async fn handle_alerts(&self) -> crate::error::TrackerResult<()> {
-        // 这里需要从警报系统获取警报
-        // 这是一个简化的实现
+    async fn handle_event(&self, event: TransferEvent) -> crate::error::TrackerResult<()> {
+        self.process_transfer_event(event).await
+    }
+
+    async fn handle_alerts(&self, alert: Alert) -> crate::error::TrackerResult<()> {
+        // 控制台警报由警报系统输出，这里只写入输出文件（与是否开启控制台警报无关）
+        self.output_sink.write_file(&self.output_formatter.format_alert(&alert));
         Ok(())
     }
 
-    #[allow(dead_code)]
     async fn process_transfer_event(&self, event: TransferEvent) -> crate::error::TrackerResult<()> {
         // 更新统计信息
-        self.increment_events_processed().await;
+        self.increment_events_processed();
+
+        // 按代币类型过滤
+        if !self.is_coin_type_monitored(&event.token_type) {
+            let filtered = self.stats.events_filtered.fetch_add(1, Ordering::Relaxed) + 1;
+            log::debug!(
+                "Skipped {} event {} (not in monitored_coin_types, {} skipped so far)",
+                event.token_type, event.transaction_id, filtered
+            );
+            return Ok(());
+        }
+
+        // 只支付gas的交易默认不作为转移记录
+        if event.event_type == "gas" && !self.config.monitoring.record_gas_only_transactions {
+            log::debug!("Skipped gas-only transaction {}", event.transaction_id);
+            return Ok(());
+        }
 
         // 处理转移事件
         let processed = self.transaction_processor.process_transfer_event(event.clone()).await?;
 
-        // 检查警报
-        self.alert_system.check_large_transfer(&processed.transaction).await?;
+        // 检查警报（失败的交易没有实际转移金额）
+        if processed.transaction.status != crate::transaction_processor::TransactionStatus::Failed {
+            self.alert_system.check_large_transfer(&processed.transaction).await?;
+        }
+        self.alert_system
+            .check_suspicious_activity(std::slice::from_ref(&processed.transaction))
+            .await?;
+        self.alert_system
+            .check_dormant_reactivation(&processed.transaction, processed.sender_last_transaction)
+            .await?;
         
         // 检查余额警报
         let sender_balance = self.transaction_processor.get_address_balance(&event.sender).await;
         let receiver_balance = self.transaction_processor.get_address_balance(&event.recipient).await;
         
-        self.alert_system.check_balance_alert(&event.sender, sender_balance).await?;
-        self.alert_system.check_balance_alert(&event.recipient, receiver_balance).await?;
+        for (address, balance) in [(&event.sender, sender_balance), (&event.recipient, receiver_balance)] {
+            if self.low_balance_alerts_enabled(address).await {
+                self.alert_system.check_balance_alert(address, balance).await?;
+            }
+        }
+
+        // 预测发送方余额耗尽时间
+        self.check_depletion(&event.sender).await?;
 
         // 更新地址信息
         self.update_address_info(&event).await?;
 
-        // 输出交易信息
-        let formatted = self.output_formatter.format_transaction(&processed.transaction);
-        println!("{}", formatted);
+        // 输出交易信息，只输出警报或低于 min_display_amount 的小额交易不打印
+        if !self.config.output.alerts_only && processed.transaction.amount >= self.config.output.min_display_amount {
+            let formatted = self.output_formatter.format_transaction(&processed.transaction);
+            self.output_sink.write(&formatted);
+        }
 
         // 更新统计信息
-        self.increment_transactions_processed().await;
+        self.increment_transactions_processed();
 
         log::debug!("Processed transfer event: {}", event.transaction_id);
         Ok(())
     }
 
-    #[allow(dead_code)]
+    /// 地址是否检查低余额（未监控的地址由警报系统按阈值决定）
+    async fn low_balance_alerts_enabled(&self, address: &str) -> bool {
+        self.monitored_addresses.read().await
+            .get(address)
+            .is_none_or(AddressInfo::low_balance_alerts_enabled)
+    }
+
+    async fn check_depletion(&self, address: &str) -> crate::error::TrackerResult<()> {
+        let horizon_hours = self.config.alerts.depletion_horizon_hours;
+        if horizon_hours == 0 || !self.monitored_addresses.read().await.contains_key(address) {
+            return Ok(());
+        }
+
+        let estimate = self.transaction_processor
+            .estimate_depletion(address, self.config.alerts.depletion_window_hours)
+            .await;
+        let horizon = Duration::from_secs(horizon_hours.saturating_mul(3600));
+        match estimate.filter(|time_to_depletion| *time_to_depletion < horizon) {
+            // 只在预测首次进入时限时警报，避免每笔交易重复发送
+            Some(time_to_depletion) => {
+                if self.depletion_alerted.write().await.insert(address.to_string()) {
+                    self.alert_system.send_depletion_alert(address, time_to_depletion).await?;
+                }
+            }
+            None => {
+                self.depletion_alerted.write().await.remove(address);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// monitored_coin_types 为空时处理所有代币；包地址按规范形式比较，`0x2::sui::SUI` 与完整形式等价
+    fn is_coin_type_monitored(&self, token_type: &str) -> bool {
+        let coin_types = &self.config.monitoring.monitored_coin_types;
+        if coin_types.is_empty() {
+            return true;
+        }
+        let canonical = |coin_type: &str| {
+            crate::config::Config::normalize_coin_type(coin_type).unwrap_or_else(|| coin_type.to_string())
+        };
+        let token_type = canonical(token_type);
+        coin_types.iter().any(|coin_type| canonical(coin_type) == token_type)
+    }
+
     async fn update_address_info(&self, event: &TransferEvent) -> crate::error::TrackerResult<()> {
         let mut addresses = self.monitored_addresses.write().await;
         let current_time = std::time::SystemTime::now()
@@ -343,15 +1094,38 @@ impl TokenTransferTracker {
             log::info!("Cleaned up {} old transaction records", removed);
         }
 
-        // 更新运行时间统计
-        self.update_uptime().await;
-
         // 验证监控地址
         let invalid_addresses = self.event_monitor.validate_addresses().await;
         if !invalid_addresses.is_empty() {
             log::warn!("Found {} invalid addresses: {:?}", invalid_addresses.len(), invalid_addresses);
         }
 
+        // 连续查询失败的地址
+        let failure_threshold = self.config.monitoring.failure_alert_threshold;
+        if failure_threshold > 0 {
+            // 每段连续失败只警报一次，地址恢复后再次失败才重新警报
+            for (address, backoff) in self.event_monitor.newly_failing_addresses(failure_threshold).await {
+                self.alert_system.send_system_error_alert(
+                    format!(
+                        "Address {} failed {} consecutive queries: {}",
+                        address, backoff.consecutive_failures, backoff.last_error
+                    ),
+                    format!("event_monitor:{}", address),
+                ).await?;
+            }
+        }
+
+        // RPC熔断状态变化
+        self.send_circuit_alerts().await?;
+
+        // 全局事件速率突增
+        self.check_rate_spike().await?;
+
+        // 保存轮询进度，失败不影响其他维护任务
+        if let Err(e) = self.save_cursor_state().await {
+            log::error!("Failed to save cursor state: {}", e);
+        }
+
         // 检查系统健康状态
         if !self.sui_client.is_healthy().await {
             log::warn!("SUI network health check failed");
@@ -364,25 +1138,466 @@ impl TokenTransferTracker {
         Ok(())
     }
 
+    /// 重新查询待确认交易的链上状态，确认后更新处理器中的记录
+    ///
+    /// 启用 finality_depth 时还要求交易所在检查点已足够深。成功查询超过
+    /// `pending_confirmation_max_checks` 次或 `pending_confirmation_timeout_seconds` 仍未确认的交易
+    /// 按最后观察到的链上状态结束，从未观察到时标记为 Failed。每轮最多查询
+    /// `pending_confirmation_batch_size` 笔，并发数不超过 `max_concurrent_checks`。
+    pub async fn confirm_pending_transactions(&self) -> crate::error::TrackerResult<PendingConfirmationReport> {
+        use crate::transaction_processor::TransactionStatus;
+        use futures::stream::{self, StreamExt};
+
+        let monitoring = &self.config.monitoring;
+        let mut report = PendingConfirmationReport::default();
+
+        // 本轮查询的交易，最久未查询的优先；查询期间不持有锁
+        let batch = {
+            let mut pending_ids = self.transaction_processor.pending_transaction_ids().await;
+            let mut checks = self.pending_checks.lock().await;
+            // 已不再待确认的交易不再跟踪
+            checks.retain(|id, _| pending_ids.binary_search(id).is_ok());
+            pending_ids.sort_by_key(|id| checks.get(id).and_then(|check| check.last_checked));
+            pending_ids.truncate(monitoring.pending_confirmation_batch_size.max(1));
+            pending_ids
+        };
+        if batch.is_empty() {
+            return Ok(report);
+        }
+
+        let lookups: Vec<_> = stream::iter(batch)
+            .map(|id| async move {
+                let transaction = self.transaction_processor.get_pending_transaction(&id).await?;
+                let result = self.client_for_network(&transaction.network).get_transaction(&id).await;
+                Some((id, transaction.network, result))
+            })
+            .buffer_unordered(monitoring.max_concurrent_checks.max(1))
+            .filter_map(|lookup| async move { lookup })
+            .collect()
+            .await;
+
+        let timeout = Duration::from_secs(monitoring.pending_confirmation_timeout_seconds);
+        let now = std::time::Instant::now();
+        // 每个网络每轮只查询一次最新检查点
+        let mut latest_checkpoints: HashMap<String, Option<u64>> = HashMap::new();
+
+        for (id, network, lookup) in lookups {
+            let client = self.client_for_network(&network);
+            report.checked += 1;
+
+            let on_chain = match lookup {
+                Ok(on_chain) => Some(on_chain),
+                // 节点故障或熔断时查询失败，不计入过期判断
+                Err(e) if e.is_retriable() || matches!(e, TrackerError::CircuitOpen(_)) => {
+                    log::debug!("Failed to query pending transaction {}: {}", id, e);
+                    self.pending_checks.lock().await.entry(id).or_default().last_checked = Some(now);
+                    continue;
+                }
+                // 节点已回答（如交易不存在），计入查询次数
+                Err(e) => {
+                    log::debug!("Pending transaction {} not resolved: {}", id, e);
+                    None
+                }
+            };
+
+            let observed_status = on_chain
+                .as_ref()
+                .filter(|on_chain| on_chain.status != TransactionStatus::Pending)
+                .map(|on_chain| on_chain.status.clone());
+            let confirmed_status = match (&on_chain, &observed_status) {
+                (Some(on_chain), Some(status)) => {
+                    let is_final = if monitoring.finality_depth == 0 {
+                        true
+                    } else {
+                        let latest = match latest_checkpoints.get(client.network_name()) {
+                            Some(latest) => *latest,
+                            None => {
+                                let latest = client.get_latest_checkpoint().await.ok();
+                                latest_checkpoints.insert(client.network_name().to_string(), latest);
+                                latest
+                            }
+                        };
+                        on_chain.checkpoint.zip(latest).is_some_and(|(checkpoint, latest)| {
+                            latest >= checkpoint.saturating_add(monitoring.finality_depth)
+                        })
+                    };
+                    is_final.then(|| status.clone())
+                }
+                _ => None,
+            };
+
+            let mut checks = self.pending_checks.lock().await;
+            let check = checks.entry(id.clone()).or_default();
+            check.attempts += 1;
+            check.last_checked = Some(now);
+            let first_checked = *check.first_checked.get_or_insert(now);
+            if observed_status.is_some() {
+                check.last_status = observed_status;
+            }
+
+            match confirmed_status {
+                Some(status) => {
+                    if status == TransactionStatus::Failed {
+                        report.failed += 1;
+                    } else {
+                        report.confirmed += 1;
+                    }
+                    checks.remove(&id);
+                    drop(checks);
+                    self.transaction_processor.update_transaction_status(&id, status).await?;
+                }
+                None if check.attempts >= monitoring.pending_confirmation_max_checks
+                    || first_checked.elapsed() >= timeout =>
+                {
+                    // 链上已有结果但尚未达到 finality_depth 时沿用该结果，不退回金额
+                    let status = check.last_status.clone().unwrap_or(TransactionStatus::Failed);
+                    log::warn!(
+                        "Pending transaction {} not confirmed after {} checks, marking it as {:?}",
+                        id, check.attempts, status
+                    );
+                    report.expired += 1;
+                    checks.remove(&id);
+                    drop(checks);
+                    self.transaction_processor.update_transaction_status(&id, status).await?;
+                }
+                None => {}
+            }
+        }
+
+        if report.confirmed + report.failed + report.expired > 0 {
+            log::info!(
+                "Pending transactions: {} checked, {} confirmed, {} failed, {} expired",
+                report.checked, report.confirmed, report.failed, report.expired
+            );
+        }
+        Ok(report)
+    }
+
+    /// 跟随 `addresses.monitored` 的监控器：主网络，以及未单独配置地址列表的其他网络
+    fn primary_address_monitors(&self) -> impl Iterator<Item = &EventMonitor> {
+        let followers = self.config.networks.iter()
+            .zip(&self.network_monitors)
+            .filter(|(network, _)| network.addresses.is_empty())
+            .map(|(_, monitor)| monitor);
+        std::iter::once(&self.event_monitor).chain(followers)
+    }
+
+    /// 查询地址余额使用的客户端：主网络监控该地址时用主网络，否则用第一个监控它的网络
+    async fn client_for_address(&self, address: &str) -> &Arc<dyn SuiRpc> {
+        for monitor in std::iter::once(&self.event_monitor).chain(&self.network_monitors) {
+            if monitor.is_monitoring(address).await {
+                return monitor.sui_client();
+            }
+        }
+        &self.sui_client
+    }
+
+    /// 按网络名称选择客户端，找不到时使用主网络客户端
+    fn client_for_network(&self, network: &str) -> &Arc<dyn SuiRpc> {
+        self.network_monitors
+            .iter()
+            .map(|monitor| monitor.sui_client())
+            .find(|client| client.network_name() == network)
+            .unwrap_or(&self.sui_client)
+    }
+
+    /// 事件处理速率超过 EWMA 基线的配置倍数时发送 rate_spike 警报
+    async fn check_rate_spike(&self) -> crate::error::TrackerResult<()> {
+        let total_events = self.stats.total_events_processed.load(Ordering::Relaxed);
+        let spike = self.rate_baseline.lock().await.observe(
+            total_events,
+            std::time::Instant::now(),
+            self.config.alerts.rate_spike_multiplier,
+        );
+
+        if let Some((rate, baseline)) = spike {
+            log::warn!("Event rate spike: {:.1} events/min (baseline {:.1})", rate, baseline);
+            self.alert_system.send_custom_alert(
+                "Transaction rate spike".to_string(),
+                format!(
+                    "Processing {:.1} events/min, {:.1}x the recent baseline of {:.1} events/min",
+                    rate, rate / baseline.max(f64::EPSILON), baseline
+                ),
+                "rate_spike".to_string(),
+            ).await?;
+        }
+
+        Ok(())
+    }
+
+    /// 为各网络客户端的熔断和恢复发送警报
+    async fn send_circuit_alerts(&self) -> crate::error::TrackerResult<()> {
+        let clients = std::iter::once(&self.sui_client)
+            .chain(self.network_monitors.iter().map(|monitor| monitor.sui_client()));
+
+        for client in clients {
+            for transition in client.take_circuit_transitions() {
+                match transition {
+                    crate::sui_client::CircuitTransition::Opened { consecutive_failures } => {
+                        self.alert_system.send_network_error_alert(
+                            format!(
+                                "RPC circuit for network '{}' opened after {} consecutive failures",
+                                client.network_name(), consecutive_failures
+                            ),
+                            format!("sui_client:{}", client.network_name()),
+                        ).await?;
+                    }
+                    crate::sui_client::CircuitTransition::Closed { open_for } => {
+                        self.alert_system.send_custom_alert(
+                            "RPC circuit closed".to_string(),
+                            format!(
+                                "RPC requests to network '{}' recovered after {}s",
+                                client.network_name(), open_for.as_secs()
+                            ),
+                            "circuit_breaker".to_string(),
+                        ).await?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// 当前 SUI/USD 价格（带缓存），同时更新格式化器中的美元估值；价格源未配置或不可用时返回 None
+    ///
+    /// 监控期间由后台任务定期刷新，命令行查询在输出前调用一次。
+    pub async fn get_sui_price(&self) -> Option<f64> {
+        let price = match &self.price_client {
+            Some(price_client) => price_client.get_sui_price().await,
+            None => None,
+        };
+        self.output_formatter.set_usd_price(price);
+        price
+    }
+
+    /// 汇总最近 `window_hours` 小时的活动，没有任何交易和警报时返回 None
+    pub async fn build_activity_digest(&self, window_hours: u64) -> Option<String> {
+        let price = self.output_formatter.usd_price();
+        let start_time = (chrono::Utc::now().timestamp() as u64).saturating_sub(window_hours.saturating_mul(3600));
+        let cutoff = chrono::Utc::now() - chrono::Duration::hours(window_hours as i64);
+
+        let transactions: Vec<_> = self.transaction_processor
+            .get_all_transactions()
+            .await
+            .into_iter()
+            .filter(|tx| tx.timestamp >= start_time)
+            .collect();
+        let volume = transactions.iter().fold(0u64, |volume, tx| volume.saturating_add(tx.amount));
+
+        let mut alerts_by_type: std::collections::BTreeMap<&str, usize> = std::collections::BTreeMap::new();
+        let alerts: Vec<Alert> = self.alert_system
+            .get_alert_history(usize::MAX)
+            .await
+            .into_iter()
+            // 不统计自定义通知（包括之前的汇总本身）
+            .filter(|alert| *alert.timestamp() >= cutoff && alert.alert_type() != "custom")
+            .collect();
+        for alert in &alerts {
+            *alerts_by_type.entry(alert.alert_type()).or_default() += 1;
+        }
+
+        if transactions.is_empty() && alerts.is_empty() {
+            return None;
+        }
+
+        let stats = self.transaction_processor.get_processor_stats().await;
+        let mut digest = format!(
+            "Last {}h across {} addresses: {} transactions, {} volume, {} alerts",
+            window_hours,
+            stats.total_addresses,
+            transactions.len(),
+            self.output_formatter.format_amount(volume),
+            alerts.len()
+        );
+        if !alerts_by_type.is_empty() {
+            let by_type: Vec<String> = alerts_by_type.iter().map(|(kind, count)| format!("{}: {}", kind, count)).collect();
+            digest.push_str(&format!(" ({})", by_type.join(", ")));
+        }
+
+        if price.is_some() {
+            let portfolio = self.transaction_processor.get_all_balances().await.values().fold(0u64, |total, balance| total.saturating_add(*balance));
+            if let Some(usd) = self.output_formatter.format_usd_value(portfolio) {
+                digest.push_str(&format!("\nPortfolio value: {} SUI (≈ {})", self.output_formatter.format_sui_value(portfolio, true), usd));
+            }
+        }
+
+        let top = self.transaction_processor.get_top_addresses_by_volume(3, window_hours).await;
+        if !top.is_empty() {
+            digest.push_str("\nTop addresses:");
+            for (address, volume) in top {
+                digest.push_str(&format!("\n  {} {}", address, self.output_formatter.format_amount(volume)));
+            }
+        }
+
+        digest.push_str(&format!(
+            "\nSince start: {} transactions, {} volume",
+            stats.total_transactions,
+            self.output_formatter.format_amount(stats.total_volume)
+        ));
+        Some(digest)
+    }
+
+    /// 通过已启用的警报渠道发送活动汇总
+    async fn send_activity_digest(&self, window_hours: u64) -> crate::error::TrackerResult<()> {
+        match self.build_activity_digest(window_hours).await {
+            Some(digest) => {
+                self.alert_system.send_custom_alert(
+                    format!("Activity digest (last {}h)", window_hours),
+                    digest,
+                    "digest".to_string(),
+                ).await
+            }
+            None => {
+                log::debug!("No activity in the last {}h, skipping digest", window_hours);
+                Ok(())
+            }
+        }
+    }
+
     async fn output_balance_summary(&self) -> crate::error::TrackerResult<()> {
-        let balances = self.transaction_processor.get_all_balances().await;
-        let summary = self.output_formatter.format_balance_summary(&balances);
-        
-        println!("\n{}", summary);
+        let output_config = &self.config.output;
+
+        if output_config.summary_show_balances {
+            let price = self.output_formatter.usd_price();
+            let balances = self.transaction_processor.get_all_balances().await;
+            let limit = (output_config.summary_top_n > 0).then_some(output_config.summary_top_n);
+            let summary = self.output_formatter.format_balance_summary_top(&balances, limit);
+            self.output_sink.write(&format!("\n{}", summary));
+
+            // 价格可用时附加组合总值
+            if price.is_some() {
+                let portfolio = balances.values().fold(0u64, |total, balance| total.saturating_add(*balance));
+                if let Some(usd) = self.output_formatter.format_usd_value(portfolio) {
+                    self.output_sink.write(&format!("Portfolio value: {} SUI (≈ {})", self.output_formatter.format_sui_value(portfolio, true), usd));
+                }
+            }
+        }
         
         // 输出系统统计信息
-        let stats = self.transaction_processor.get_processor_stats().await;
-        let stats_summary = self.output_formatter.format_system_stats(&stats);
-        println!("\n{}", stats_summary);
+        if output_config.summary_show_system_stats {
+            let stats = self.transaction_processor.get_processor_stats().await;
+            let stats_summary = self.output_formatter.format_system_stats(&stats);
+            self.output_sink.write(&format!("\n{}", stats_summary));
+        }
 
         Ok(())
     }
 
-    pub async fn add_address(&self, address: String) -> crate::error::TrackerResult<()> {
-        if !crate::config::Config::is_valid_sui_address(&address) {
-            return Err(TrackerError::invalid_address(
-                format!("Invalid SUI address: {}", address)
-            ));
+    /// 最终统计（跟踪器统计、处理器统计和RPC延迟）
+    pub async fn output_final_stats(&self) -> crate::error::TrackerResult<()> {
+        let stats = self.get_tracker_stats().await;
+        let processor_stats = self.transaction_processor.get_processor_stats().await;
+        self.output_sink.write(&format!("\n{}", self.output_formatter.format_final_stats(&stats, &processor_stats)));
+
+        let rpc_latency = self.rpc_latency_stats();
+        if !rpc_latency.is_empty() {
+            self.output_sink.write(&self.output_formatter.format_rpc_latency_stats(&rpc_latency));
+        }
+
+        Ok(())
+    }
+
+    /// 立即输出余额摘要和完整统计（收到 SIGUSR1 时调用）
+    pub async fn dump_stats(&self) -> crate::error::TrackerResult<()> {
+        self.output_balance_summary().await?;
+        self.output_final_stats().await
+    }
+
+    /// 添加监控地址，返回是否为新地址；已监控的地址只刷新余额，保留首次出现时间、交易数和警报阈值
+    pub async fn add_address(&self, address: String) -> crate::error::TrackerResult<bool> {
+        let address = Self::canonical_address(&address)?;
+        let was_new = self.monitor_address(address.clone()).await?;
+
+        {
+            let mut state = self.address_state.write().await;
+            state.removed.remove(&address);
+            state.added.insert(address);
+        }
+        self.save_monitored_addresses().await?;
+        Ok(was_new)
+    }
+
+    pub async fn remove_address(&self, address: &str) -> crate::error::TrackerResult<()> {
+        let address = &crate::config::Config::normalize_address(address).unwrap_or_else(|| address.to_string());
+        self.unmonitor_address(address).await?;
+
+        {
+            let mut state = self.address_state.write().await;
+            state.added.remove(address);
+            state.removed.insert(address.to_string());
+        }
+        self.save_monitored_addresses().await
+    }
+
+    /// 将运行时增删的地址写入 `addresses.state_file`，下次启动时自动恢复
+    pub async fn save_monitored_addresses(&self) -> crate::error::TrackerResult<()> {
+        let path = &self.config.addresses.state_file;
+        if path.is_empty() {
+            return Ok(());
+        }
+
+        let contents = serde_json::to_string_pretty(&*self.address_state.read().await)?;
+        // 先写临时文件再重命名，避免中途退出留下损坏的状态文件
+        let tmp_path = format!("{}.tmp", path);
+        std::fs::write(&tmp_path, contents)?;
+        std::fs::rename(&tmp_path, path)?;
+
+        log::debug!("Saved monitored address state to {}", path);
+        Ok(())
+    }
+
+    /// 将各网络的轮询进度写入 `monitoring.cursor_state_file`，下次启动时从该位置继续
+    pub async fn save_cursor_state(&self) -> crate::error::TrackerResult<()> {
+        let path = &self.config.monitoring.cursor_state_file;
+        if path.is_empty() {
+            return Ok(());
+        }
+
+        let mut state = CursorState::default();
+        for monitor in std::iter::once(&self.event_monitor).chain(&self.network_monitors) {
+            state.networks.insert(monitor.sui_client().network_name().to_string(), NetworkCursors {
+                addresses: monitor.address_cursors().await,
+                packages: monitor.package_cursors().await,
+            });
+        }
+
+        let contents = serde_json::to_string_pretty(&state)?;
+        // 先写临时文件再重命名，避免中途退出留下损坏的状态文件
+        let tmp_path = format!("{}.tmp", path);
+        std::fs::write(&tmp_path, contents)?;
+        std::fs::rename(&tmp_path, path)?;
+
+        log::debug!("Saved cursor state to {}", path);
+        Ok(())
+    }
+
+    /// 可同时监控的地址数上限
+    pub fn max_monitored_addresses(&self) -> usize {
+        self.config.monitoring.max_monitored_addresses
+    }
+
+    /// 将外部输入的地址规范化，无效时返回错误
+    fn canonical_address(address: &str) -> crate::error::TrackerResult<String> {
+        crate::config::Config::normalize_address(address).ok_or_else(|| {
+            TrackerError::invalid_address(format!("Invalid SUI address: {}", address))
+        })
+    }
+
+    /// 开始监控地址，地址已在监控中时只更新余额并返回 false
+    async fn monitor_address(&self, address: String) -> crate::error::TrackerResult<bool> {
+        let address = Self::canonical_address(&address)?;
+
+        // 已监控的地址不占用新名额
+        {
+            let addresses = self.monitored_addresses.read().await;
+            let max_addresses = self.max_monitored_addresses();
+            if !addresses.contains_key(&address) && addresses.len() >= max_addresses {
+                return Err(TrackerError::validation_error(format!(
+                    "Cannot monitor {}: {} addresses already monitored, max_monitored_addresses is {}",
+                    address, addresses.len(), max_addresses
+                )));
+            }
         }
 
         // 获取初始余额
@@ -392,48 +1607,82 @@ impl TokenTransferTracker {
             .unwrap()
             .as_secs();
 
+        {
+            let mut addresses = self.monitored_addresses.write().await;
+            if let Some(info) = addresses.get_mut(&address) {
+                info.balance = balance;
+                info.last_checked = current_time;
+                log::info!("Address {} is already monitored, refreshed its balance", address);
+                return Ok(false);
+            }
+        }
+
+        let alert_threshold = match self.config.address_low_balance_threshold(&address) {
+            Some(threshold) => threshold,
+            None => self.alert_system.low_balance_threshold().await,
+        };
+
         {
             let mut addresses = self.monitored_addresses.write().await;
             addresses.insert(address.clone(), AddressInfo {
                 balance,
                 last_checked: current_time,
-                alert_threshold: Some(self.config.alerts.low_balance_threshold),
+                alert_threshold: Some(alert_threshold),
                 total_transactions: 0,
                 first_seen: current_time,
                 last_seen: current_time,
+                tags: self.config.address_tags(&address),
             });
         }
 
+        self.alert_system.set_threshold(address.clone(), alert_threshold).await;
+
         // 添加到监控器
-        self.event_monitor.add_address(address.clone()).await?;
+        for monitor in self.primary_address_monitors() {
+            monitor.add_address(address.clone()).await?;
+        }
 
         // 更新统计信息
         self.update_monitored_addresses_count().await;
 
         log::info!("Added address to monitoring: {}", address);
-        println!("{}", self.output_formatter.format_success(&format!("Added address: {}", address)));
+        self.report_success(&format!("Added address: {}", address));
 
-        Ok(())
+        Ok(true)
     }
 
-    pub async fn remove_address(&self, address: &str) -> crate::error::TrackerResult<()> {
+    async fn unmonitor_address(&self, address: &str) -> crate::error::TrackerResult<()> {
         {
             let mut addresses = self.monitored_addresses.write().await;
             addresses.remove(address);
         }
 
-        self.event_monitor.remove_address(address).await?;
+        self.alert_system.remove_threshold(address).await;
+        for monitor in self.primary_address_monitors() {
+            monitor.remove_address(address).await?;
+        }
         self.update_monitored_addresses_count().await;
 
         log::info!("Removed address from monitoring: {}", address);
-        println!("{}", self.output_formatter.format_success(&format!("Removed address: {}", address)));
+        self.report_success(&format!("Removed address: {}", address));
 
         Ok(())
     }
 
     pub async fn get_address_info(&self, address: &str) -> Option<AddressInfo> {
+        let address = crate::config::Config::normalize_address(address)?;
         let addresses = self.monitored_addresses.read().await;
-        addresses.get(address).cloned()
+        addresses.get(&address).cloned()
+    }
+
+    /// 所有监控地址及其信息（一次加锁取快照，按地址排序）
+    pub async fn get_all_addresses_with_info(&self) -> Vec<(String, AddressInfo)> {
+        let mut entries: Vec<(String, AddressInfo)> = self.monitored_addresses.read().await
+            .iter()
+            .map(|(address, info)| (address.clone(), info.clone()))
+            .collect();
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+        entries
     }
 
     pub async fn get_all_addresses(&self) -> Vec<String> {
@@ -441,25 +1690,271 @@ impl TokenTransferTracker {
         addresses.keys().cloned().collect()
     }
 
+    pub async fn get_group_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.groups.read().await.keys().cloned().collect();
+        names.sort();
+        names
+    }
+
+    async fn group_addresses(&self, group: &str) -> crate::error::TrackerResult<Vec<String>> {
+        if let Some(addresses) = self.groups.read().await.get(group) {
+            return Ok(addresses.iter()
+                .map(|address| crate::config::Config::normalize_address(address).unwrap_or_else(|| address.clone()))
+                .collect());
+        }
+        Err(TrackerError::validation_error(format!(
+            "Unknown address group '{}' (configured groups: {})",
+            group,
+            self.get_group_names().await.join(", ")
+        )))
+    }
+
+    /// 组内所有地址的余额之和
+    pub async fn get_group_balance(&self, group: &str) -> crate::error::TrackerResult<u64> {
+        let mut total: u64 = 0;
+        for address in self.group_addresses(group).await? {
+            total = total.saturating_add(self.transaction_processor.get_address_balance(&address).await);
+        }
+        Ok(total)
+    }
+
+    /// 汇总组内所有地址的统计信息，组内地址之间的转账会同时计入发出和收到
+    pub async fn get_group_stats(&self, group: &str) -> crate::error::TrackerResult<GroupStats> {
+        let addresses = self.group_addresses(group).await?;
+        let mut group_stats = GroupStats {
+            name: group.to_string(),
+            addresses: addresses.len(),
+            total_balance: self.get_group_balance(group).await?,
+            ..GroupStats::default()
+        };
+
+        for address in &addresses {
+            if let Some(stats) = self.transaction_processor.get_address_stats(address).await {
+                group_stats.total_transactions += stats.total_transactions;
+                group_stats.total_sent = group_stats.total_sent.saturating_add(stats.total_sent);
+                group_stats.total_received = group_stats.total_received.saturating_add(stats.total_received);
+                group_stats.total_gas_spent = group_stats.total_gas_spent.saturating_add(stats.total_gas_spent);
+            }
+        }
+
+        Ok(group_stats)
+    }
+
     pub async fn get_tracker_stats(&self) -> TrackerStats {
-        self.stats.read().await.clone()
+        // 所有网络的监控器共用主监控器的事件通道
+        let event_sender = self.event_monitor.event_sender();
+        TrackerStats {
+            events_queued: event_sender.queued(),
+            event_backpressure_waits: event_sender.backpressure_waits(),
+            alerts_queued: self.alert_system.queued_alerts(),
+            alerts_dropped: self.alert_system.dropped_alerts(),
+            ..self.stats.snapshot()
+        }
     }
 
     // 公开的查询方法，用于命令行工具
     pub async fn query_balance(&self, address: &str, coin_type: Option<&str>) -> crate::error::TrackerResult<u64> {
-        self.sui_client.get_balance(address, coin_type).await
+        self.sui_client.get_balance(&Self::canonical_address(address)?, coin_type).await
+    }
+
+    /// 地址的 SUI 余额历史，以链上当前余额锚定最新快照并向前回推
+    ///
+    /// 本地未观测到的转账不会出现在快照中，但不影响最新余额的准确性。
+    pub async fn get_balance_history(&self, address: &str, limit: u32) -> crate::error::TrackerResult<crate::transaction_processor::BalanceHistory> {
+        let address = Self::canonical_address(address)?;
+        let current_balance = self.sui_client.get_balance(&address, Some("0x2::sui::SUI")).await?;
+        Ok(self.transaction_processor
+            .get_balance_history_anchored(&address, limit, crate::transaction_processor::BalanceAnchor::Latest(current_balance))
+            .await)
+    }
+
+    /// 主网络RPC熔断器状态
+    pub fn circuit_state(&self) -> crate::sui_client::CircuitState {
+        self.sui_client.circuit_state()
+    }
+
+    /// 各RPC方法的请求耗时统计
+    pub fn rpc_latency_stats(&self) -> Vec<crate::sui_client::RpcLatencyStats> {
+        self.sui_client.rpc_latency_stats()
+    }
+
+    pub async fn query_balance_detailed(&self, address: &str, coin_type: Option<&str>) -> crate::error::TrackerResult<crate::sui_client::BalanceDetail> {
+        self.sui_client.get_balance_detailed(&Self::canonical_address(address)?, coin_type).await
     }
 
     pub async fn query_all_balances(&self, address: &str) -> crate::error::TrackerResult<Vec<(String, u64)>> {
-        self.sui_client.get_all_balances(address).await
+        self.sui_client.get_all_balances(&Self::canonical_address(address)?).await
+    }
+
+    /// 逐页读取地址的 Coin 对象，生成数量、最大/最小 Coin 和碎片化程度报告
+    ///
+    /// 最多读取 MAX_COIN_PAGES 页，超出部分不计入报告。
+    pub async fn query_coin_report(&self, address: &str, coin_type: Option<&str>) -> crate::error::TrackerResult<crate::sui_client::CoinReport> {
+        let address = Self::canonical_address(address)?;
+        let coin_type = coin_type.unwrap_or("0x2::sui::SUI");
+
+        let mut coins = Vec::new();
+        let mut cursor: Option<String> = None;
+        for _ in 0..MAX_COIN_PAGES {
+            let (page, next_cursor) = self.sui_client
+                .get_coins(&address, Some(coin_type), cursor.as_deref(), Some(COIN_PAGE_SIZE))
+                .await?;
+            coins.extend(page);
+            cursor = next_cursor;
+            if cursor.is_none() {
+                break;
+            }
+        }
+        if cursor.is_some() {
+            log::warn!("Coin report for {} truncated at {} coin objects", address, coins.len());
+        }
+
+        Ok(crate::sui_client::CoinReport::from_coins(&address, coin_type, &coins))
+    }
+
+    /// 查询所有代币余额，同时返回无法解析的余额
+    pub async fn query_all_balances_report(&self, address: &str) -> crate::error::TrackerResult<crate::sui_client::AllBalances> {
+        self.sui_client.get_all_balances_report(&Self::canonical_address(address)?).await
     }
 
     pub async fn query_transactions_sent(&self, address: &str, limit: Option<u16>) -> crate::error::TrackerResult<Vec<crate::sui_client::SuiTransaction>> {
-        self.sui_client.query_transactions_sent(address, limit).await
+        self.sui_client.query_transactions_sent(&Self::canonical_address(address)?, limit).await
     }
 
     pub async fn query_transactions_received(&self, address: &str, limit: Option<u16>) -> crate::error::TrackerResult<Vec<crate::sui_client::SuiTransaction>> {
-        self.sui_client.query_transactions_received(address, limit).await
+        self.sui_client.query_transactions_received(&Self::canonical_address(address)?, limit).await
+    }
+
+    pub async fn get_transaction(&self, digest: &str) -> crate::error::TrackerResult<crate::sui_client::SuiTransaction> {
+        self.sui_client.get_transaction(digest).await
+    }
+
+    pub async fn query_transactions_since(
+        &self,
+        address: &str,
+        direction: crate::sui_client::TransactionDirection,
+        since: chrono::DateTime<chrono::Utc>,
+    ) -> crate::error::TrackerResult<Vec<crate::sui_client::SuiTransaction>> {
+        self.sui_client.query_transactions_since(&Self::canonical_address(address)?, direction, since).await
+    }
+
+    /// 拉取地址的历史交易，按时间顺序当作实时事件处理并触发警报检查，返回处理的事件数
+    pub async fn replay_address_history(&self, address: &str, limit: u16) -> crate::error::TrackerResult<usize> {
+        let address = Self::canonical_address(address)?;
+        let events = self.fetch_history_events(std::slice::from_ref(&address), limit).await?;
+
+        log::info!("Replaying {} historical transfers for {}", events.len(), address);
+        let replayed = events.len();
+        for event in events {
+            self.process_transfer_event(event).await?;
+        }
+
+        Ok(replayed)
+    }
+
+    /// 将地址的历史交易载入交易记录，不输出交易、不触发警报，返回载入的事件数
+    ///
+    /// 命令行查询在独立进程中运行，内存中没有交易记录，按交易记录统计前先调用。
+    pub async fn load_address_history(&self, addresses: &[String], limit: u16) -> crate::error::TrackerResult<usize> {
+        let addresses = addresses.iter()
+            .map(|address| Self::canonical_address(address))
+            .collect::<crate::error::TrackerResult<Vec<String>>>()?;
+        let events = self.fetch_history_events(&addresses, limit).await?;
+
+        log::info!("Loaded {} historical transfers for {} addresses", events.len(), addresses.len());
+        let loaded = events.len();
+        for event in events {
+            self.transaction_processor.process_transfer_event(event).await?;
+        }
+
+        Ok(loaded)
+    }
+
+    /// 各地址最近 `limit` 笔发送和接收的转账事件，按时间排序
+    ///
+    /// 同一交易可能同时出现在发送和接收结果中（如自转账、监控地址之间的转账），只保留一次（每种代币各一个事件）。
+    async fn fetch_history_events(&self, addresses: &[String], limit: u16) -> crate::error::TrackerResult<Vec<TransferEvent>> {
+        use crate::sui_client::TransactionDirection;
+
+        let network_name = self.sui_client.network_name();
+        let mut seen = std::collections::HashSet::new();
+        let mut events = Vec::new();
+        for address in addresses {
+            let sent = self.sui_client.query_transactions_sent(address, Some(limit)).await?;
+            let received = self.sui_client.query_transactions_received(address, Some(limit)).await?;
+            events.extend(
+                crate::sui_client::transactions_to_events(network_name, address, TransactionDirection::Sent, sent)
+                    .into_iter()
+                    .chain(crate::sui_client::transactions_to_events(network_name, address, TransactionDirection::Received, received))
+                    .filter(|event| seen.insert((event.id.clone(), event.token_type.clone())))
+                    .filter_map(EventMonitor::parse_transfer_event),
+            );
+        }
+        events.sort_by_key(|event| event.timestamp);
+        Ok(events)
+    }
+
+    /// 用链上余额逐代币类型校正处理器中按转账累加得到的余额
+    ///
+    /// 每个地址对账SUI以及处理过的其他代币类型，`checked` 和 `reconciled` 按（地址, 代币类型）计数。
+    pub async fn reconcile_balances(&self) -> crate::error::TrackerResult<ReconciliationReport> {
+        let tolerance = self.config.monitoring.reconcile_tolerance;
+        let mut report = ReconciliationReport::default();
+
+        for address in self.get_all_addresses().await {
+            let client = self.client_for_address(&address).await;
+            let mut coin_types: Vec<String> = self.transaction_processor.get_token_balances(&address).await.into_keys().collect();
+            let sui = crate::config::Config::normalize_coin_type("0x2::sui::SUI");
+            if !coin_types.iter().any(|coin_type| crate::config::Config::normalize_coin_type(coin_type) == sui) {
+                coin_types.push("0x2::sui::SUI".to_string());
+            }
+            coin_types.sort();
+
+            let mut updated = false;
+            for coin_type in coin_types {
+                let on_chain = match client.get_balance_uncached(&address, Some(&coin_type)).await {
+                    Ok(balance) => balance,
+                    Err(e) => {
+                        log::warn!("Failed to fetch {} balance for reconciliation of {}: {}", coin_type, address, e);
+                        continue;
+                    }
+                };
+                report.checked += 1;
+                updated = true;
+
+                let computed = self.transaction_processor.set_token_balance(&address, &coin_type, on_chain).await;
+                let drift = computed.abs_diff(on_chain);
+                if drift > tolerance {
+                    log::warn!(
+                        "Balance drift for {} ({}): computed {}, on-chain {}",
+                        address, coin_type, computed, on_chain
+                    );
+                    report.reconciled += 1;
+                    report.total_drift = report.total_drift.saturating_add(drift);
+                    report.max_drift = report.max_drift.max(drift);
+                }
+            }
+            if !updated {
+                continue;
+            }
+
+            let balance = self.transaction_processor.get_address_balance(&address).await;
+            let mut addresses = self.monitored_addresses.write().await;
+            if let Some(address_info) = addresses.get_mut(&address) {
+                address_info.balance = balance;
+                address_info.last_checked = std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .unwrap()
+                    .as_secs();
+            }
+        }
+
+        self.stats.balances_reconciled.fetch_add(report.reconciled as u64, Ordering::Relaxed);
+        let _ = self.stats.total_balance_drift.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |drift| {
+            Some(drift.saturating_add(report.total_drift))
+        });
+
+        Ok(report)
     }
 
     pub async fn force_balance_check(&self) -> crate::error::TrackerResult<()> {
@@ -469,7 +1964,8 @@ impl TokenTransferTracker {
         let mut updates = 0;
 
         for address in addresses {
-            match self.sui_client.get_balance(&address, Some("0x2::sui::SUI")).await {
+            let client = self.client_for_address(&address).await;
+            match client.get_balance(&address, Some("0x2::sui::SUI")).await {
                 Ok(balance) => {
                     let mut addresses = self.monitored_addresses.write().await;
                     if let Some(address_info) = addresses.get_mut(&address) {
@@ -488,62 +1984,192 @@ impl TokenTransferTracker {
         }
 
         log::info!("Balance check completed, updated {} addresses", updates);
-        println!("{}", self.output_formatter.format_success(&format!("Balance check completed, updated {} addresses", updates)));
+        self.report_success(&format!("Balance check completed, updated {} addresses", updates));
 
         Ok(())
     }
 
-    pub async fn export_data(&self, format: &str, output_path: &str) -> crate::error::TrackerResult<()> {
+    /// 当前编译启用的导出格式
+    pub fn export_formats() -> Vec<&'static str> {
+        #[allow(unused_mut)]
+        let mut formats = vec!["json", "csv"];
+        #[cfg(feature = "parquet")]
+        formats.push("parquet");
+        formats
+    }
+
+    /// 导出余额与统计（parquet 为全部交易），返回导出的记录数和字节数
+    pub async fn export_data(
+        &self,
+        format: &str,
+        output_path: &str,
+    ) -> crate::error::TrackerResult<crate::transaction_processor::ExportSummary> {
         let export_format = match format {
             "json" => crate::transaction_processor::ExportFormat::Json,
             "csv" => crate::transaction_processor::ExportFormat::Csv,
-            _ => return Err(TrackerError::validation_error("Invalid export format. Use 'json' or 'csv'")),
+            #[cfg(feature = "parquet")]
+            "parquet" => crate::transaction_processor::ExportFormat::Parquet,
+            _ => return Err(TrackerError::validation_error(format!(
+                "Invalid export format. Use one of: {}",
+                Self::export_formats().join(", ")
+            ))),
         };
 
-        let data = self.transaction_processor.export_data(export_format).await?;
-        std::fs::write(output_path, data)?;
-        
-        log::info!("Exported data to {} in {} format", output_path, format);
-        println!("{}", self.output_formatter.format_success(&format!("Exported data to {}", output_path)));
+        let summary = self.transaction_processor
+            .export_to_path(export_format, std::path::Path::new(output_path))
+            .await?;
 
-        Ok(())
+        let records_label = if format == "parquet" { "transactions" } else { "addresses" };
+        log::info!(
+            "Exported {} {} ({} bytes) to {} in {} format",
+            summary.records, records_label, summary.bytes, output_path, format
+        );
+        self.report_success(&OutputFormatter::format_export_summary(&summary, records_label, output_path));
+
+        Ok(summary)
     }
 
-    // 统计信息更新方法
-    #[allow(dead_code)]
-    async fn increment_events_processed(&self) {
-        let mut stats = self.stats.write().await;
-        stats.total_events_processed += 1;
+    /// 导出完整交易历史（json 为交易数组，csv 与交易历史列相同），逐条写入文件，返回导出的条数和字节数
+    pub async fn export_history(
+        &self,
+        format: &str,
+        output_path: &str,
+    ) -> crate::error::TrackerResult<crate::transaction_processor::ExportSummary> {
+        use crate::transaction_processor::{ExportFormat, ExportSummary};
+        use std::io::Write;
+
+        let export_format = match format {
+            "json" => ExportFormat::Json,
+            "csv" => ExportFormat::Csv,
+            _ => return Err(TrackerError::validation_error(
+                "Transaction history export supports json and csv",
+            )),
+        };
+
+        let is_json = matches!(export_format, ExportFormat::Json);
+        let mut writer = std::io::BufWriter::new(std::fs::File::create(output_path)?);
+        let formatter = &self.output_formatter;
+
+        if is_json {
+            writer.write_all(b"[")?;
+        } else {
+            writer.write_all(crate::output_formatter::TRANSACTION_HISTORY_CSV_HEADER.as_bytes())?;
+        }
+
+        let mut first = true;
+        let count = self.transaction_processor.for_each_transaction(|tx| {
+            if is_json {
+                if !first {
+                    writer.write_all(b",")?;
+                }
+                writer.write_all(b"\n")?;
+                serde_json::to_writer(&mut writer, &formatter.transaction_json_value(tx))?;
+            } else {
+                writer.write_all(formatter.transaction_history_csv_row(tx).as_bytes())?;
+            }
+            first = false;
+            Ok(())
+        }).await?;
+
+        if is_json {
+            writer.write_all(b"\n]\n")?;
+        }
+        writer.flush()?;
+
+        let summary = ExportSummary {
+            records: count,
+            bytes: writer.get_ref().metadata()?.len() as usize,
+            format: export_format,
+        };
+        log::info!("Exported {} transactions ({} bytes) to {} in {} format", count, summary.bytes, output_path, format);
+        self.report_success(&OutputFormatter::format_export_summary(&summary, "transactions", output_path));
+
+        Ok(summary)
     }
 
-    #[allow(dead_code)]
-    async fn increment_transactions_processed(&self) {
-        let mut stats = self.stats.write().await;
-        stats.total_transactions_processed += 1;
+    /// 追加导出交易CSV：文件不存在时写入表头，只追加上次导出之后的新交易，返回追加的条数
+    pub async fn export_transactions_append(&self, output_path: &str) -> crate::error::TrackerResult<usize> {
+        use std::io::Write;
+
+        let cursor_path = format!("{}.cursor", output_path);
+        let is_new_file = std::fs::metadata(output_path).map(|m| m.len() == 0).unwrap_or(true);
+
+        // 导出文件不存在时忽略旧的进度
+        let cursor: ExportCursor = if is_new_file {
+            ExportCursor::default()
+        } else {
+            match std::fs::read_to_string(&cursor_path) {
+                Ok(contents) => serde_json::from_str(&contents)?,
+                Err(_) => ExportCursor::default(),
+            }
+        };
+
+        let transactions: Vec<_> = self.transaction_processor.get_all_transactions().await
+            .into_iter()
+            .filter(|tx| {
+                tx.timestamp > cursor.last_timestamp
+                    || (tx.timestamp == cursor.last_timestamp && !cursor.last_ids.contains(&tx.id))
+            })
+            .collect();
+
+        if !transactions.is_empty() || is_new_file {
+            let mut file = std::fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(output_path)?;
+            file.write_all(self.output_formatter.format_transactions_csv(&transactions, is_new_file).as_bytes())?;
+        }
+
+        if let Some(last) = transactions.last() {
+            let mut next_cursor = if last.timestamp == cursor.last_timestamp {
+                cursor
+            } else {
+                ExportCursor { last_timestamp: last.timestamp, last_ids: Vec::new() }
+            };
+            next_cursor.last_ids.extend(
+                transactions.iter()
+                    .filter(|tx| tx.timestamp == last.timestamp)
+                    .map(|tx| tx.id.clone()),
+            );
+            std::fs::write(&cursor_path, serde_json::to_string(&next_cursor)?)?;
+        }
+
+        log::info!("Appended {} transactions to {}", transactions.len(), output_path);
+        self.report_success(&format!(
+            "Appended {} new transactions to {}", transactions.len(), output_path
+        ));
+
+        Ok(transactions.len())
     }
 
-    #[allow(dead_code)]
-    async fn increment_alerts_sent(&self) {
-        let mut stats = self.stats.write().await;
-        stats.total_alerts_sent += 1;
+    /// 操作完成提示，quiet 模式下不显示
+    fn report_success(&self, message: &str) {
+        if !self.output_formatter.is_quiet() {
+            println!("{}", self.output_formatter.format_success(message));
+        }
     }
 
-    async fn increment_errors(&self) {
-        let mut stats = self.stats.write().await;
-        stats.total_errors += 1;
+    // 统计信息更新方法
+    fn increment_events_processed(&self) {
+        self.stats.total_events_processed.fetch_add(1, Ordering::Relaxed);
     }
 
-    async fn update_uptime(&self) {
-        let mut stats = self.stats.write().await;
-        stats.uptime_seconds = stats.start_time
-            .elapsed()
-            .unwrap_or_default()
-            .as_secs();
+    fn increment_transactions_processed(&self) {
+        self.stats.total_transactions_processed.fetch_add(1, Ordering::Relaxed);
+    }
+
+    #[allow(dead_code)]
+    fn increment_alerts_sent(&self) {
+        self.stats.total_alerts_sent.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn increment_errors(&self) {
+        self.stats.total_errors.fetch_add(1, Ordering::Relaxed);
     }
 
     async fn update_monitored_addresses_count(&self) {
-        let mut stats = self.stats.write().await;
-        stats.addresses_monitored = self.monitored_addresses.read().await.len();
+        let count = self.monitored_addresses.read().await.len();
+        self.stats.addresses_monitored.store(count, Ordering::Relaxed);
     }
 
     fn init_logging(logging_config: &crate::config::LoggingConfig) {
@@ -564,9 +2190,20 @@ impl TokenTransferTracker {
         
         builder.filter_level(level);
         
-        // 如果需要文件输出
+        // 如果需要文件输出，按 max_file_size_mb / rotate_files 轮转
         if !logging_config.file_path.is_empty() {
-            builder.target(env_logger::Target::Pipe(Box::new(std::fs::File::create(&logging_config.file_path).unwrap())));
+            match crate::rotating_file::RotatingFile::open_mb(
+                &logging_config.file_path,
+                logging_config.max_file_size_mb,
+                logging_config.rotate_files,
+            ) {
+                Ok(file) => {
+                    builder.target(env_logger::Target::Pipe(Box::new(file)));
+                }
+                Err(e) => {
+                    eprintln!("Failed to open log file {}: {}, logging to console", logging_config.file_path, e);
+                }
+            }
         }
         
         let _ = builder.try_init();
@@ -580,6 +2217,154 @@ mod tests {
     use super::*;
     use crate::config::Config;
 
+    #[tokio::test]
+    async fn test_cursor_state_persistence() {
+        let address = format!("0x{}", "a".repeat(64));
+        let path = std::env::temp_dir().join(format!("tracker_cursors_{}.json", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let mut config = Config::default();
+        config.output.quiet = true;
+        config.addresses.monitored = vec![address.clone()];
+        config.monitoring.cursor_state_file = path.to_string_lossy().to_string();
+        let client = Arc::new(crate::mock_sui_client::MockSuiClient::new());
+
+        let tracker = TokenTransferTracker::with_rpc_client(config.clone(), client.clone()).await.unwrap();
+        let cursor = crate::event_monitor::AddressCursor {
+            last_digest: Some("digest-9".to_string()),
+            last_checked: 1_700_000_000,
+        };
+        let saved = HashMap::from([(address.clone(), cursor.clone())]);
+        tracker.event_monitor.restore_cursors(&saved, &HashMap::new()).await;
+        tracker.save_cursor_state().await.unwrap();
+
+        // 重新启动后从保存的进度继续
+        let restarted = TokenTransferTracker::with_rpc_client(config.clone(), client.clone()).await.unwrap();
+        assert_eq!(restarted.event_monitor.address_cursors().await.get(&address), Some(&cursor));
+
+        // 其他网络的进度不会套用到主网络
+        let other_client = Arc::new(crate::mock_sui_client::MockSuiClient::new().with_network_name("testnet"));
+        let other = TokenTransferTracker::with_rpc_client(config, other_client).await.unwrap();
+        assert_eq!(other.event_monitor.address_cursors().await.get(&address), Some(&Default::default()));
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[tokio::test]
+    async fn test_reload_keeps_cli_overrides() {
+        let path = std::env::temp_dir().join(format!("tracker_reload_{}.toml", std::process::id()));
+        let mut config = Config::default();
+        config.output.quiet = true;
+        config.alerts.low_balance_threshold = 1_000;
+        config.save(&path).unwrap();
+
+        let args = crate::config::ConfigArgs {
+            low_balance_threshold: Some(5_000),
+            ..Default::default()
+        };
+        config.merge_with_args(&args);
+        let mut tracker = TokenTransferTracker::with_rpc_client(
+            config,
+            Arc::new(crate::mock_sui_client::MockSuiClient::new()),
+        ).await.unwrap();
+        tracker.set_config_path(path.to_string_lossy().to_string());
+        tracker.set_config_args(args);
+
+        // 重新加载后仍使用命令行指定的阈值，而不是文件中的值
+        tracker.reload_config().await.unwrap();
+        assert_eq!(tracker.alert_system.low_balance_threshold().await, 5_000);
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[tokio::test]
+    async fn test_low_balance_opt_out() {
+        let [ignored, zero_threshold, alerted] = ["a", "b", "c"].map(|c| format!("0x{}", c.repeat(64)));
+        let mut config = Config::default();
+        config.output.quiet = true;
+        config.alerts.enable_console_alerts = false;
+        config.addresses.monitored = vec![ignored.clone(), zero_threshold.clone(), alerted.clone()];
+        config.addresses.tags.insert(ignored.clone(), vec![IGNORE_LOW_BALANCE_TAG.to_string()]);
+        config.addresses.low_balance_thresholds.insert(zero_threshold.clone(), 0);
+        let tracker = TokenTransferTracker::with_rpc_client(
+            config,
+            Arc::new(crate::mock_sui_client::MockSuiClient::new()),
+        ).await.unwrap();
+
+        assert!(!tracker.get_address_info(&ignored).await.unwrap().low_balance_alerts_enabled());
+        assert!(!tracker.get_address_info(&zero_threshold).await.unwrap().low_balance_alerts_enabled());
+        assert!(tracker.get_address_info(&alerted).await.unwrap().low_balance_alerts_enabled());
+
+        for (id, sender, recipient) in [("tx-1", &ignored, &zero_threshold), ("tx-2", &zero_threshold, &alerted)] {
+            tracker.process_transfer_event(TransferEvent {
+                transaction_id: id.to_string(),
+                package_id: "0x2".to_string(),
+                transaction_module: "pay".to_string(),
+                sender: sender.clone(),
+                recipient: recipient.clone(),
+                amount: 1,
+                token_type: "0x2::sui::SUI".to_string(),
+                timestamp: 1_700_000_000,
+                block_number: 1,
+                event_type: "transfer".to_string(),
+                gas_used: None,
+                status: crate::transaction_processor::TransactionStatus::Success,
+                network: String::new(),
+            }).await.unwrap();
+        }
+
+        let low_balance: Vec<String> = tracker.alert_system.get_alert_history(100).await
+            .into_iter()
+            .filter_map(|alert| match alert {
+                Alert::LowBalance { address, .. } => Some(address),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(low_balance, vec![alerted]);
+    }
+
+    #[tokio::test]
+    async fn test_depletion_alert_sent_once_per_crossing() {
+        let address = format!("0x{}", "a".repeat(64));
+        let other = format!("0x{}", "b".repeat(64));
+        let mut config = Config::default();
+        config.output.quiet = true;
+        config.alerts.enable_console_alerts = false;
+        config.alerts.depletion_window_hours = 1;
+        config.addresses.monitored = vec![address.clone()];
+        let tracker = TokenTransferTracker::with_rpc_client(
+            config,
+            Arc::new(crate::mock_sui_client::MockSuiClient::new()),
+        ).await.unwrap();
+        tracker.transaction_processor.set_address_balance(&address, 3_500).await;
+
+        let now = chrono::Utc::now().timestamp() as u64;
+        for id in ["tx-1", "tx-2", "tx-3"] {
+            tracker.process_transfer_event(TransferEvent {
+                transaction_id: id.to_string(),
+                package_id: "0x2".to_string(),
+                transaction_module: "pay".to_string(),
+                sender: address.clone(),
+                recipient: other.clone(),
+                amount: 1_000,
+                token_type: "0x2::sui::SUI".to_string(),
+                timestamp: now,
+                block_number: 1,
+                event_type: "transfer".to_string(),
+                gas_used: None,
+                status: crate::transaction_processor::TransactionStatus::Success,
+                network: String::new(),
+            }).await.unwrap();
+        }
+
+        // 三笔交易后预测都在时限内，只在首次进入时警报
+        let depletion = tracker.alert_system.get_alert_history(100).await
+            .into_iter()
+            .filter(|alert| matches!(alert, Alert::Custom { category, .. } if category == "depletion"))
+            .count();
+        assert_eq!(depletion, 1);
+    }
+
     #[tokio::test]
     async fn test_tracker_creation() {
         let config = Config::default();
@@ -618,4 +2403,54 @@ mod tests {
             }
         }
     }
+
+    #[tokio::test]
+    async fn test_addresses_monitored_initialized() {
+        let mut config = Config::default();
+        config.addresses.monitored = vec![
+            format!("0x{}", "1".repeat(64)),
+            format!("0x{}", "2".repeat(64)),
+        ];
+
+        let rpc = Arc::new(crate::mock_sui_client::MockSuiClient::new());
+        let tracker = TokenTransferTracker::with_rpc_client(config, rpc).await.unwrap();
+        assert_eq!(tracker.get_tracker_stats().await.addresses_monitored, 2);
+
+        let listed: Vec<String> = tracker.get_all_addresses_with_info().await.into_iter().map(|(address, _)| address).collect();
+        assert_eq!(listed, vec![format!("0x{}", "1".repeat(64)), format!("0x{}", "2".repeat(64))]);
+    }
+
+    #[test]
+    fn test_rate_baseline_spike() {
+        let start = std::time::Instant::now();
+        let minute = Duration::from_secs(60);
+        let mut baseline = RateBaseline::new(start);
+
+        // 第一个周期只建立基线
+        assert!(baseline.observe(20, start + minute, 5.0).is_none());
+        // 速率平稳
+        assert!(baseline.observe(40, start + minute * 2, 5.0).is_none());
+        // 速率突增到基线的10倍
+        let (rate, base) = baseline.observe(240, start + minute * 3, 5.0).unwrap();
+        assert_eq!(rate, 200.0);
+        assert_eq!(base, 20.0);
+        // 关闭时不报警
+        assert!(baseline.observe(10_000, start + minute * 4, 0.0).is_none());
+    }
+
+    #[test]
+    fn test_address_state_apply() {
+        let canonical = |address: &str| config::Config::normalize_address(address).unwrap();
+        let configured = vec!["0xaaa".to_string(), "0xbbb".to_string()];
+        let mut state = AddressState::default();
+        assert_eq!(state.apply(&configured), vec![canonical("0xaaa"), canonical("0xbbb")]);
+
+        state.added.insert("0xccc".to_string());
+        state.added.insert("0xAAA".to_string());
+        state.removed.insert("0xBbb".to_string());
+        assert_eq!(state.apply(&configured), vec![canonical("0xaaa"), canonical("0xccc")]);
+
+        let restored: AddressState = serde_json::from_str(&serde_json::to_string(&state).unwrap()).unwrap();
+        assert_eq!(restored.apply(&configured), state.apply(&configured));
+    }
 }
\ No newline at end of file
@@ -1,15 +1,26 @@
 use clap::{Arg, ArgMatches, Command};
 use sui_token_transfer_tracker::{TokenTransferTracker, Config, config::ConfigArgs, TrackerResult, TrackerError, OutputFormat};
+use sui_token_transfer_tracker::transaction_processor::{SearchCriteria, TransactionStatus};
+use sui_token_transfer_tracker::output_formatter::{AddressListEntry, OutputFormatter};
+use sui_token_transfer_tracker::messages::Messages;
+use sui_token_transfer_tracker::sui_client::{SuiTransaction, TransactionDirection};
+use sui_token_transfer_tracker::units::{mist_to_sui, parse_sui_amount, SUI_DECIMALS};
+use std::fmt::Display;
+use std::ops::RangeInclusive;
 use std::path::Path;
+use std::str::FromStr;
 
 #[tokio::main]
 async fn main() -> TrackerResult<()> {
     // 解析命令行参数
     let matches = parse_args();
 
-    // 初始化日志
-    if let Err(_) = env_logger::try_init_from_env(env_logger::Env::default().default_filter_or("info")) {
-        // 日志已经初始化过了，忽略错误
+    // 初始化日志（守护进程模式下由跟踪器按配置写入日志文件）
+    let daemon = matches.get_flag("daemon");
+    if !daemon {
+        if let Err(_) = env_logger::try_init_from_env(env_logger::Env::default().default_filter_or("info")) {
+            // 日志已经初始化过了，忽略错误
+        }
     }
 
     // 处理不需要网络连接的简单命令
@@ -17,23 +28,56 @@ async fn main() -> TrackerResult<()> {
         return Ok(());
     }
 
+    // 守护进程模式：写入PID文件，已有实例运行时拒绝启动
+    let _pid_file = if daemon && should_start_monitoring(&matches) {
+        let path = matches.get_one::<String>("pid-file").unwrap();
+        Some(PidFile::create(path)?)
+    } else {
+        None
+    };
+
     // 加载配置
     let config = load_config(&matches).await?;
 
     // 创建跟踪器
     let mut tracker = TokenTransferTracker::new(config).await?;
+    if let Some(config_path) = matches.get_one::<String>("config") {
+        tracker.set_config_path(config_path.clone());
+    }
+    tracker.set_config_args(config_args(&matches)?);
 
     // 处理需要跟踪器的命令
     handle_tracker_commands(&matches, &mut tracker).await?;
 
     // 启动监控（如果需要）
     if should_start_monitoring(&matches) {
-        println!("{}", tracker.output_formatter.format_welcome_message());
+        if !tracker.output_formatter.is_quiet() {
+            println!("{}", tracker.output_formatter.format_welcome_message());
+        }
+
+        if matches.get_flag("start-paused") {
+            tracker.pause_monitoring().await;
+            if !tracker.output_formatter.is_quiet() {
+                println!("⏸️  Monitoring paused, send SIGUSR2 to resume");
+            }
+        }
         
-        // 简化实现：直接运行监控，用户可以用Ctrl+C停止
-        if let Err(e) = tracker.start_monitoring().await {
-            eprintln!("Error starting monitoring: {}", e);
-            std::process::exit(1);
+        // 运行监控，直到收到 SIGTERM / Ctrl+C
+        let stopped_by_signal = tokio::select! {
+            result = tracker.start_monitoring() => {
+                if let Err(e) = result {
+                    eprintln!("Error starting monitoring: {}", e);
+                    std::process::exit(1);
+                }
+                false
+            }
+            _ = wait_for_shutdown_signal() => true,
+        };
+
+        if stopped_by_signal {
+            log::info!("Shutdown signal received, stopping tracker");
+            tracker.stop_monitoring().await?;
+            tracker.output_final_stats().await?;
         }
     }
 
@@ -41,6 +85,10 @@ async fn main() -> TrackerResult<()> {
 }
 
 fn parse_args() -> ArgMatches {
+    build_cli().get_matches()
+}
+
+fn build_cli() -> Command {
     Command::new("SUI Token Transfer Tracker")
         .version("0.1.0")
         .about("Real-time monitoring of SUI token transfers")
@@ -70,6 +118,12 @@ fn parse_args() -> ArgMatches {
             .help("Add address to monitoring list")
             .num_args(1))
         
+        .arg(Arg::new("add-addresses-file")
+            .long("add-addresses-file")
+            .value_name("FILE")
+            .help("Add addresses from a file (one per line, # starts a comment)")
+            .num_args(1))
+        
         .arg(Arg::new("remove-address")
             .long("remove-address")
             .value_name("ADDRESS")
@@ -92,7 +146,7 @@ fn parse_args() -> ArgMatches {
             .short('i')
             .long("poll-interval")
             .value_name("SECONDS")
-            .help("Polling interval in seconds")
+            .help("Polling interval in seconds (1-86400)")
             .num_args(1))
         
         // 警报配置
@@ -100,13 +154,13 @@ fn parse_args() -> ArgMatches {
             .short('t')
             .long("threshold")
             .value_name("AMOUNT")
-            .help("Low balance threshold in SUI")
+            .help("Low balance threshold in SUI, e.g. 1.5 (converted to MIST)")
             .num_args(1))
         
         .arg(Arg::new("large-transfer-threshold")
             .long("large-transfer-threshold")
             .value_name("AMOUNT")
-            .help("Large transfer threshold in SUI")
+            .help("Large transfer threshold in SUI, e.g. 100 (converted to MIST)")
             .num_args(1))
         
         // 输出选项
@@ -127,6 +181,32 @@ fn parse_args() -> ArgMatches {
             .num_args(1)
             .value_parser(["table", "json", "csv"]))
         
+        .arg(Arg::new("json")
+            .long("json")
+            .help("Shorthand for --output-format json")
+            .conflicts_with("output-format")
+            .action(clap::ArgAction::SetTrue))
+        
+        .arg(Arg::new("quiet")
+            .long("quiet")
+            .help("Only print data and errors (no banners, headers or progress messages)")
+            .action(clap::ArgAction::SetTrue))
+        
+        .arg(Arg::new("alerts-only")
+            .long("alerts-only")
+            .help("Monitor without printing transactions or balance summaries, only alerts")
+            .action(clap::ArgAction::SetTrue))
+        
+        .arg(Arg::new("no-summary")
+            .long("no-summary")
+            .help("Disable the periodic balance summary (same as balance_summary_interval = 0)")
+            .action(clap::ArgAction::SetTrue))
+        
+        .arg(Arg::new("start-paused")
+            .long("start-paused")
+            .help("Start with polling paused; send SIGUSR2 to resume (and again to pause)")
+            .action(clap::ArgAction::SetTrue))
+        
         // 日志选项
         .arg(Arg::new("log-level")
             .long("log-level")
@@ -150,15 +230,28 @@ fn parse_args() -> ArgMatches {
         .arg(Arg::new("export")
             .long("export")
             .value_name("FORMAT")
-            .help("Export data (json, csv)")
+            .help(format!("Export data ({})", TokenTransferTracker::export_formats().join(", ")))
             .num_args(1)
-            .value_parser(["json", "csv"]))
+            .value_parser(clap::builder::PossibleValuesParser::new(TokenTransferTracker::export_formats())))
+        
+        .arg(Arg::new("export-append")
+            .long("export-append")
+            .help("Append only new transactions to an existing CSV export (use with --export csv)")
+            .requires("export")
+            .action(clap::ArgAction::SetTrue))
+        
+        .arg(Arg::new("export-history")
+            .long("export-history")
+            .help("Export every recorded transaction instead of the summary (use with --export json|csv)")
+            .requires("export")
+            .conflicts_with("export-append")
+            .action(clap::ArgAction::SetTrue))
         
         .arg(Arg::new("output")
             .short('o')
             .long("output")
             .value_name("FILE")
-            .help("Output file for export")
+            .help("Output file for export and --volume-timeseries")
             .num_args(1))
         
         .arg(Arg::new("generate-config")
@@ -166,6 +259,16 @@ fn parse_args() -> ArgMatches {
             .help("Generate default configuration file")
             .action(clap::ArgAction::SetTrue))
         
+        .arg(Arg::new("health-check")
+            .long("health-check")
+            .help("Check configuration and RPC connectivity, then exit (non-zero on failure)")
+            .action(clap::ArgAction::SetTrue))
+        
+        .arg(Arg::new("config-check")
+            .long("config-check")
+            .help("Validate the configuration without network access, then exit (non-zero on failure)")
+            .action(clap::ArgAction::SetTrue))
+        
         .arg(Arg::new("dry-run")
             .long("dry-run")
             .help("Run in dry-run mode (no actual monitoring)")
@@ -191,12 +294,136 @@ fn parse_args() -> ArgMatches {
             .help("Show recent transactions for address")
             .num_args(1))
         
+        .arg(Arg::new("since")
+            .long("since")
+            .value_name("RFC3339")
+            .help("With --transactions: list all transactions at or after this time (e.g. 2024-05-01T00:00:00Z), ignoring --limit")
+            .requires("transactions")
+            .num_args(1))
+        
+        // 交易搜索
+        .arg(Arg::new("faucet")
+            .long("faucet")
+            .value_name("ADDRESS")
+            .help("Request test SUI from the faucet for an address (testnet/devnet only)")
+            .num_args(1))
+        
+        .arg(Arg::new("coins")
+            .long("coins")
+            .value_name("ADDRESS")
+            .help("Show SUI coin objects of an address: count, largest/smallest coin and fragmentation")
+            .num_args(1))
+        
+        .arg(Arg::new("tx")
+            .long("tx")
+            .value_name("DIGEST")
+            .help("Show a single transaction by digest")
+            .num_args(1))
+        
+        .arg(Arg::new("replay")
+            .long("replay")
+            .value_name("ADDRESS")
+            .help("Replay an address's historical transactions through processing and alert checks (up to --limit per direction)")
+            .num_args(1))
+        
+        .arg(Arg::new("search")
+            .long("search")
+            .help("Search the latest 50 sent and received transactions of the monitored addresses, or of --search-address (combine with the --search-* filters and --limit)")
+            .action(clap::ArgAction::SetTrue))
+        
+        .arg(Arg::new("search-address")
+            .long("search-address")
+            .value_name("ADDRESS")
+            .help("Only transactions sent or received by this address")
+            .num_args(1))
+        
+        .arg(Arg::new("min-amount")
+            .long("min-amount")
+            .value_name("MIST")
+            .help("Only transactions with at least this amount")
+            .num_args(1))
+        
+        .arg(Arg::new("max-amount")
+            .long("max-amount")
+            .value_name("MIST")
+            .help("Only transactions with at most this amount")
+            .num_args(1))
+        
+        .arg(Arg::new("token-type")
+            .long("token-type")
+            .value_name("TYPE")
+            .help("Only transactions of this coin type")
+            .num_args(1))
+        
+        .arg(Arg::new("start-ts")
+            .long("start-ts")
+            .value_name("UNIX_SECONDS")
+            .help("Only transactions at or after this timestamp")
+            .num_args(1))
+        
+        .arg(Arg::new("end-ts")
+            .long("end-ts")
+            .value_name("UNIX_SECONDS")
+            .help("Only transactions at or before this timestamp")
+            .num_args(1))
+        
+        .arg(Arg::new("status")
+            .long("status")
+            .value_name("STATUS")
+            .help("Only transactions with this status")
+            .num_args(1)
+            .value_parser(["success", "failed", "pending"]))
+        
+        .arg(Arg::new("top-addresses")
+            .long("top-addresses")
+            .value_name("N")
+            .help("Show the N addresses with the highest transfer volume")
+            .num_args(1))
+        
+        .arg(Arg::new("volume-timeseries")
+            .long("volume-timeseries")
+            .value_name("COIN_TYPE")
+            .help("Print the transfer volume of a coin type per time bucket as CSV (timestamp,volume), e.g. for charts")
+            .num_args(1))
+        
+        .arg(Arg::new("bucket-seconds")
+            .long("bucket-seconds")
+            .value_name("SECONDS")
+            .help("Bucket size for --volume-timeseries (default: 3600)")
+            .num_args(1)
+            .default_value("3600"))
+        
+        .arg(Arg::new("group-summary")
+            .long("group-summary")
+            .value_name("NAME")
+            .help("Show aggregated balance and volume for an address group from the config")
+            .num_args(1))
+        
+        .arg(Arg::new("window-hours")
+            .long("window-hours")
+            .value_name("HOURS")
+            .help("Time window for --top-addresses and --volume-timeseries (default: 24)")
+            .num_args(1)
+            .default_value("24"))
+        
         .arg(Arg::new("limit")
             .long("limit")
             .value_name("NUMBER")
-            .help("Limit number of transactions to show (default: 10)")
+            .help("Limit number of transactions to show, 1-1000 (default: 10; --search: no limit)")
+            .num_args(1))
+        
+        // 守护进程选项
+        .arg(Arg::new("daemon")
+            .long("daemon")
+            .help("Run as a background service: write a PID file, log to the configured log file, stop on SIGTERM")
+            .action(clap::ArgAction::SetTrue))
+        
+        .arg(Arg::new("pid-file")
+            .long("pid-file")
+            .value_name("FILE")
+            .help("PID file path used in daemon mode")
             .num_args(1)
-            .default_value("10"))
+            .default_value("sui-tracker.pid"))
         
         .arg(Arg::new("version")
             .short('V')
@@ -209,14 +436,58 @@ fn parse_args() -> ArgMatches {
             .help("Addresses to monitor")
             .action(clap::ArgAction::Append)
             .num_args(0..))
-        
-        .get_matches()
+}
+
+/// 数值参数的允许范围
+const POLL_INTERVAL_RANGE: RangeInclusive<u64> = 1..=86_400;
+const LIMIT_RANGE: RangeInclusive<u16> = 1..=1000;
+const TOP_ADDRESSES_RANGE: RangeInclusive<usize> = 1..=1000;
+const WINDOW_HOURS_RANGE: RangeInclusive<u64> = 1..=8760;
+const BUCKET_SECONDS_RANGE: RangeInclusive<u64> = 1..=604_800;
+const DEFAULT_LIMIT: u16 = 10;
+/// 命令行统计前每个地址每个方向载入的历史交易数（单页查询上限）
+const HISTORY_LOAD_LIMIT: u16 = 50;
+
+/// 解析数值参数并检查范围；未提供时返回 None
+fn parse_number_flag<T>(matches: &ArgMatches, name: &str, range: RangeInclusive<T>) -> TrackerResult<Option<T>>
+where
+    T: FromStr + PartialOrd + Display,
+{
+    matches.get_one::<String>(name)
+        .map(|value| parse_number_value(name, value, range))
+        .transpose()
+}
+
+/// 解析单个数值参数，错误信息包含参数名、输入值和允许范围
+fn parse_number_value<T>(name: &str, value: &str, range: RangeInclusive<T>) -> TrackerResult<T>
+where
+    T: FromStr + PartialOrd + Display,
+{
+    value.trim().parse::<T>()
+        .ok()
+        .filter(|parsed| range.contains(parsed))
+        .ok_or_else(|| TrackerError::validation_error(format!(
+            "Invalid value for --{}: '{}' (expected an integer between {} and {})",
+            name, value, range.start(), range.end()
+        )))
+}
+
+/// 各查询命令共用的 --limit 解析
+fn parse_limit(matches: &ArgMatches) -> TrackerResult<u16> {
+    Ok(parse_number_flag(matches, "limit", LIMIT_RANGE)?.unwrap_or(DEFAULT_LIMIT))
 }
 
 async fn load_config(matches: &ArgMatches) -> TrackerResult<Config> {
-    let mut config = Config::load(matches.get_one::<String>("config").map(|s| s.as_str()))?;
+    let mut config = Config::load_with_env(matches.get_one::<String>("config").map(|s| s.as_str()))?;
     
-    // 收集命令行参数
+    // 应用命令行参数
+    config.merge_with_args(&config_args(matches)?);
+    
+    Ok(config)
+}
+
+/// 收集覆盖配置的命令行参数，重新加载配置时同样要应用
+fn config_args(matches: &ArgMatches) -> TrackerResult<ConfigArgs> {
     let mut args = ConfigArgs::default();
     
     // 地址参数
@@ -233,34 +504,37 @@ async fn load_config(matches: &ArgMatches) -> TrackerResult<Config> {
         args.rpc_url = Some(rpc_url.to_string());
     }
     
-    if let Some(poll_interval) = matches.get_one::<String>("poll-interval") {
-        args.poll_interval = Some(poll_interval.parse()
-            .map_err(|_| TrackerError::Configuration("Invalid poll interval".to_string()))?);
-    }
+    args.poll_interval = parse_number_flag(matches, "poll-interval", POLL_INTERVAL_RANGE)?;
     
     // 警报参数
     if let Some(threshold) = matches.get_one::<String>("threshold") {
-        args.low_balance_threshold = Some(threshold.parse()
-            .map_err(|_| TrackerError::Configuration("Invalid threshold".to_string()))?);
+        args.low_balance_threshold = Some(parse_sui_amount(threshold)
+            .ok_or_else(|| TrackerError::Configuration(format!("Invalid threshold '{}' (expected a non-negative SUI amount)", threshold)))?);
     }
     
     if let Some(large_threshold) = matches.get_one::<String>("large-transfer-threshold") {
-        args.large_transfer_threshold = Some(large_threshold.parse()
-            .map_err(|_| TrackerError::Configuration("Invalid large transfer threshold".to_string()))?);
+        args.large_transfer_threshold = Some(parse_sui_amount(large_threshold)
+            .ok_or_else(|| TrackerError::Configuration(format!("Invalid large transfer threshold '{}' (expected a non-negative SUI amount)", large_threshold)))?);
     }
     
     // 输出参数
     args.use_colors = Some(!matches.get_flag("no-colors"));
     args.show_timestamps = Some(!matches.get_flag("no-timestamps"));
+    if matches.get_flag("quiet") {
+        args.quiet = Some(true);
+    }
+    if matches.get_flag("alerts-only") {
+        args.alerts_only = Some(true);
+    }
+    if matches.get_flag("no-summary") {
+        args.no_summary = Some(true);
+    }
     
     if let Some(log_level) = matches.get_one::<String>("log-level") {
         args.log_level = Some(log_level.to_string());
     }
     
-    // 应用命令行参数
-    config.merge_with_args(&args);
-    
-    Ok(config)
+    Ok(args)
 }
 
 async fn handle_simple_commands(matches: &ArgMatches) -> TrackerResult<bool> {
@@ -278,11 +552,178 @@ async fn handle_simple_commands(matches: &ArgMatches) -> TrackerResult<bool> {
         println!("Default configuration file generated: config.toml");
         return Ok(true);
     }
+
+    // 启动前自检（不启动监控）
+    if matches.get_flag("health-check") {
+        if !run_health_check(matches).await {
+            std::process::exit(1);
+        }
+        return Ok(true);
+    }
+
+    // 只校验配置，不访问网络
+    if matches.get_flag("config-check") {
+        if !run_config_check(matches).await {
+            std::process::exit(1);
+        }
+        return Ok(true);
+    }
+
+    // 从水龙头领取测试币
+    if let Some(address) = matches.get_one::<String>("faucet") {
+        request_faucet(matches, address).await?;
+        return Ok(true);
+    }
     
     Ok(false)
 }
 
+/// 水龙头到账前等待的时间
+const FAUCET_SETTLE_DELAY: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// 向当前网络的水龙头申请测试币，成功后稍等片刻再查询余额确认到账
+async fn request_faucet(matches: &ArgMatches, address: &str) -> TrackerResult<()> {
+    let address = Config::normalize_address(address)
+        .ok_or_else(|| TrackerError::invalid_address(format!("Invalid SUI address: {}", address)))?;
+    let config = load_config(matches).await?;
+    let client = TokenTransferTracker::create_client(&config.network).await?;
+    let messages = Messages::new(config.output.language, config.output.use_emoji);
+
+    // 主网等没有水龙头的网络由 request_faucet 直接返回说明性错误
+    if let Some(faucet_url) = client.faucet_url() {
+        println!("{}", messages.faucet_requesting(faucet_url, &address));
+    }
+    if let Err(e) = client.request_faucet(&address).await {
+        eprintln!("{}", messages.faucet_failed(&e.to_string()));
+        std::process::exit(1);
+    }
+    println!("{}", messages.faucet_succeeded());
+
+    tokio::time::sleep(FAUCET_SETTLE_DELAY).await;
+    match client.get_balance_uncached(&address, Some("0x2::sui::SUI")).await {
+        Ok(balance) => println!("{}", messages.sui_balance(mist_to_sui(balance, SUI_DECIMALS), balance)),
+        Err(e) => eprintln!("{}", messages.balance_unavailable_with(&e.to_string())),
+    }
+
+    Ok(())
+}
+
+/// 加载配置并逐段校验，不创建客户端也不访问网络，返回是否全部通过
+async fn run_config_check(matches: &ArgMatches) -> bool {
+    let source = matches.get_one::<String>("config").map(|s| s.as_str()).unwrap_or("defaults");
+    // 配置加载失败时没有输出设置可用，按默认设置输出
+    let config = match load_config(matches).await {
+        Ok(config) => config,
+        Err(e) => {
+            let messages = Messages::default();
+            println!("{}", messages.checking_config(source));
+            println!("{}", messages.config_load_failed(&e.to_string()));
+            return false;
+        }
+    };
+    let messages = Messages::new(config.output.language, config.output.use_emoji);
+    println!("{}", messages.checking_config(source));
+
+    let mut passed = true;
+    for (section, result) in config.validation_report() {
+        match result {
+            Ok(()) => println!("{}", messages.config_section_ok(section)),
+            Err(e) => {
+                println!("{}", messages.config_section_failed(section, &e.to_string()));
+                passed = false;
+            }
+        }
+    }
+
+    if passed {
+        println!("{}", messages.config_valid(config.addresses.monitored.len()));
+    }
+    passed
+}
+
+/// 依次检查配置、RPC连通性和水龙头，返回关键检查是否全部通过
+async fn run_health_check(matches: &ArgMatches) -> bool {
+    // 配置（加载失败时按默认输出设置提示）
+    let config = match load_config(matches).await {
+        Ok(config) => config,
+        Err(e) => {
+            let messages = Messages::default();
+            println!("{}", messages.running_health_check());
+            println!("{}", messages.health_config_invalid(&e.to_string()));
+            return false;
+        }
+    };
+    let messages = Messages::new(config.output.language, config.output.use_emoji);
+    println!("{}", messages.running_health_check());
+    if let Err(e) = config.validate() {
+        println!("{}", messages.health_config_invalid(&e.to_string()));
+        return false;
+    }
+    println!("{}", messages.health_config_valid(config.addresses.monitored.len()));
+
+    // 客户端
+    let client = match TokenTransferTracker::create_client(&config.network).await {
+        Ok(client) => client,
+        Err(e) => {
+            println!("{}", messages.rpc_client_failed(&e.to_string()));
+            return false;
+        }
+    };
+
+    // 连通性与延迟
+    let started = std::time::Instant::now();
+    let healthy = match client.get_chain_id().await {
+        Ok(chain_id) => {
+            let latency_ms = started.elapsed().as_millis();
+            println!("{}", messages.rpc_connected(&config.network.rpc_url, &chain_id));
+            let slow = config.network.slow_rpc_threshold_ms > 0 && latency_ms > config.network.slow_rpc_threshold_ms as u128;
+            println!("{}", messages.rpc_latency(latency_ms, slow));
+            true
+        }
+        Err(e) => {
+            println!("{}", messages.rpc_unreachable(&config.network.rpc_url, &e.to_string()));
+            false
+        }
+    };
+
+    // 水龙头（仅测试网/开发网，非关键检查）
+    if let Some(faucet_url) = client.faucet_url() {
+        match client.check_faucet().await {
+            Ok(()) => println!("{}", messages.faucet_reachable(faucet_url)),
+            Err(e) => println!("{}", messages.faucet_unreachable(&e.to_string())),
+        }
+    }
+
+    if config.addresses.monitored.is_empty() {
+        println!("{}", messages.no_addresses_configured());
+    }
+
+    if healthy {
+        println!("{}", messages.health_check_passed());
+    } else {
+        println!("{}", messages.health_check_failed());
+    }
+    healthy
+}
+
 async fn handle_tracker_commands(matches: &ArgMatches, tracker: &mut TokenTransferTracker) -> TrackerResult<()> {
+    // 设置输出格式（需在所有输出之前）
+    if matches.get_flag("json") {
+        tracker.output_formatter.set_format(OutputFormat::Json);
+    } else if let Some(format) = matches.get_one::<String>("output-format") {
+        match format.as_str() {
+            "table" => tracker.output_formatter.set_format(OutputFormat::Table),
+            "json" => tracker.output_formatter.set_format(OutputFormat::Json),
+            "csv" => tracker.output_formatter.set_format(OutputFormat::Csv),
+            _ => return Err(TrackerError::Configuration("Invalid output format".to_string())),
+        }
+    }
+
+    // 金额附带美元估值时，输出前先取一次价格
+    if tracker.output_formatter.shows_usd_value() {
+        tracker.get_sui_price().await;
+    }
+    
     // 查询地址信息
     if let Some(address) = matches.get_one::<String>("query") {
         query_address_info(address, tracker, matches).await?;
@@ -297,10 +738,91 @@ async fn handle_tracker_commands(matches: &ArgMatches, tracker: &mut TokenTransf
     
     // 查询交易
     if let Some(address) = matches.get_one::<String>("transactions") {
-        let limit: usize = matches.get_one::<String>("limit")
-            .and_then(|s| s.parse().ok())
-            .unwrap_or(10);
-        query_transactions(address, tracker, limit).await?;
+        if let Some(since) = matches.get_one::<String>("since") {
+            let since = chrono::DateTime::parse_from_rfc3339(since)
+                .map_err(|e| TrackerError::validation_error(format!("Invalid --since timestamp '{}': {}", since, e)))?
+                .with_timezone(&chrono::Utc);
+            query_transactions_since(address, tracker, since).await?;
+            return Ok(());
+        }
+
+        let limit = parse_limit(matches)?;
+        query_transactions(address, tracker, usize::from(limit)).await?;
+        return Ok(());
+    }
+    
+    // 按digest查询交易
+    if let Some(digest) = matches.get_one::<String>("tx") {
+        let transaction = tracker.get_transaction(digest).await?;
+        println!("{}", tracker.output_formatter.format_sui_transaction(&transaction));
+        return Ok(());
+    }
+    
+    // 回放历史交易
+    if let Some(address) = matches.get_one::<String>("replay") {
+        let limit = parse_limit(matches)?;
+        let quiet = tracker.output_formatter.is_quiet();
+        let messages = tracker.output_formatter.messages();
+        if !quiet {
+            println!("{}", messages.replaying(address));
+        }
+        let replayed = tracker.replay_address_history(address, limit).await?;
+        if quiet {
+            println!("{}", replayed);
+        } else {
+            println!("{}", messages.replayed(replayed));
+        }
+        return Ok(());
+    }
+    
+    // 搜索交易
+    if matches.get_flag("search") {
+        search_transactions(tracker, matches).await?;
+        return Ok(());
+    }
+    
+    // 交易量排行
+    if let Some(n) = matches.get_one::<String>("top-addresses") {
+        let n = parse_number_value("top-addresses", n, TOP_ADDRESSES_RANGE)?;
+        let window_hours = parse_number_flag(matches, "window-hours", WINDOW_HOURS_RANGE)?.unwrap_or(24);
+        let ranking = tracker.transaction_processor.get_top_addresses_by_volume(n, window_hours).await;
+        println!("{}", tracker.output_formatter.format_top_addresses(&ranking, window_hours));
+        return Ok(());
+    }
+    
+    // 交易量时间序列
+    if let Some(token_type) = matches.get_one::<String>("volume-timeseries") {
+        let bucket_seconds = parse_number_flag(matches, "bucket-seconds", BUCKET_SECONDS_RANGE)?.unwrap_or(3600);
+        let window_hours = parse_number_flag(matches, "window-hours", WINDOW_HOURS_RANGE)?.unwrap_or(24);
+        // 独立进程中没有交易记录，先载入监控地址的近期历史
+        let addresses = tracker.get_all_addresses().await;
+        tracker.load_address_history(&addresses, HISTORY_LOAD_LIMIT).await?;
+        let series = tracker.transaction_processor.get_volume_timeseries(token_type, bucket_seconds, window_hours).await;
+        let csv = OutputFormatter::format_volume_timeseries_csv(&series);
+        match matches.get_one::<String>("output") {
+            Some(path) => {
+                std::fs::write(path, csv)?;
+                if !tracker.output_formatter.is_quiet() {
+                    println!("{}", tracker.output_formatter.format_success(
+                        &format!("Wrote {} volume buckets to {}", series.len(), path)));
+                }
+            }
+            None => print!("{}", csv),
+        }
+        return Ok(());
+    }
+    
+    // Coin 对象分布
+    if let Some(address) = matches.get_one::<String>("coins") {
+        let report = tracker.query_coin_report(address, None).await?;
+        println!("{}", tracker.output_formatter.format_coin_report(&report));
+        return Ok(());
+    }
+    
+    // 地址分组汇总
+    if let Some(group) = matches.get_one::<String>("group-summary") {
+        let stats = tracker.get_group_stats(group).await?;
+        println!("{}", tracker.output_formatter.format_group_summary(&stats));
         return Ok(());
     }
     
@@ -319,6 +841,12 @@ async fn handle_tracker_commands(matches: &ArgMatches, tracker: &mut TokenTransf
         return Ok(());
     }
     
+    // 从文件批量添加地址
+    if let Some(path) = matches.get_one::<String>("add-addresses-file") {
+        add_addresses_from_file(path, tracker).await?;
+        return Ok(());
+    }
+    
     // 移除地址
     if let Some(address) = matches.get_one::<String>("remove-address") {
         tracker.remove_address(address).await?;
@@ -327,16 +855,16 @@ async fn handle_tracker_commands(matches: &ArgMatches, tracker: &mut TokenTransf
     
     // 列出地址
     if matches.get_flag("list-addresses") {
-        let addresses = tracker.get_all_addresses().await;
-        println!("Monitored addresses:");
-        for address in addresses {
-            if let Some(info) = tracker.get_address_info(&address).await {
-                println!("  {}: {} ({} transactions)", 
-                    address, 
-                    tracker.output_formatter.format_amount(info.balance),
-                    info.total_transactions);
-            }
-        }
+        let entries: Vec<AddressListEntry> = tracker.get_all_addresses_with_info().await
+            .into_iter()
+            .map(|(address, info)| AddressListEntry {
+                address,
+                balance: info.balance,
+                total_transactions: info.total_transactions,
+                label: None,
+            })
+            .collect();
+        println!("{}", tracker.output_formatter.format_address_list(&entries));
         return Ok(());
     }
     
@@ -349,18 +877,17 @@ async fn handle_tracker_commands(matches: &ArgMatches, tracker: &mut TokenTransf
     // 导出数据
     if let Some(format) = matches.get_one::<String>("export") {
         let output_path = matches.get_one::<String>("output").map(|s| s.as_str()).unwrap_or("export");
-        tracker.export_data(format, output_path).await?;
-        return Ok(());
-    }
-    
-    // 设置输出格式
-    if let Some(format) = matches.get_one::<String>("output-format") {
-        match format.as_str() {
-            "table" => tracker.output_formatter.set_format(OutputFormat::Table),
-            "json" => tracker.output_formatter.set_format(OutputFormat::Json),
-            "csv" => tracker.output_formatter.set_format(OutputFormat::Csv),
-            _ => return Err(TrackerError::Configuration("Invalid output format".to_string())),
+        if matches.get_flag("export-append") {
+            if format != "csv" {
+                return Err(TrackerError::validation_error("--export-append only supports csv export"));
+            }
+            tracker.export_transactions_append(output_path).await?;
+        } else if matches.get_flag("export-history") {
+            tracker.export_history(format, output_path).await?;
+        } else {
+            tracker.export_data(format, output_path).await?;
         }
+        return Ok(());
     }
     
     Ok(())
@@ -370,7 +897,9 @@ fn should_start_monitoring(matches: &ArgMatches) -> bool {
     // 如果指定了特定的操作命令，不启动监控
     !matches.get_flag("version") &&
     !matches.get_flag("generate-config") &&
+    !matches.get_flag("health-check") &&
     !matches.contains_id("add-address") &&
+    !matches.contains_id("add-addresses-file") &&
     !matches.contains_id("remove-address") &&
     !matches.get_flag("list-addresses") &&
     !matches.get_flag("force-check") &&
@@ -379,31 +908,55 @@ fn should_start_monitoring(matches: &ArgMatches) -> bool {
     !matches.contains_id("query") &&
     !matches.contains_id("balance") &&
     !matches.contains_id("transactions") &&
+    !matches.contains_id("replay") &&
+    !matches.contains_id("tx") &&
+    !matches.get_flag("search") &&
+    !matches.contains_id("top-addresses") &&
+    !matches.contains_id("volume-timeseries") &&
+    !matches.contains_id("group-summary") &&
+    !matches.contains_id("coins") &&
     // 如果只有一个地址参数，也不启动监控（默认查询模式）
     !(matches.get_many::<String>("addresses").map_or(false, |addrs| addrs.len() == 1))
 }
 
 async fn query_address_info(address: &str, tracker: &TokenTransferTracker, matches: &ArgMatches) -> TrackerResult<()> {
-    println!("🔍 正在查询 SUI 地址: {}", address);
-    println!("================================================");
+    let quiet = tracker.output_formatter.is_quiet();
+    let messages = tracker.output_formatter.messages();
+    if !quiet {
+        println!("{}", messages.querying_address(address));
+        println!("{}", messages.separator());
+        println!("{}", messages.fetching_balance());
+    }
     
     // 查询余额
-    println!("💰 查询地址余额...");
-    if let Ok(balance) = tracker.query_balance(address, Some("0x2::sui::SUI")).await {
-        let sui_balance = balance as f64 / 1_000_000_000.0;
-        println!("💳 SUI 余额: {:.9} SUI ({} MIST)", sui_balance, balance);
-        println!("🪙 代币类型: \"0x2::sui::SUI\"");
+    if let Ok(detail) = tracker.query_balance_detailed(address, Some("0x2::sui::SUI")).await {
+        let sui_balance = mist_to_sui(detail.total_balance, SUI_DECIMALS);
+        println!("{}", messages.sui_balance(sui_balance, detail.total_balance));
+        println!("{}", messages.coin_type(&detail.coin_type));
+        println!("{}", messages.coin_object_count(detail.coin_object_count));
+        if detail.locked_balance > 0 {
+            println!("{}", messages.locked_balance(mist_to_sui(detail.locked_balance, SUI_DECIMALS)));
+        }
     } else {
-        println!("❌ 无法获取余额信息");
+        eprintln!("{}", messages.balance_unavailable());
     }
     
     // 查询所有代币余额
-    println!("\n💎 查询所有代币余额...");
-    if let Ok(balances) = tracker.query_all_balances(address).await {
-        println!("📊 总共找到 {} 种代币:", balances.len());
+    if !quiet {
+        println!("\n{}", messages.fetching_all_balances());
+    }
+    if let Ok(report) = tracker.query_all_balances_report(address).await {
+        let balances = &report.balances;
+        println!("{}", messages.coin_types_found(balances.len()));
+        if !report.unparsed.is_empty() {
+            eprintln!("{}", messages.unparsed_balances(report.unparsed.len()));
+            for (coin_type, raw) in &report.unparsed {
+                eprintln!("   \"{}\": {}", coin_type, raw);
+            }
+        }
         for (i, (coin_type, balance)) in balances.iter().enumerate() {
             if coin_type == "0x2::sui::SUI" {
-                let sui_balance = *balance as f64 / 1_000_000_000.0;
+                let sui_balance = mist_to_sui(*balance, SUI_DECIMALS);
                 println!("   {}. \"{}\": {:.9} SUI", i + 1, coin_type, sui_balance);
             } else {
                 println!("   {}. \"{}\": {} units", i + 1, coin_type, balance);
@@ -412,121 +965,309 @@ async fn query_address_info(address: &str, tracker: &TokenTransferTracker, match
     }
     
     // 查询交易历史
-    let limit: usize = matches.get_one::<String>("limit")
-        .and_then(|s| s.parse().ok())
-        .unwrap_or(5);
+    let limit = parse_limit(matches)?;
     
-    println!("\n📝 查询最近交易历史...");
-    if let Ok(sent_transactions) = tracker.query_transactions_sent(address, Some(limit as u16)).await {
-        println!("🎯 找到 {} 笔发送的交易:", sent_transactions.len());
+    if !quiet {
+        println!("\n{}", messages.fetching_recent_transactions());
+    }
+    if let Ok(sent_transactions) = tracker.query_transactions_sent(address, Some(limit)).await {
+        println!("{}", messages.sent_transactions_found(sent_transactions.len()));
         
         for (i, tx) in sent_transactions.iter().enumerate() {
-            println!("\n📋 交易 #{}", i + 1);
-            println!("   📄 交易摘要: \"{}\"", tx.digest);
+            println!("\n{}", messages.transaction_heading(i + 1));
+            println!("   {}", messages.digest(&format!("\"{}\"", tx.digest)));
             if let Some(timestamp) = &tx.timestamp {
-                println!("   🕰️  时间: {}", timestamp.format("%Y-%m-%d %H:%M:%S UTC"));
+                println!("   {}", messages.time(&timestamp.format("%Y-%m-%d %H:%M:%S UTC").to_string()));
             }
             if let Some(gas_used) = &tx.gas_used {
-                println!("   ⛽ Gas 消耗: \"{}\"", gas_used);
+                println!("   {}", messages.gas_used(&format!("\"{}\"", gas_used)));
             }
             
             for balance_change in &tx.balance_changes {
-                let amount_f64 = balance_change.amount as f64 / 1_000_000_000.0;
-                if balance_change.amount >= 0 {
-                    println!("   💰 余额变化: +{:.9} SUI (\"{}\")", amount_f64, balance_change.owner);
+                let amount_f64 = mist_to_sui(balance_change.amount, SUI_DECIMALS);
+                let amount = if balance_change.amount >= 0 {
+                    format!("+{:.9}", amount_f64)
                 } else {
-                    println!("   💰 余额变化: {:.9} SUI (\"{}\")", amount_f64, balance_change.owner);
-                }
-                println!("      🪙 代币: \"{}\"", balance_change.coin_type);
+                    format!("{:.9}", amount_f64)
+                };
+                println!("   {}", messages.balance_change(&amount, &balance_change.owner));
+                println!("      {}", messages.coin(&balance_change.coin_type));
             }
         }
     }
     
     // 查询接收的交易
-    println!("\n📥 查询接收的交易...");
+    if !quiet {
+        println!("\n{}", messages.fetching_received_transactions());
+    }
     if let Ok(received_transactions) = tracker.query_transactions_received(address, Some(3)).await {
-        println!("📨 找到 {} 笔接收的交易:", received_transactions.len());
+        println!("{}", messages.received_transactions_found(received_transactions.len()));
         
         for (i, tx) in received_transactions.iter().enumerate() {
-            println!("\n📋 接收交易 #{}", i + 1);
-            println!("   📄 交易摘要: \"{}\"", tx.digest);
+            println!("\n{}", messages.received_transaction_heading(i + 1));
+            println!("   {}", messages.digest(&format!("\"{}\"", tx.digest)));
             
             // 显示接收到的代币
             for balance_change in &tx.balance_changes {
                 if balance_change.amount > 0 && balance_change.owner == address {
-                    let amount_f64 = balance_change.amount as f64 / 1_000_000_000.0;
-                    println!("   💰 接收: +{:.9} SUI", amount_f64);
+                    let amount_f64 = mist_to_sui(balance_change.amount, SUI_DECIMALS);
+                    println!("   {}", messages.received_amount(amount_f64));
                 }
             }
         }
     }
     
-    println!("\n🎉 地址查询完成!");
-    println!("💡 提示: 如果没有看到交易，可能是因为:");
-    println!("   1. 地址确实没有交易历史");
-    println!("   2. 交易比较老，需要查询更多历史");
-    println!("   3. 需要查询其他类型的交易过滤器");
+    if !quiet {
+        println!("\n{}", messages.query_complete());
+        for line in messages.no_transactions_hint() {
+            println!("{}", line);
+        }
+    }
     
     Ok(())
 }
 
 async fn query_balance(address: &str, tracker: &TokenTransferTracker) -> TrackerResult<()> {
-    println!("💰 查询地址余额: {}", address);
+    let messages = tracker.output_formatter.messages();
+    if !tracker.output_formatter.is_quiet() {
+        println!("{}", messages.fetching_balance_for(address));
+    }
     
     if let Ok(balance) = tracker.query_balance(address, Some("0x2::sui::SUI")).await {
-        let sui_balance = balance as f64 / 1_000_000_000.0;
-        println!("💳 SUI 余额: {:.9} SUI ({} MIST)", sui_balance, balance);
+        let sui_balance = mist_to_sui(balance, SUI_DECIMALS);
+        println!("{}", messages.sui_balance(sui_balance, balance));
     } else {
-        return Err(TrackerError::network_error("无法获取余额信息"));
+        return Err(TrackerError::network_error("Failed to fetch balance"));
     }
     
     Ok(())
 }
 
 async fn query_transactions(address: &str, tracker: &TokenTransferTracker, limit: usize) -> TrackerResult<()> {
-    println!("📝 查询地址交易: {} (限制: {}笔)", address, limit);
+    let messages = tracker.output_formatter.messages();
+    if !tracker.output_formatter.is_quiet() {
+        println!("{}", messages.querying_transactions(address, limit));
+    }
     
     if let Ok(transactions) = tracker.query_transactions_sent(address, Some(limit as u16)).await {
-        println!("🎯 找到 {} 笔交易:", transactions.len());
+        println!("{}", messages.transactions_found(transactions.len()));
         
-        for (i, tx) in transactions.iter().enumerate() {
-            println!("\n📋 交易 #{}", i + 1);
-            println!("   📄 交易摘要: {}", tx.digest);
-            if let Some(timestamp) = &tx.timestamp {
-                println!("   🕰️  时间: {}", timestamp.format("%Y-%m-%d %H:%M:%S UTC"));
+        print_transaction_list(&messages, &transactions);
+    } else {
+        return Err(TrackerError::network_error("Failed to fetch transactions"));
+    }
+    
+    Ok(())
+}
+
+async fn query_transactions_since(address: &str, tracker: &TokenTransferTracker, since: chrono::DateTime<chrono::Utc>) -> TrackerResult<()> {
+    let messages = tracker.output_formatter.messages();
+    if !tracker.output_formatter.is_quiet() {
+        println!("{}", messages.querying_transactions_since(address, &since.format("%Y-%m-%d %H:%M:%S UTC").to_string()));
+    }
+
+    let transactions = tracker.query_transactions_since(address, TransactionDirection::Sent, since).await?;
+    println!("{}", messages.transactions_found(transactions.len()));
+    print_transaction_list(&messages, &transactions);
+
+    Ok(())
+}
+
+fn print_transaction_list(messages: &Messages, transactions: &[SuiTransaction]) {
+    for (i, tx) in transactions.iter().enumerate() {
+        println!("\n{}", messages.transaction_heading(i + 1));
+        println!("   {}", messages.digest(&tx.digest));
+        if let Some(timestamp) = &tx.timestamp {
+            println!("   {}", messages.time(&timestamp.format("%Y-%m-%d %H:%M:%S UTC").to_string()));
+        }
+        if let Some(gas_used) = &tx.gas_used {
+            println!("   {}", messages.gas_used(&gas_used.to_string()));
+        }
+    }
+}
+
+/// 逐行读取地址并添加到监控列表，单个地址失败不会中断整个批次
+async fn add_addresses_from_file(path: &str, tracker: &TokenTransferTracker) -> TrackerResult<()> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| TrackerError::config_error(format!("Failed to read address file {}: {}", path, e)))?;
+
+    let mut known: std::collections::HashSet<String> = tracker.get_all_addresses().await.into_iter().collect();
+    let (mut added, mut skipped, mut invalid) = (0usize, 0usize, 0usize);
+
+    for (line_number, line) in content.lines().enumerate() {
+        let address = line.trim();
+        if address.is_empty() || address.starts_with('#') {
+            continue;
+        }
+
+        let address = match Config::normalize_address(address) {
+            Some(address) => address,
+            None => {
+                eprintln!("{}", tracker.output_formatter.format_warning(
+                    &format!("Line {}: invalid SUI address: {}", line_number + 1, address)));
+                invalid += 1;
+                continue;
             }
-            if let Some(gas_used) = &tx.gas_used {
-                println!("   ⛽ Gas 消耗: {}", gas_used);
+        };
+
+        if known.contains(&address) {
+            skipped += 1;
+            continue;
+        }
+
+        // 达到地址上限后不再逐个尝试，剩余行都计为跳过
+        if known.len() >= tracker.max_monitored_addresses() {
+            eprintln!("{}", tracker.output_formatter.format_warning(&format!(
+                "Line {}: address limit reached ({} monitored, max_monitored_addresses is {}), skipping the rest of {}",
+                line_number + 1, known.len(), tracker.max_monitored_addresses(), path)));
+            skipped += content.lines().skip(line_number)
+                .map(str::trim)
+                .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                .count();
+            break;
+        }
+
+        match tracker.add_address(address.clone()).await {
+            Ok(_) => {
+                known.insert(address.clone());
+                added += 1;
+            }
+            Err(e) => {
+                eprintln!("{}", tracker.output_formatter.format_warning(
+                    &format!("Line {}: failed to add {}: {}", line_number + 1, address, e)));
+                skipped += 1;
             }
         }
-    } else {
-        return Err(TrackerError::network_error("无法获取交易信息"));
     }
-    
+
+    if !tracker.output_formatter.is_quiet() {
+        println!("{}", tracker.output_formatter.format_info(
+            &format!("Addresses from {}: {} added, {} skipped, {} invalid", path, added, skipped, invalid)));
+    }
+
     Ok(())
 }
 
-async fn output_final_stats(tracker: &TokenTransferTracker) -> TrackerResult<()> {
-    let stats = tracker.get_tracker_stats().await;
-    let processor_stats = tracker.transaction_processor.get_processor_stats().await;
-    
-    println!("\n=== Final Statistics ===");
-    println!("Uptime: {} seconds", stats.uptime_seconds);
-    println!("Events processed: {}", stats.total_events_processed);
-    println!("Transactions processed: {}", stats.total_transactions_processed);
-    println!("Alerts sent: {}", stats.total_alerts_sent);
-    println!("Errors encountered: {}", stats.total_errors);
-    println!("Addresses monitored: {}", stats.addresses_monitored);
-    println!("Total addresses in processor: {}", processor_stats.total_addresses);
-    println!("Total volume processed: {}", tracker.output_formatter.format_amount(processor_stats.total_volume));
-    
+async fn search_transactions(tracker: &TokenTransferTracker, matches: &ArgMatches) -> TrackerResult<()> {
+    fn parse_u64(matches: &ArgMatches, name: &str) -> TrackerResult<Option<u64>> {
+        matches.get_one::<String>(name)
+            .map(|value| value.parse::<u64>()
+                .map_err(|_| TrackerError::validation_error(format!("Invalid value for --{}: '{}' (expected a non-negative integer)", name, value))))
+            .transpose()
+    }
+
+    let criteria = SearchCriteria {
+        address: matches.get_one::<String>("search-address").cloned(),
+        min_amount: parse_u64(matches, "min-amount")?,
+        max_amount: parse_u64(matches, "max-amount")?,
+        token_type: matches.get_one::<String>("token-type").cloned(),
+        start_ts: parse_u64(matches, "start-ts")?,
+        end_ts: parse_u64(matches, "end-ts")?,
+        status: matches.get_one::<String>("status").map(|status| match status.as_str() {
+            "failed" => TransactionStatus::Failed,
+            "pending" => TransactionStatus::Pending,
+            _ => TransactionStatus::Success,
+        }),
+        // 未指定 --limit 时返回所有匹配的交易
+        limit: parse_number_flag(matches, "limit", LIMIT_RANGE)?.map(usize::from),
+    };
+
+    // 独立进程中没有交易记录，先载入要搜索的地址的近期历史
+    let addresses = match &criteria.address {
+        Some(address) => vec![address.clone()],
+        None => tracker.get_all_addresses().await,
+    };
+    tracker.load_address_history(&addresses, HISTORY_LOAD_LIMIT).await?;
+
+    let results = tracker.transaction_processor.search_transactions(criteria).await;
+    if results.is_empty() {
+        println!("{}", tracker.output_formatter.format_info("No matching transactions found"));
+    } else {
+        println!("{}", tracker.output_formatter.format_transaction_history(&results));
+    }
+
     Ok(())
 }
 
+/// 等待 SIGTERM 或 Ctrl+C
+async fn wait_for_shutdown_signal() {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{signal, SignalKind};
+        match signal(SignalKind::terminate()) {
+            Ok(mut sigterm) => {
+                tokio::select! {
+                    _ = sigterm.recv() => {}
+                    _ = tokio::signal::ctrl_c() => {}
+                }
+                return;
+            }
+            Err(e) => log::warn!("Failed to install SIGTERM handler: {}", e),
+        }
+    }
+
+    let _ = tokio::signal::ctrl_c().await;
+}
+
+/// 守护进程PID文件，正常退出时自动删除
+struct PidFile {
+    path: std::path::PathBuf,
+}
+
+impl PidFile {
+    fn create(path: &str) -> TrackerResult<Self> {
+        if let Ok(contents) = std::fs::read_to_string(path) {
+            if let Ok(pid) = contents.trim().parse::<u32>() {
+                if is_process_alive(pid) {
+                    return Err(TrackerError::config_error(format!(
+                        "Tracker is already running with PID {} (PID file: {})",
+                        pid, path
+                    )));
+                }
+            }
+            log::warn!("Removing stale PID file: {}", path);
+        }
+
+        std::fs::write(path, format!("{}\n", std::process::id()))?;
+        log::info!("Wrote PID file: {}", path);
+
+        Ok(Self { path: path.into() })
+    }
+}
+
+impl Drop for PidFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+#[cfg(unix)]
+fn is_process_alive(pid: u32) -> bool {
+    // kill -0 只检查进程是否存在，不发送信号
+    std::process::Command::new("kill")
+        .arg("-0")
+        .arg(pid.to_string())
+        .stderr(std::process::Stdio::null())
+        .status()
+        .map(|status| status.success())
+        .unwrap_or(false)
+}
+
+#[cfg(not(unix))]
+fn is_process_alive(_pid: u32) -> bool {
+    false
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_cli_definition() {
+        // 短参数或长参数重名等定义错误在这里失败，而不是在运行时 panic
+        build_cli().debug_assert();
+    }
+
     #[test]
     fn test_should_start_monitoring() {
         let app = Command::new("test");
@@ -546,6 +1287,31 @@ mod tests {
         assert!(!should_start_monitoring(&matches));
     }
 
+    #[test]
+    fn test_parse_number_flags() {
+        let app = || Command::new("test")
+            .arg(Arg::new("limit").long("limit").num_args(1))
+            .arg(Arg::new("poll-interval").long("poll-interval").num_args(1));
+
+        let matches = app().try_get_matches_from(["test"]).unwrap();
+        assert_eq!(parse_limit(&matches).unwrap(), DEFAULT_LIMIT);
+        assert_eq!(parse_number_flag(&matches, "poll-interval", POLL_INTERVAL_RANGE).unwrap(), None);
+
+        let matches = app().try_get_matches_from(["test", "--limit", "1000", "--poll-interval", "86400"]).unwrap();
+        assert_eq!(parse_limit(&matches).unwrap(), 1000);
+        assert_eq!(parse_number_flag(&matches, "poll-interval", POLL_INTERVAL_RANGE).unwrap(), Some(86_400));
+
+        // 超出范围和非数字输入都给出参数名、输入值和允许范围
+        let matches = app().try_get_matches_from(["test", "--limit", "1001", "--poll-interval", "abc"]).unwrap();
+        let error = parse_limit(&matches).unwrap_err().to_string();
+        assert!(error.contains("--limit: '1001'") && error.contains("between 1 and 1000"), "{}", error);
+        let error = parse_number_flag(&matches, "poll-interval", POLL_INTERVAL_RANGE).unwrap_err().to_string();
+        assert!(error.contains("--poll-interval: 'abc'") && error.contains("between 1 and 86400"), "{}", error);
+
+        let matches = app().try_get_matches_from(["test", "--limit", "0"]).unwrap();
+        assert!(parse_limit(&matches).is_err());
+    }
+
     #[tokio::test]
     async fn test_config_loading() {
         // 测试加载默认配置
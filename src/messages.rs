@@ -0,0 +1,392 @@
+//! 命令行提示文本目录
+//!
+//! 查询命令的标题、进度提示和欢迎信息按 `output.language` 选择语言（默认英文），
+//! `output.use_emoji` 关闭时去掉行首的 emoji，便于在 CI 日志中阅读。
+
+use serde::{Deserialize, Serialize};
+
+/// 提示文本的语言
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Language {
+    #[default]
+    En,
+    Zh,
+}
+
+/// 按语言和 emoji 设置生成提示文本
+#[derive(Debug, Clone, Copy)]
+pub struct Messages {
+    language: Language,
+    use_emoji: bool,
+}
+
+impl Default for Messages {
+    fn default() -> Self {
+        Self::new(Language::default(), true)
+    }
+}
+
+impl Messages {
+    pub fn new(language: Language, use_emoji: bool) -> Self {
+        Self { language, use_emoji }
+    }
+
+    pub fn language(&self) -> Language {
+        self.language
+    }
+
+    /// 在文本前加上 emoji（关闭 emoji 时原样返回）
+    fn line(&self, emoji: &str, text: String) -> String {
+        if self.use_emoji {
+            format!("{} {}", emoji, text)
+        } else {
+            text
+        }
+    }
+
+    fn pick(&self, en: String, zh: String) -> String {
+        match self.language {
+            Language::En => en,
+            Language::Zh => zh,
+        }
+    }
+
+    pub fn welcome_title(&self) -> String {
+        self.pick("SUI Token Transfer Tracker".to_string(), "SUI 代币转账追踪器".to_string())
+    }
+
+    pub fn welcome_subtitle(&self) -> String {
+        self.pick(
+            "Real-time monitoring of SUI blockchain transfers".to_string(),
+            "实时监控 SUI 链上转账".to_string(),
+        )
+    }
+
+    pub fn separator(&self) -> String {
+        "=".repeat(48)
+    }
+
+    pub fn querying_address(&self, address: &str) -> String {
+        self.line("🔍", self.pick(
+            format!("Querying SUI address: {}", address),
+            format!("正在查询 SUI 地址: {}", address),
+        ))
+    }
+
+    pub fn fetching_balance(&self) -> String {
+        self.line("💰", self.pick("Fetching balance...".to_string(), "查询地址余额...".to_string()))
+    }
+
+    pub fn fetching_balance_for(&self, address: &str) -> String {
+        self.line("💰", self.pick(
+            format!("Fetching balance: {}", address),
+            format!("查询地址余额: {}", address),
+        ))
+    }
+
+    pub fn sui_balance(&self, sui: f64, mist: u64) -> String {
+        self.line("💳", self.pick(
+            format!("SUI balance: {:.9} SUI ({} MIST)", sui, mist),
+            format!("SUI 余额: {:.9} SUI ({} MIST)", sui, mist),
+        ))
+    }
+
+    pub fn coin_type(&self, coin_type: &str) -> String {
+        self.line("🪙", self.pick(
+            format!("Coin type: \"{}\"", coin_type),
+            format!("代币类型: \"{}\"", coin_type),
+        ))
+    }
+
+    pub fn coin_object_count(&self, count: u64) -> String {
+        self.line("🧩", self.pick(
+            format!("Coin objects: {}", count),
+            format!("Coin 对象数量: {}", count),
+        ))
+    }
+
+    pub fn locked_balance(&self, sui: f64) -> String {
+        self.line("🔒", self.pick(
+            format!("Locked balance: {:.9} SUI", sui),
+            format!("锁定余额: {:.9} SUI", sui),
+        ))
+    }
+
+    pub fn balance_unavailable(&self) -> String {
+        self.line("❌", self.pick("Failed to fetch balance".to_string(), "无法获取余额信息".to_string()))
+    }
+
+    pub fn balance_unavailable_with(&self, error: &str) -> String {
+        self.line("⚠️ ", self.pick(
+            format!("Failed to fetch balance: {}", error),
+            format!("无法查询余额: {}", error),
+        ))
+    }
+
+    pub fn fetching_all_balances(&self) -> String {
+        self.line("💎", self.pick("Fetching all coin balances...".to_string(), "查询所有代币余额...".to_string()))
+    }
+
+    pub fn coin_types_found(&self, count: usize) -> String {
+        self.line("📊", self.pick(
+            format!("Found {} coin types:", count),
+            format!("总共找到 {} 种代币:", count),
+        ))
+    }
+
+    pub fn unparsed_balances(&self, count: usize) -> String {
+        self.line("⚠️ ", self.pick(
+            format!("{} coin balances could not be parsed and are not included above:", count),
+            format!("{} 种代币的余额无法解析，未计入以上结果:", count),
+        ))
+    }
+
+    pub fn fetching_recent_transactions(&self) -> String {
+        self.line("📝", self.pick("Fetching recent transactions...".to_string(), "查询最近交易历史...".to_string()))
+    }
+
+    pub fn sent_transactions_found(&self, count: usize) -> String {
+        self.line("🎯", self.pick(
+            format!("Found {} sent transactions:", count),
+            format!("找到 {} 笔发送的交易:", count),
+        ))
+    }
+
+    pub fn fetching_received_transactions(&self) -> String {
+        self.line("📥", self.pick("Fetching received transactions...".to_string(), "查询接收的交易...".to_string()))
+    }
+
+    pub fn received_transactions_found(&self, count: usize) -> String {
+        self.line("📨", self.pick(
+            format!("Found {} received transactions:", count),
+            format!("找到 {} 笔接收的交易:", count),
+        ))
+    }
+
+    pub fn transactions_found(&self, count: usize) -> String {
+        self.line("🎯", self.pick(
+            format!("Found {} transactions:", count),
+            format!("找到 {} 笔交易:", count),
+        ))
+    }
+
+    pub fn querying_transactions(&self, address: &str, limit: usize) -> String {
+        self.line("📝", self.pick(
+            format!("Querying transactions: {} (limit: {})", address, limit),
+            format!("查询地址交易: {} (限制: {}笔)", address, limit),
+        ))
+    }
+
+    pub fn querying_transactions_since(&self, address: &str, since: &str) -> String {
+        self.line("📝", self.pick(
+            format!("Querying transactions: {} (since {})", address, since),
+            format!("查询地址交易: {} (自 {} 起)", address, since),
+        ))
+    }
+
+    pub fn transaction_heading(&self, index: usize) -> String {
+        self.line("📋", self.pick(format!("Transaction #{}", index), format!("交易 #{}", index)))
+    }
+
+    pub fn received_transaction_heading(&self, index: usize) -> String {
+        self.line("📋", self.pick(format!("Received transaction #{}", index), format!("接收交易 #{}", index)))
+    }
+
+    pub fn digest(&self, digest: &str) -> String {
+        self.line("📄", self.pick(format!("Digest: {}", digest), format!("交易摘要: {}", digest)))
+    }
+
+    pub fn time(&self, time: &str) -> String {
+        self.line("🕰️ ", self.pick(format!("Time: {}", time), format!("时间: {}", time)))
+    }
+
+    pub fn gas_used(&self, gas_used: &str) -> String {
+        self.line("⛽", self.pick(format!("Gas used: {}", gas_used), format!("Gas 消耗: {}", gas_used)))
+    }
+
+    /// `amount` 为已带符号的 SUI 金额文本，如 "+1.500000000"
+    pub fn balance_change(&self, amount: &str, owner: &str) -> String {
+        self.line("💰", self.pick(
+            format!("Balance change: {} SUI (\"{}\")", amount, owner),
+            format!("余额变化: {} SUI (\"{}\")", amount, owner),
+        ))
+    }
+
+    pub fn coin(&self, coin_type: &str) -> String {
+        self.line("🪙", self.pick(format!("Coin: \"{}\"", coin_type), format!("代币: \"{}\"", coin_type)))
+    }
+
+    pub fn received_amount(&self, sui: f64) -> String {
+        self.line("💰", self.pick(format!("Received: +{:.9} SUI", sui), format!("接收: +{:.9} SUI", sui)))
+    }
+
+    pub fn query_complete(&self) -> String {
+        self.line("🎉", self.pick("Address query complete!".to_string(), "地址查询完成!".to_string()))
+    }
+
+    /// 没有查到交易时的可能原因，每个元素一行
+    pub fn no_transactions_hint(&self) -> Vec<String> {
+        let lines = match self.language {
+            Language::En => [
+                "Tip: if no transactions are shown, it may be because:",
+                "   1. The address has no transaction history",
+                "   2. The transactions are older than the queried range",
+                "   3. A different transaction filter is needed",
+            ],
+            Language::Zh => [
+                "提示: 如果没有看到交易，可能是因为:",
+                "   1. 地址确实没有交易历史",
+                "   2. 交易比较老，需要查询更多历史",
+                "   3. 需要查询其他类型的交易过滤器",
+            ],
+        };
+        let mut lines: Vec<String> = lines.iter().map(|line| line.to_string()).collect();
+        lines[0] = self.line("💡", lines[0].clone());
+        lines
+    }
+
+    pub fn replaying(&self, address: &str) -> String {
+        self.line("⏪", self.pick(
+            format!("Replaying transaction history for {}...", address),
+            format!("正在回放地址 {} 的历史交易...", address),
+        ))
+    }
+
+    pub fn replayed(&self, count: usize) -> String {
+        self.line("✅", self.pick(format!("Replayed {} transactions", count), format!("已回放 {} 笔交易", count)))
+    }
+
+    pub fn faucet_requesting(&self, faucet_url: &str, address: &str) -> String {
+        self.line("🚰", self.pick(
+            format!("Requesting test SUI from {} for {}", faucet_url, address),
+            format!("正在向 {} 申请测试币: {}", faucet_url, address),
+        ))
+    }
+
+    pub fn faucet_failed(&self, error: &str) -> String {
+        self.line("❌", self.pick(format!("Faucet request failed: {}", error), format!("水龙头请求失败: {}", error)))
+    }
+
+    pub fn faucet_succeeded(&self) -> String {
+        self.line("✅", self.pick("Faucet request succeeded".to_string(), "水龙头请求成功".to_string()))
+    }
+
+    pub fn checking_config(&self, source: &str) -> String {
+        self.line("🔍", self.pick(
+            format!("Checking configuration ({})...", source),
+            format!("正在检查配置 ({})...", source),
+        ))
+    }
+
+    pub fn config_load_failed(&self, error: &str) -> String {
+        self.line("❌", self.pick(format!("load: {}", error), format!("加载失败: {}", error)))
+    }
+
+    /// 配置校验中通过的一段
+    pub fn config_section_ok(&self, section: &str) -> String {
+        self.line("✅", section.to_string())
+    }
+
+    pub fn config_section_failed(&self, section: &str, error: &str) -> String {
+        self.line("❌", format!("{}: {}", section, error))
+    }
+
+    pub fn config_valid(&self, addresses: usize) -> String {
+        self.line("✅", self.pick(
+            format!("Configuration is valid ({} monitored addresses)", addresses),
+            format!("配置有效（监控 {} 个地址）", addresses),
+        ))
+    }
+
+    pub fn running_health_check(&self) -> String {
+        self.line("🩺", self.pick("Running health check...".to_string(), "正在进行健康检查...".to_string()))
+    }
+
+    pub fn health_config_valid(&self, addresses: usize) -> String {
+        self.line("✅", self.pick(
+            format!("Configuration: valid ({} monitored addresses)", addresses),
+            format!("配置: 有效（监控 {} 个地址）", addresses),
+        ))
+    }
+
+    pub fn health_config_invalid(&self, error: &str) -> String {
+        self.line("❌", self.pick(format!("Configuration: {}", error), format!("配置: {}", error)))
+    }
+
+    pub fn rpc_client_failed(&self, error: &str) -> String {
+        self.line("❌", self.pick(format!("RPC client: {}", error), format!("RPC 客户端: {}", error)))
+    }
+
+    pub fn rpc_connected(&self, rpc_url: &str, chain_id: &str) -> String {
+        self.line("✅", self.pick(
+            format!("RPC connectivity: {} (chain id {})", rpc_url, chain_id),
+            format!("RPC 连通性: {} (链 ID {})", rpc_url, chain_id),
+        ))
+    }
+
+    pub fn rpc_unreachable(&self, rpc_url: &str, error: &str) -> String {
+        self.line("❌", self.pick(
+            format!("RPC connectivity: {} ({})", rpc_url, error),
+            format!("RPC 连通性: {} ({})", rpc_url, error),
+        ))
+    }
+
+    pub fn rpc_latency(&self, latency_ms: u128, slow: bool) -> String {
+        if slow {
+            self.line("⚠️ ", self.pick(
+                format!("RPC latency: {} ms (slow)", latency_ms),
+                format!("RPC 延迟: {} ms（较慢）", latency_ms),
+            ))
+        } else {
+            self.line("✅", self.pick(format!("RPC latency: {} ms", latency_ms), format!("RPC 延迟: {} ms", latency_ms)))
+        }
+    }
+
+    pub fn faucet_reachable(&self, faucet_url: &str) -> String {
+        self.line("✅", self.pick(format!("Faucet: {} reachable", faucet_url), format!("水龙头: {} 可访问", faucet_url)))
+    }
+
+    pub fn faucet_unreachable(&self, error: &str) -> String {
+        self.line("⚠️ ", self.pick(format!("Faucet: {}", error), format!("水龙头: {}", error)))
+    }
+
+    pub fn no_addresses_configured(&self) -> String {
+        self.line("⚠️ ", self.pick(
+            "No addresses configured; monitoring would have nothing to watch".to_string(),
+            "未配置地址，监控将没有可关注的对象".to_string(),
+        ))
+    }
+
+    pub fn health_check_passed(&self) -> String {
+        self.line("🎉", self.pick("Health check passed".to_string(), "健康检查通过".to_string()))
+    }
+
+    pub fn health_check_failed(&self) -> String {
+        self.line("💥", self.pick("Health check failed".to_string(), "健康检查未通过".to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_messages_language_and_emoji() {
+        let en = Messages::default();
+        assert_eq!(en.querying_address("0x1"), "🔍 Querying SUI address: 0x1");
+
+        let zh = Messages::new(Language::Zh, true);
+        assert_eq!(zh.querying_address("0x1"), "🔍 正在查询 SUI 地址: 0x1");
+        assert_eq!(zh.no_transactions_hint()[0], "💡 提示: 如果没有看到交易，可能是因为:");
+
+        let plain = Messages::new(Language::En, false);
+        assert_eq!(plain.time("now"), "Time: now");
+        assert_eq!(plain.health_check_passed(), "Health check passed");
+        assert_eq!(plain.rpc_latency(1500, true), "RPC latency: 1500 ms (slow)");
+        assert!(plain.no_transactions_hint().iter().all(|line| line.is_ascii()));
+
+        let language: Language = toml::Value::String("zh".to_string()).try_into().unwrap();
+        assert_eq!(language, Language::Zh);
+    }
+}
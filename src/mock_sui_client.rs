@@ -0,0 +1,316 @@
+use crate::config::Config;
+use crate::error::{TrackerError, TrackerResult};
+use crate::sui_client::{
+    transactions_to_events, AllBalances, BalanceDetail, EventPage, MoveEventPage, PackageFilter, SuiCoin, SuiEvent,
+    SuiRpc, SuiTransaction, TransactionDirection,
+};
+use chrono::{DateTime, Utc};
+use futures::future::BoxFuture;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Mutex;
+
+const SUI_COIN_TYPE: &str = "0x2::sui::SUI";
+
+/// 返回预设数据的离线客户端，用于在没有网络的情况下测试监控和处理流程
+///
+/// 地址按规范形式保存，交易按加入顺序（从旧到新）返回。
+#[derive(Debug)]
+pub struct MockSuiClient {
+    network_name: String,
+    chain_id: String,
+    healthy: AtomicBool,
+    latest_checkpoint: AtomicU64,
+    /// 地址 -> [(代币类型, 余额)]
+    balances: Mutex<HashMap<String, Vec<(String, u64)>>>,
+    sent: Mutex<HashMap<String, Vec<SuiTransaction>>>,
+    received: Mutex<HashMap<String, Vec<SuiTransaction>>>,
+    /// suix_queryEvents 形式的原始 Move 事件，按加入顺序（从旧到新）
+    move_events: Mutex<Vec<serde_json::Value>>,
+    /// 地址 -> Coin 对象
+    coins: Mutex<HashMap<String, Vec<SuiCoin>>>,
+}
+
+impl Default for MockSuiClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MockSuiClient {
+    pub fn new() -> Self {
+        Self {
+            network_name: "mock".to_string(),
+            chain_id: "mock".to_string(),
+            healthy: AtomicBool::new(true),
+            latest_checkpoint: AtomicU64::new(0),
+            balances: Mutex::new(HashMap::new()),
+            sent: Mutex::new(HashMap::new()),
+            received: Mutex::new(HashMap::new()),
+            move_events: Mutex::new(Vec::new()),
+            coins: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn with_network_name(mut self, name: impl Into<String>) -> Self {
+        self.network_name = name.into();
+        self
+    }
+
+    /// 设置为不健康时，链ID和交易查询失败、健康检查返回 false
+    pub fn set_healthy(&self, healthy: bool) {
+        self.healthy.store(healthy, Ordering::SeqCst);
+    }
+
+    pub fn set_latest_checkpoint(&self, checkpoint: u64) {
+        self.latest_checkpoint.store(checkpoint, Ordering::SeqCst);
+    }
+
+    pub fn set_balance(&self, address: &str, coin_type: &str, balance: u64) {
+        let mut balances = self.balances.lock().unwrap();
+        let entry = balances.entry(Self::key(address)).or_default();
+        match entry.iter_mut().find(|(existing, _)| existing == coin_type) {
+            Some((_, existing)) => *existing = balance,
+            None => entry.push((coin_type.to_string(), balance)),
+        }
+    }
+
+    /// 添加地址发送的交易
+    pub fn add_sent_transaction(&self, address: &str, transaction: SuiTransaction) {
+        self.sent.lock().unwrap().entry(Self::key(address)).or_default().push(transaction);
+    }
+
+    /// 添加地址接收的交易
+    pub fn add_received_transaction(&self, address: &str, transaction: SuiTransaction) {
+        self.received.lock().unwrap().entry(Self::key(address)).or_default().push(transaction);
+    }
+
+    /// 添加 Move 事件（需包含 `id`、`packageId`、`transactionModule`），按包/模块过滤返回
+    pub fn add_move_event(&self, event: serde_json::Value) {
+        self.move_events.lock().unwrap().push(event);
+    }
+
+    /// 添加地址持有的 Coin 对象，按 coin_type 过滤返回
+    pub fn add_coin(&self, address: &str, coin: SuiCoin) {
+        self.coins.lock().unwrap().entry(Self::key(address)).or_default().push(coin);
+    }
+
+    fn key(address: &str) -> String {
+        Config::normalize_address(address).unwrap_or_else(|| address.to_string())
+    }
+
+    fn transactions(&self, address: &str, direction: TransactionDirection) -> Vec<SuiTransaction> {
+        let store = match direction {
+            TransactionDirection::Sent => &self.sent,
+            TransactionDirection::Received => &self.received,
+        };
+        store.lock().unwrap().get(&Self::key(address)).cloned().unwrap_or_default()
+    }
+
+    fn balance(&self, address: &str, coin_type: Option<&str>) -> u64 {
+        let coin_type = coin_type.unwrap_or(SUI_COIN_TYPE);
+        self.balances.lock().unwrap()
+            .get(&Self::key(address))
+            .and_then(|balances| balances.iter().find(|(existing, _)| existing == coin_type))
+            .map(|(_, balance)| *balance)
+            .unwrap_or(0)
+    }
+
+    fn limited(mut transactions: Vec<SuiTransaction>, limit: Option<u16>) -> Vec<SuiTransaction> {
+        if let Some(limit) = limit {
+            transactions.truncate(limit as usize);
+        }
+        transactions
+    }
+}
+
+impl SuiRpc for MockSuiClient {
+    fn network_name(&self) -> &str {
+        &self.network_name
+    }
+
+    fn get_balance<'a>(&'a self, address: &'a str, coin_type: Option<&'a str>) -> BoxFuture<'a, TrackerResult<u64>> {
+        Box::pin(async move { Ok(self.balance(address, coin_type)) })
+    }
+
+    fn get_balance_detailed<'a>(&'a self, address: &'a str, coin_type: Option<&'a str>) -> BoxFuture<'a, TrackerResult<BalanceDetail>> {
+        Box::pin(async move {
+            let total_balance = self.balance(address, coin_type);
+            Ok(BalanceDetail {
+                coin_type: coin_type.unwrap_or(SUI_COIN_TYPE).to_string(),
+                total_balance,
+                coin_object_count: u64::from(total_balance > 0),
+                locked_balance: 0,
+            })
+        })
+    }
+
+    fn get_all_balances_report<'a>(&'a self, address: &'a str) -> BoxFuture<'a, TrackerResult<AllBalances>> {
+        Box::pin(async move {
+            Ok(AllBalances {
+                balances: self.balances.lock().unwrap().get(&Self::key(address)).cloned().unwrap_or_default(),
+                unparsed: Vec::new(),
+            })
+        })
+    }
+
+    fn query_transactions_sent<'a>(&'a self, address: &'a str, limit: Option<u16>) -> BoxFuture<'a, TrackerResult<Vec<SuiTransaction>>> {
+        Box::pin(async move { Ok(Self::limited(self.transactions(address, TransactionDirection::Sent), limit)) })
+    }
+
+    fn query_transactions_received<'a>(&'a self, address: &'a str, limit: Option<u16>) -> BoxFuture<'a, TrackerResult<Vec<SuiTransaction>>> {
+        Box::pin(async move { Ok(Self::limited(self.transactions(address, TransactionDirection::Received), limit)) })
+    }
+
+    fn query_transactions_since<'a>(
+        &'a self,
+        address: &'a str,
+        direction: TransactionDirection,
+        since: DateTime<Utc>,
+    ) -> BoxFuture<'a, TrackerResult<Vec<SuiTransaction>>> {
+        Box::pin(async move {
+            let mut transactions = self.transactions(address, direction);
+            transactions.retain(|tx| tx.timestamp.is_some_and(|timestamp| timestamp >= since));
+            // 与 SuiClient 一致，从新到旧返回
+            transactions.reverse();
+            Ok(transactions)
+        })
+    }
+
+    fn get_transaction<'a>(&'a self, digest: &'a str) -> BoxFuture<'a, TrackerResult<SuiTransaction>> {
+        Box::pin(async move {
+            if !self.healthy.load(Ordering::SeqCst) {
+                return Err(TrackerError::network_error("Failed to get transaction: mock client is unhealthy"));
+            }
+            let find = |store: &Mutex<HashMap<String, Vec<SuiTransaction>>>| {
+                store.lock().unwrap().values().flatten().find(|tx| tx.digest == digest).cloned()
+            };
+            find(&self.sent)
+                .or_else(|| find(&self.received))
+                .ok_or_else(|| TrackerError::validation_error(format!("Transaction {} not found", digest)))
+        })
+    }
+
+    fn get_chain_id(&self) -> BoxFuture<'_, TrackerResult<String>> {
+        Box::pin(async move {
+            if self.healthy.load(Ordering::SeqCst) {
+                Ok(self.chain_id.clone())
+            } else {
+                Err(TrackerError::network_error("Failed to get chain ID: mock client is unhealthy"))
+            }
+        })
+    }
+
+    fn health_check(&self) -> BoxFuture<'_, TrackerResult<bool>> {
+        Box::pin(async move { Ok(self.healthy.load(Ordering::SeqCst)) })
+    }
+
+    fn get_latest_checkpoint(&self) -> BoxFuture<'_, TrackerResult<u64>> {
+        Box::pin(async move { Ok(self.latest_checkpoint.load(Ordering::SeqCst)) })
+    }
+
+    fn query_transfer_events<'a>(&'a self, address: &'a str, limit: u32) -> BoxFuture<'a, TrackerResult<Vec<SuiEvent>>> {
+        Box::pin(async move {
+            let transactions = Self::limited(
+                self.transactions(address, TransactionDirection::Sent),
+                Some(limit.min(u16::MAX as u32) as u16),
+            );
+            Ok(transactions_to_events(&self.network_name, address, TransactionDirection::Sent, transactions))
+        })
+    }
+
+    fn get_coins<'a>(
+        &'a self,
+        address: &'a str,
+        coin_type: Option<&'a str>,
+        cursor: Option<&'a str>,
+        limit: Option<u32>,
+    ) -> BoxFuture<'a, TrackerResult<(Vec<SuiCoin>, Option<String>)>> {
+        Box::pin(async move {
+            let coin_type = coin_type.unwrap_or(SUI_COIN_TYPE);
+            let coins: Vec<SuiCoin> = self.coins.lock().unwrap()
+                .get(&Self::key(address))
+                .map(|coins| coins.iter().filter(|coin| coin.coin_type == coin_type).cloned().collect())
+                .unwrap_or_default();
+
+            // 游标为上一页最后一个 Coin 对象的ID
+            let start = cursor
+                .and_then(|cursor| coins.iter().position(|coin| coin.coin_object_id == cursor))
+                .map_or(0, |index| index + 1);
+            let limit = limit.map_or(usize::MAX, |limit| limit as usize);
+            let page: Vec<SuiCoin> = coins.iter().skip(start).take(limit).cloned().collect();
+            let next_cursor = if start + page.len() < coins.len() {
+                page.last().map(|coin| coin.coin_object_id.clone())
+            } else {
+                None
+            };
+            Ok((page, next_cursor))
+        })
+    }
+
+    fn query_package_events_after<'a>(
+        &'a self,
+        filter: &'a PackageFilter,
+        cursor: Option<&'a str>,
+        limit: u32,
+    ) -> BoxFuture<'a, TrackerResult<MoveEventPage>> {
+        Box::pin(async move {
+            let events: Vec<serde_json::Value> = self.move_events.lock().unwrap()
+                .iter()
+                .filter(|event| {
+                    let field = |key: &str| event.get(key).and_then(|v| v.as_str()).unwrap_or_default();
+                    Self::key(field("packageId")) == filter.package_id
+                        && filter.module.as_deref().is_none_or(|module| field("transactionModule") == module)
+                })
+                .cloned()
+                .collect();
+            let limit = limit as usize;
+            let event_cursor = |event: &serde_json::Value| event.get("id").and_then(MoveEventPage::cursor_from_json);
+
+            let start = match cursor {
+                Some(cursor) => events.iter()
+                    .position(|event| event_cursor(event).as_deref() == Some(cursor))
+                    .map_or(0, |index| index + 1),
+                None => events.len().saturating_sub(limit),
+            };
+            let page: Vec<serde_json::Value> = events.iter().skip(start).take(limit).cloned().collect();
+            let has_next_page = cursor.is_some() && start + page.len() < events.len();
+            let next_cursor = page.last()
+                .and_then(event_cursor)
+                .or_else(|| cursor.map(str::to_string));
+
+            Ok(MoveEventPage { events: page, cursor: next_cursor, has_next_page })
+        })
+    }
+
+    fn query_transfer_events_after<'a>(
+        &'a self,
+        address: &'a str,
+        cursor: Option<&'a str>,
+        limit: u32,
+    ) -> BoxFuture<'a, TrackerResult<EventPage>> {
+        Box::pin(async move {
+            let transactions = self.transactions(address, TransactionDirection::Sent);
+            let limit = limit as usize;
+
+            // 没有游标时返回最新的一页，否则返回游标之后的交易
+            let start = match cursor {
+                Some(cursor) => transactions.iter()
+                    .position(|tx| tx.digest == cursor)
+                    .map_or(0, |index| index + 1),
+                None => transactions.len().saturating_sub(limit),
+            };
+            let page: Vec<SuiTransaction> = transactions.iter().skip(start).take(limit).cloned().collect();
+            let has_next_page = cursor.is_some() && start + page.len() < transactions.len();
+            let next_cursor = page.last()
+                .map(|tx| tx.digest.clone())
+                .or_else(|| cursor.map(str::to_string));
+
+            Ok(EventPage {
+                events: transactions_to_events(&self.network_name, address, TransactionDirection::Sent, page),
+                cursor: next_cursor,
+                has_next_page,
+            })
+        })
+    }
+}
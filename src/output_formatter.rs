@@ -1,7 +1,20 @@
-use crate::transaction_processor::{Transaction, AddressStats, ProcessorStats};
+use crate::transaction_processor::{Transaction, AddressStats, ExportSummary, ProcessorStats};
 use crate::alert_system::{Alert, AlertStats};
+use crate::sui_client::{CoinReport, RpcLatencyStats, SuiTransaction};
+use crate::{GroupStats, TrackerStats};
+use crate::units::{mist_to_sui, MIST_PER_SUI, SUI_DECIMALS};
+use crate::messages::{Language, Messages};
+use std::borrow::Cow;
 use std::collections::HashMap;
+use crate::error::{TrackerError, TrackerResult};
 use chrono::DateTime;
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::sync::{Arc, RwLock};
+
+/// 交易历史CSV的表头
+pub const TRANSACTION_HISTORY_CSV_HEADER: &str =
+    "ID,Sender,Recipient,Amount,Amount_SUI,Token_Type,Timestamp,Block_Number,Gas_Used,Gas_Price,Status\n";
 
 #[derive(Debug, Clone)]
 pub struct OutputFormatter {
@@ -9,6 +22,8 @@ pub struct OutputFormatter {
     use_colors: bool,
     show_timestamps: bool,
     output_format: OutputFormat,
+    /// 最近一次查询到的 SUI/USD 价格，克隆的格式化器共享
+    usd_price: Arc<RwLock<Option<f64>>>,
 }
 
 #[derive(Debug, Clone)]
@@ -20,6 +35,24 @@ pub struct OutputConfig {
     pub table_width: usize,
     pub enable_json_output: bool,
     pub enable_csv_output: bool,
+    pub display_decimals: u8,
+    pub group_digits: bool,
+    pub compact: bool,
+    pub csv_header: bool,
+    /// 交易着色的金额分档（MIST），金额大于该值时使用对应颜色
+    pub color_tier_high: u64,
+    pub color_tier_medium: u64,
+    pub color_tier_low: u64,
+    /// JSON输出是否缩进（默认单行，便于逐行解析）
+    pub json_pretty: bool,
+    /// 不输出装饰性内容（欢迎信息、标题、进度提示）
+    pub quiet: bool,
+    /// 已知价格时在金额后附加美元估值
+    pub show_usd_value: bool,
+    /// 提示文本语言
+    pub language: Language,
+    /// 提示文本行首是否显示 emoji
+    pub use_emoji: bool,
 }
 
 impl Default for OutputConfig {
@@ -32,10 +65,31 @@ impl Default for OutputConfig {
             table_width: 80,
             enable_json_output: false,
             enable_csv_output: false,
+            display_decimals: 9,
+            group_digits: false,
+            compact: false,
+            csv_header: false,
+            color_tier_high: 10 * MIST_PER_SUI,
+            color_tier_medium: MIST_PER_SUI,
+            color_tier_low: MIST_PER_SUI / 10,
+            json_pretty: false,
+            quiet: false,
+            show_usd_value: false,
+            language: Language::default(),
+            use_emoji: true,
         }
     }
 }
 
+/// `--list-addresses` 输出的一行
+#[derive(Debug, Clone)]
+pub struct AddressListEntry {
+    pub address: String,
+    pub balance: u64,
+    pub total_transactions: u64,
+    pub label: Option<String>,
+}
+
 #[derive(Debug, Clone)]
 pub enum OutputFormat {
     Table,
@@ -50,6 +104,7 @@ impl OutputFormatter {
             use_colors,
             show_timestamps,
             output_format: OutputFormat::Table,
+            usd_price: Arc::new(RwLock::new(None)),
         }
     }
 
@@ -59,6 +114,7 @@ impl OutputFormatter {
             use_colors: config.use_colors,
             show_timestamps: config.show_timestamps,
             output_format: OutputFormat::Table,
+            usd_price: Arc::new(RwLock::new(None)),
         }
     }
 
@@ -66,6 +122,20 @@ impl OutputFormatter {
         self.output_format = format;
     }
 
+    pub fn is_quiet(&self) -> bool {
+        self.config.quiet
+    }
+
+    /// 金额后是否附带美元估值
+    pub fn shows_usd_value(&self) -> bool {
+        self.config.show_usd_value
+    }
+
+    /// 按配置的语言和 emoji 设置生成提示文本
+    pub fn messages(&self) -> Messages {
+        Messages::new(self.config.language, self.config.use_emoji)
+    }
+
     pub fn format_transaction(&self, transaction: &Transaction) -> String {
         match self.output_format {
             OutputFormat::Table => self.format_transaction_table(transaction),
@@ -83,10 +153,34 @@ impl OutputFormatter {
     }
 
     pub fn format_balance_summary(&self, balances: &HashMap<String, u64>) -> String {
+        self.format_balance_summary_top(balances, None)
+    }
+
+    /// 余额摘要，只包含余额最高的 limit 个地址
+    pub fn format_balance_summary_top(&self, balances: &HashMap<String, u64>, limit: Option<usize>) -> String {
+        let top = |limit: usize| -> HashMap<String, u64> {
+            Self::sorted_balances(balances)
+                .into_iter()
+                .take(limit)
+                .map(|(address, balance)| (address.clone(), *balance))
+                .collect()
+        };
+
+        match (&self.output_format, limit) {
+            (OutputFormat::Table, _) => self.format_balance_summary_table(balances, limit),
+            (OutputFormat::Json, None) => self.format_balance_summary_json(balances),
+            (OutputFormat::Json, Some(limit)) => self.format_balance_summary_json(&top(limit)),
+            (OutputFormat::Csv, None) => self.format_balance_summary_csv(balances),
+            (OutputFormat::Csv, Some(limit)) => self.format_balance_summary_csv(&top(limit)),
+        }
+    }
+
+    /// 余额摘要加上累计发送、接收和净流量，没有统计的地址按 0 显示
+    pub fn format_balance_summary_with_stats(&self, balances: &HashMap<String, u64>, stats: &HashMap<String, AddressStats>) -> String {
         match self.output_format {
-            OutputFormat::Table => self.format_balance_summary_table(balances),
-            OutputFormat::Json => self.format_balance_summary_json(balances),
-            OutputFormat::Csv => self.format_balance_summary_csv(balances),
+            OutputFormat::Table => self.format_balance_summary_with_stats_table(balances, stats),
+            OutputFormat::Json => self.format_balance_summary_with_stats_json(balances, stats),
+            OutputFormat::Csv => self.format_balance_summary_with_stats_csv(balances, stats),
         }
     }
 
@@ -122,6 +216,63 @@ impl OutputFormatter {
         }
     }
 
+    pub fn format_address_list(&self, entries: &[AddressListEntry]) -> String {
+        match self.output_format {
+            OutputFormat::Table => self.format_address_list_table(entries),
+            OutputFormat::Json => self.format_address_list_json(entries),
+            OutputFormat::Csv => self.format_address_list_csv(entries),
+        }
+    }
+
+    pub fn format_final_stats(&self, stats: &TrackerStats, processor_stats: &ProcessorStats) -> String {
+        match self.output_format {
+            OutputFormat::Table => self.format_final_stats_table(stats, processor_stats),
+            OutputFormat::Json => self.format_final_stats_json(stats, processor_stats),
+            OutputFormat::Csv => self.format_final_stats_csv(stats, processor_stats),
+        }
+    }
+
+    pub fn format_top_addresses(&self, ranking: &[(String, u64)], window_hours: u64) -> String {
+        match self.output_format {
+            OutputFormat::Table => self.format_top_addresses_table(ranking, window_hours),
+            OutputFormat::Json => self.format_top_addresses_json(ranking, window_hours),
+            OutputFormat::Csv => self.format_top_addresses_csv(ranking),
+        }
+    }
+
+    pub fn format_group_summary(&self, stats: &GroupStats) -> String {
+        match self.output_format {
+            OutputFormat::Table => self.format_group_summary_table(stats),
+            OutputFormat::Json => self.format_group_summary_json(stats),
+            OutputFormat::Csv => self.format_group_summary_csv(stats),
+        }
+    }
+
+    pub fn format_coin_report(&self, report: &CoinReport) -> String {
+        match self.output_format {
+            OutputFormat::Table => self.format_coin_report_table(report),
+            OutputFormat::Json => self.format_coin_report_json(report),
+            OutputFormat::Csv => self.format_coin_report_csv(report),
+        }
+    }
+
+    pub fn format_rpc_latency_stats(&self, stats: &[RpcLatencyStats]) -> String {
+        match self.output_format {
+            OutputFormat::Table => self.format_rpc_latency_stats_table(stats),
+            OutputFormat::Json => self.format_rpc_latency_stats_json(stats),
+            OutputFormat::Csv => self.format_rpc_latency_stats_csv(stats),
+        }
+    }
+
+    /// 格式化按digest查询到的链上交易
+    pub fn format_sui_transaction(&self, transaction: &SuiTransaction) -> String {
+        match self.output_format {
+            OutputFormat::Table => self.format_sui_transaction_table(transaction),
+            OutputFormat::Json => self.format_sui_transaction_json(transaction),
+            OutputFormat::Csv => self.format_sui_transaction_csv(transaction),
+        }
+    }
+
     // Table formatting methods
     fn format_transaction_table(&self, transaction: &Transaction) -> String {
         let timestamp = if self.show_timestamps {
@@ -132,22 +283,23 @@ impl OutputFormatter {
             String::new()
         };
 
-        let amount_formatted = self.format_amount(transaction.amount);
-        let _color_prefix = if self.use_colors {
-            self.get_transaction_color(transaction)
+        // 金额按 color_tier_* 分档着色
+        let amount_formatted = if self.use_colors {
+            format!("{}{}\x1b[0m", self.get_transaction_color(transaction), self.format_amount(transaction.amount))
         } else {
-            String::new()
+            self.format_amount(transaction.amount)
         };
 
-        let _color_suffix = if self.use_colors {
-            "\x1b[0m"
+        let network = if transaction.network.is_empty() {
+            String::new()
         } else {
-            ""
+            format!("[{}] ", transaction.network)
         };
 
         format!(
-            "{}{}→ {} {} | {} | {} | {}",
+            "{}{}{}→ {} {} | {} | {} | {}",
             timestamp,
+            network,
             self.truncate_address(&transaction.sender),
             self.truncate_address(&transaction.recipient),
             amount_formatted,
@@ -229,7 +381,14 @@ impl OutputFormatter {
         )
     }
 
-    fn format_balance_summary_table(&self, balances: &HashMap<String, u64>) -> String {
+    /// 按余额从高到低排序
+    fn sorted_balances(balances: &HashMap<String, u64>) -> Vec<(&String, &u64)> {
+        let mut sorted: Vec<_> = balances.iter().collect();
+        sorted.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+        sorted
+    }
+
+    fn format_balance_summary_table(&self, balances: &HashMap<String, u64>, limit: Option<usize>) -> String {
         if balances.is_empty() {
             return "No balances to display".to_string();
         }
@@ -241,18 +400,51 @@ impl OutputFormatter {
             self.repeat_char('=', 15), 
             self.repeat_char('=', 10)));
 
-        let mut sorted_balances: Vec<_> = balances.iter().collect();
-        sorted_balances.sort_by(|a, b| b.1.cmp(a.1));
-
-        for (address, balance) in sorted_balances {
+        let limit = limit.unwrap_or(balances.len());
+        for (address, balance) in Self::sorted_balances(balances).into_iter().take(limit) {
             summary.push_str(&format!(
-                "{:<20} {:<15.9} {:<10}\n",
+                "{:<20} {:<15} {:<10}\n",
                 self.truncate_address(address),
-                *balance as f64 / 1_000_000_000.0,
+                self.format_sui_value(*balance, true),
                 self.format_amount(*balance)
             ));
         }
 
+        if balances.len() > limit {
+            summary.push_str(&format!("... and {} more addresses\n", balances.len() - limit));
+        }
+
+        summary
+    }
+
+    fn format_balance_summary_with_stats_table(&self, balances: &HashMap<String, u64>, stats: &HashMap<String, AddressStats>) -> String {
+        if balances.is_empty() {
+            return "No balances to display".to_string();
+        }
+
+        let mut summary = String::from("Balance Summary:\n");
+        summary.push_str(&format!("{:<20} {:<15} {:<15} {:<15} {:<16}\n",
+            "Address", "Balance (SUI)", "Sent (SUI)", "Received (SUI)", "Net (SUI)"));
+        summary.push_str(&format!("{:<20} {:<15} {:<15} {:<15} {:<16}\n",
+            self.repeat_char('=', 20),
+            self.repeat_char('=', 15),
+            self.repeat_char('=', 15),
+            self.repeat_char('=', 15),
+            self.repeat_char('=', 16)));
+
+        let empty = AddressStats::default();
+        for (address, balance) in Self::sorted_balances(balances) {
+            let address_stats = stats.get(address).unwrap_or(&empty);
+            summary.push_str(&format!(
+                "{:<20} {:<15} {:<15} {:<15} {:<16}\n",
+                self.truncate_address(address),
+                self.format_sui_value(*balance, true),
+                self.format_sui_value(address_stats.total_sent, true),
+                self.format_sui_value(address_stats.total_received, true),
+                self.format_signed_sui_value(address_stats.net_flow())
+            ));
+        }
+
         summary
     }
 
@@ -277,11 +469,11 @@ impl OutputFormatter {
                 .unwrap_or_default();
             
             history.push_str(&format!(
-                "{:<12} {:<12} {:<12} {:<15.9} {:<12} {:<8}\n",
+                "{:<12} {:<12} {:<12} {:<15} {:<12} {:<8}\n",
                 dt.format("%H:%M:%S"),
                 self.truncate_address(&transaction.sender),
                 self.truncate_address(&transaction.recipient),
-                transaction.amount as f64 / 1_000_000_000.0,
+                self.format_sui_value(transaction.amount, true),
                 self.format_token_type(&transaction.token_type),
                 self.format_status(&transaction.status)
             ));
@@ -296,6 +488,8 @@ impl OutputFormatter {
         summary.push_str(&format!("  Total Transactions: {}\n", stats.total_transactions));
         summary.push_str(&format!("  Total Sent: {}\n", self.format_amount(stats.total_sent)));
         summary.push_str(&format!("  Total Received: {}\n", self.format_amount(stats.total_received)));
+        summary.push_str(&format!("  Net Flow: {}\n", self.format_signed_amount(stats.net_flow())));
+        summary.push_str(&format!("  Gas Spent: {}\n", self.format_amount(stats.total_gas_spent)));
         summary.push_str(&format!("  Average Transaction: {}\n", self.format_amount(stats.average_transaction_amount)));
         summary.push_str(&format!("  Largest Transaction: {}\n", self.format_amount(stats.largest_transaction)));
         summary.push_str(&format!("  Smallest Transaction: {}\n", 
@@ -324,7 +518,30 @@ impl OutputFormatter {
         summary.push_str(&format!("  Total Transactions: {}\n", stats.total_transactions));
         summary.push_str(&format!("  Total Volume: {}\n", self.format_amount(stats.total_volume)));
         summary.push_str(&format!("  Max History Records: {}\n", stats.config.max_history_records));
+        summary.push_str(&format!("  Evicted History Records: {}\n", stats.evicted_count));
         summary.push_str(&format!("  Cleanup Interval: {} hours\n", stats.config.cleanup_interval_hours));
+        let processing_time = &stats.processing_time;
+        if processing_time.count > 0 {
+            summary.push_str(&format!(
+                "  Processing Time: {} events, avg {}, p95 {}, max {}\n",
+                processing_time.count,
+                Self::format_nanos(processing_time.mean_ns()),
+                Self::format_nanos(processing_time.p95_ns),
+                Self::format_nanos(processing_time.max_ns)
+            ));
+        }
+        // 同时监控多个网络时按网络分组显示
+        if stats.by_network.len() > 1 {
+            summary.push_str("  By Network:\n");
+            for (network, network_stats) in &stats.by_network {
+                summary.push_str(&format!(
+                    "    {}: {} transactions, {}\n",
+                    network,
+                    network_stats.total_transactions,
+                    self.format_amount(network_stats.total_volume)
+                ));
+            }
+        }
         summary
     }
 
@@ -345,25 +562,163 @@ impl OutputFormatter {
         summary
     }
 
+    fn format_address_list_table(&self, entries: &[AddressListEntry]) -> String {
+        let mut list = String::from("Monitored addresses:\n");
+        for entry in entries {
+            let label = entry.label.as_ref().map(|l| format!(" [{}]", l)).unwrap_or_default();
+            list.push_str(&format!("  {}{}: {} ({} transactions)\n",
+                entry.address,
+                label,
+                self.format_amount(entry.balance),
+                entry.total_transactions));
+        }
+        list
+    }
+
+    fn format_final_stats_table(&self, stats: &TrackerStats, processor_stats: &ProcessorStats) -> String {
+        let mut summary = String::from("=== Final Statistics ===\n");
+        summary.push_str(&format!("Uptime: {} seconds\n", stats.uptime_seconds));
+        summary.push_str(&format!("Events processed: {}\n", stats.total_events_processed));
+        summary.push_str(&format!("Transactions processed: {}\n", stats.total_transactions_processed));
+        summary.push_str(&format!("Alerts sent: {}\n", stats.total_alerts_sent));
+        summary.push_str(&format!("Errors encountered: {}\n", stats.total_errors));
+        summary.push_str(&format!("Addresses monitored: {}\n", stats.addresses_monitored));
+        summary.push_str(&format!("Balances reconciled: {} (total drift: {})\n", stats.balances_reconciled, self.format_amount(stats.total_balance_drift)));
+        summary.push_str(&format!("Event queue: {} queued, {} backpressure waits\n", stats.events_queued, stats.event_backpressure_waits));
+        summary.push_str(&format!("Alert queue: {} queued, {} dropped\n", stats.alerts_queued, stats.alerts_dropped));
+        summary.push_str(&format!("Total addresses in processor: {}\n", processor_stats.total_addresses));
+        summary.push_str(&format!("Total volume processed: {}\n", self.format_amount(processor_stats.total_volume)));
+        summary
+    }
+
+    fn format_top_addresses_table(&self, ranking: &[(String, u64)], window_hours: u64) -> String {
+        if ranking.is_empty() {
+            return format!("No transfer volume in the last {} hours", window_hours);
+        }
+
+        let mut summary = format!("Top Addresses by Volume (last {} hours):\n", window_hours);
+        summary.push_str(&format!("{:<5} {:<20} {:<15}\n", "Rank", "Address", "Volume (SUI)"));
+        summary.push_str(&format!("{:<5} {:<20} {:<15}\n",
+            self.repeat_char('=', 5),
+            self.repeat_char('=', 20),
+            self.repeat_char('=', 15)));
+
+        for (rank, (address, volume)) in ranking.iter().enumerate() {
+            summary.push_str(&format!(
+                "{:<5} {:<20} {:<15}\n",
+                rank + 1,
+                self.truncate_address(address),
+                self.format_sui_value(*volume, true)
+            ));
+        }
+
+        summary
+    }
+
+    fn format_group_summary_table(&self, stats: &GroupStats) -> String {
+        let mut summary = format!("Group '{}' ({} addresses):\n", stats.name, stats.addresses);
+        summary.push_str(&format!("  Total Balance: {}\n", self.format_amount(stats.total_balance)));
+        summary.push_str(&format!("  Total Transactions: {}\n", stats.total_transactions));
+        summary.push_str(&format!("  Total Sent: {}\n", self.format_amount(stats.total_sent)));
+        summary.push_str(&format!("  Total Received: {}\n", self.format_amount(stats.total_received)));
+        summary.push_str(&format!("  Gas Spent: {}\n", self.format_amount(stats.total_gas_spent)));
+        summary
+    }
+
+    fn format_coin_report_table(&self, report: &CoinReport) -> String {
+        // 非 SUI 代币的小数位未知，直接显示最小单位
+        let amount = |value: u64| {
+            if report.coin_type == "0x2::sui::SUI" {
+                self.format_amount(value)
+            } else {
+                format!("{} units", value)
+            }
+        };
+        let mut summary = format!("Coins of {} held by {}:\n", report.coin_type, report.address);
+        summary.push_str(&format!("  Coin Objects: {}\n", report.coin_count));
+        summary.push_str(&format!("  Total Balance: {}\n", amount(report.total_balance)));
+        summary.push_str(&format!("  Largest Coin: {}\n", amount(report.largest_coin)));
+        summary.push_str(&format!("  Smallest Coin: {}\n", amount(report.smallest_coin)));
+        summary.push_str(&format!("  Fragmentation: {:.2}\n", report.fragmentation_score));
+        summary
+    }
+
+    fn format_rpc_latency_stats_table(&self, stats: &[RpcLatencyStats]) -> String {
+        let mut summary = String::from("RPC Latency (ms):\n");
+        summary.push_str(&format!("{:<36} {:>8} {:>8} {:>8} {:>8} {:>8}\n", "Method", "Count", "Min", "P50", "P95", "Max"));
+        for entry in stats {
+            summary.push_str(&format!(
+                "{:<36} {:>8} {:>8} {:>8} {:>8} {:>8}\n",
+                entry.method,
+                entry.count,
+                entry.min.as_millis(),
+                entry.p50.as_millis(),
+                entry.p95.as_millis(),
+                entry.max.as_millis()
+            ));
+        }
+        summary
+    }
+
+    fn format_sui_transaction_table(&self, transaction: &SuiTransaction) -> String {
+        let mut summary = format!("Transaction {}\n", transaction.digest);
+        summary.push_str(&format!("  Status: {} {:?}\n", self.format_status(&transaction.status), transaction.status));
+        if let Some(timestamp) = &transaction.timestamp {
+            summary.push_str(&format!("  Time: {}\n", timestamp.format("%Y-%m-%d %H:%M:%S UTC")));
+        }
+        if let Some(gas_used) = transaction.gas_used.as_deref().and_then(|g| g.parse::<u64>().ok()) {
+            summary.push_str(&format!("  Gas Used: {}\n", self.format_amount(gas_used)));
+        }
+        if !transaction.balance_changes.is_empty() {
+            summary.push_str("  Balance Changes:\n");
+            for change in &transaction.balance_changes {
+                let sign = if change.amount < 0 { "-" } else { "+" };
+                summary.push_str(&format!(
+                    "    {} {}{} ({})\n",
+                    self.truncate_address(&change.owner),
+                    sign,
+                    self.format_amount(change.amount.unsigned_abs()),
+                    change.coin_type
+                ));
+            }
+        }
+        summary
+    }
+
     // JSON formatting methods
+    /// 按 `json_pretty` 输出紧凑（单行）或缩进的JSON
+    fn json_string(&self, value: serde_json::Value) -> String {
+        if self.config.json_pretty {
+            serde_json::to_string_pretty(&value).unwrap_or_else(|_| value.to_string())
+        } else {
+            value.to_string()
+        }
+    }
+
     fn format_transaction_json(&self, transaction: &Transaction) -> String {
+        self.json_string(self.transaction_json_value(transaction))
+    }
+
+    /// 单笔交易的JSON对象，流式导出时逐条序列化
+    pub fn transaction_json_value(&self, transaction: &Transaction) -> serde_json::Value {
         serde_json::json!({
             "id": transaction.id,
             "sender": transaction.sender,
             "recipient": transaction.recipient,
             "amount": transaction.amount,
-            "amount_sui": transaction.amount as f64 / 1_000_000_000.0,
+            "amount_sui": mist_to_sui(transaction.amount, SUI_DECIMALS),
             "token_type": transaction.token_type,
             "timestamp": transaction.timestamp,
             "block_number": transaction.block_number,
             "gas_used": transaction.gas_used,
             "gas_price": transaction.gas_price,
             "status": self.format_status(&transaction.status),
-        }).to_string()
+            "network": transaction.network,
+        })
     }
 
-    fn format_alert_json(&self, alert: &Alert) -> String {
-        serde_json::json!({
+    pub fn format_alert_json(&self, alert: &Alert) -> String {
+        self.json_string(serde_json::json!({
             "type": match alert {
                 Alert::LowBalance { .. } => "low_balance",
                 Alert::LargeTransfer { .. } => "large_transfer",
@@ -380,7 +735,30 @@ impl OutputFormatter {
             },
             "timestamp": alert.timestamp().to_rfc3339(),
             "message": self.format_alert_table(alert),
-        }).to_string()
+        }))
+    }
+
+    fn format_balance_summary_with_stats_json(&self, balances: &HashMap<String, u64>, stats: &HashMap<String, AddressStats>) -> String {
+        let empty = AddressStats::default();
+        let formatted_balances: HashMap<String, serde_json::Value> = balances
+            .iter()
+            .map(|(addr, balance)| {
+                let address_stats = stats.get(addr).unwrap_or(&empty);
+                (addr.clone(), serde_json::json!({
+                    "balance": balance,
+                    "balance_sui": mist_to_sui(*balance, SUI_DECIMALS),
+                    "total_sent": address_stats.total_sent,
+                    "total_received": address_stats.total_received,
+                    "net_flow": address_stats.net_flow(),
+                    "net_flow_sui": mist_to_sui(address_stats.net_flow(), SUI_DECIMALS),
+                }))
+            })
+            .collect();
+
+        self.json_string(serde_json::json!({
+            "summary": formatted_balances,
+            "total_addresses": balances.len(),
+        }))
     }
 
     fn format_balance_summary_json(&self, balances: &HashMap<String, u64>) -> String {
@@ -389,53 +767,46 @@ impl OutputFormatter {
             .map(|(addr, balance)| {
                 (addr.clone(), serde_json::json!({
                     "balance": balance,
-                    "balance_sui": *balance as f64 / 1_000_000_000.0,
+                    "balance_sui": mist_to_sui(*balance, SUI_DECIMALS),
                 }))
             })
             .collect();
 
-        serde_json::json!({
+        self.json_string(serde_json::json!({
             "summary": formatted_balances,
             "total_addresses": balances.len(),
-        }).to_string()
+        }))
     }
 
     fn format_transaction_history_json(&self, transactions: &[Transaction]) -> String {
+        // 与单笔交易和 --export-history 使用同一种对象格式
         let formatted_transactions: Vec<serde_json::Value> = transactions
             .iter()
-            .map(|tx| serde_json::json!({
-                "id": tx.id,
-                "sender": tx.sender,
-                "recipient": tx.recipient,
-                "amount": tx.amount,
-                "amount_sui": tx.amount as f64 / 1_000_000_000.0,
-                "token_type": tx.token_type,
-                "timestamp": tx.timestamp,
-                "block_number": tx.block_number,
-                "gas_used": tx.gas_used,
-                "gas_price": tx.gas_price,
-                "status": self.format_status(&tx.status),
-            }))
+            .map(|tx| self.transaction_json_value(tx))
             .collect();
 
-        serde_json::json!({
+        self.json_string(serde_json::json!({
             "transactions": formatted_transactions,
             "total_count": transactions.len(),
-        }).to_string()
+        }))
     }
 
     fn format_address_stats_json(&self, address: &str, stats: &AddressStats) -> String {
-        serde_json::json!({
+        self.json_string(serde_json::json!({
             "address": address,
             "total_transactions": stats.total_transactions,
             "total_sent": stats.total_sent,
             "total_received": stats.total_received,
-            "total_sent_sui": stats.total_sent as f64 / 1_000_000_000.0,
-            "total_received_sui": stats.total_received as f64 / 1_000_000_000.0,
+            "total_sent_sui": mist_to_sui(stats.total_sent, SUI_DECIMALS),
+            "total_received_sui": mist_to_sui(stats.total_received, SUI_DECIMALS),
+            "net_flow": stats.net_flow(),
+            "net_flow_sui": mist_to_sui(stats.net_flow(), SUI_DECIMALS),
+            "total_gas_spent": stats.total_gas_spent,
+            "total_gas_spent_sui": mist_to_sui(stats.total_gas_spent, SUI_DECIMALS),
             "average_transaction_amount": stats.average_transaction_amount,
-            "average_transaction_amount_sui": stats.average_transaction_amount as f64 / 1_000_000_000.0,
+            "average_transaction_amount_sui": mist_to_sui(stats.average_transaction_amount, SUI_DECIMALS),
             "largest_transaction": stats.largest_transaction,
-            "largest_transaction_sui": stats.largest_transaction as f64 / 1_000_000_000.0,
+            "largest_transaction_sui": mist_to_sui(stats.largest_transaction, SUI_DECIMALS),
             "smallest_transaction": if stats.smallest_transaction == u64::MAX {
                 serde_json::Value::Null
             } else {
@@ -443,55 +814,166 @@ impl OutputFormatter {
             },
             "first_transaction": stats.first_transaction,
             "last_transaction": stats.last_transaction,
-        }).to_string()
+        }))
     }
 
     fn format_system_stats_json(&self, stats: &ProcessorStats) -> String {
-        serde_json::json!({
+        self.json_string(serde_json::json!({
             "total_addresses": stats.total_addresses,
             "total_transactions": stats.total_transactions,
             "total_volume": stats.total_volume,
-            "total_volume_sui": stats.total_volume as f64 / 1_000_000_000.0,
+            "total_volume_sui": mist_to_sui(stats.total_volume, SUI_DECIMALS),
             "max_history_records": stats.config.max_history_records,
+            "evicted_count": stats.evicted_count,
             "cleanup_interval_hours": stats.config.cleanup_interval_hours,
-        }).to_string()
+            "by_network": stats.by_network,
+            "processing_time": {
+                "count": stats.processing_time.count,
+                "total_ns": stats.processing_time.total_ns,
+                "mean_ns": stats.processing_time.mean_ns(),
+                "p95_ns": stats.processing_time.p95_ns,
+                "max_ns": stats.processing_time.max_ns,
+            },
+        }))
     }
 
     fn format_alert_summary_json(&self, alert_stats: &AlertStats) -> String {
-        serde_json::json!({
+        self.json_string(serde_json::json!({
             "total_alerts": alert_stats.total_alerts,
             "alerts_by_type": alert_stats.alerts_by_type,
             "alerts_by_severity": alert_stats.alerts_by_severity,
-        }).to_string()
+        }))
+    }
+
+    fn format_address_list_json(&self, entries: &[AddressListEntry]) -> String {
+        let list: Vec<serde_json::Value> = entries
+            .iter()
+            .map(|entry| serde_json::json!({
+                "address": entry.address,
+                "balance": entry.balance,
+                "balance_sui": mist_to_sui(entry.balance, SUI_DECIMALS),
+                "total_transactions": entry.total_transactions,
+                "label": entry.label,
+            }))
+            .collect();
+        self.json_string(serde_json::Value::Array(list))
+    }
+
+    fn format_final_stats_json(&self, stats: &TrackerStats, processor_stats: &ProcessorStats) -> String {
+        self.json_string(serde_json::json!({
+            "uptime_seconds": stats.uptime_seconds,
+            "events_processed": stats.total_events_processed,
+            "transactions_processed": stats.total_transactions_processed,
+            "alerts_sent": stats.total_alerts_sent,
+            "errors": stats.total_errors,
+            "addresses_monitored": stats.addresses_monitored,
+            "balances_reconciled": stats.balances_reconciled,
+            "total_balance_drift": stats.total_balance_drift,
+            "events_filtered": stats.events_filtered,
+            "events_queued": stats.events_queued,
+            "event_backpressure_waits": stats.event_backpressure_waits,
+            "alerts_queued": stats.alerts_queued,
+            "alerts_dropped": stats.alerts_dropped,
+            "processor_addresses": processor_stats.total_addresses,
+            "total_volume": processor_stats.total_volume,
+            "total_volume_sui": mist_to_sui(processor_stats.total_volume, SUI_DECIMALS),
+        }))
+    }
+
+    fn format_top_addresses_json(&self, ranking: &[(String, u64)], window_hours: u64) -> String {
+        let entries: Vec<serde_json::Value> = ranking
+            .iter()
+            .enumerate()
+            .map(|(rank, (address, volume))| serde_json::json!({
+                "rank": rank + 1,
+                "address": address,
+                "volume": volume,
+                "volume_sui": mist_to_sui(*volume, SUI_DECIMALS),
+            }))
+            .collect();
+
+        self.json_string(serde_json::json!({
+            "window_hours": window_hours,
+            "top_addresses": entries,
+        }))
+    }
+
+    fn format_group_summary_json(&self, stats: &GroupStats) -> String {
+        self.json_string(serde_json::json!({
+            "group": stats.name,
+            "addresses": stats.addresses,
+            "total_balance": stats.total_balance,
+            "total_balance_sui": mist_to_sui(stats.total_balance, SUI_DECIMALS),
+            "total_transactions": stats.total_transactions,
+            "total_sent": stats.total_sent,
+            "total_received": stats.total_received,
+            "total_gas_spent": stats.total_gas_spent,
+        }))
+    }
+
+    fn format_coin_report_json(&self, report: &CoinReport) -> String {
+        let value = serde_json::to_value(report).unwrap_or_else(|e| {
+            serde_json::json!({ "error": format!("Failed to serialize coin report: {}", e) })
+        });
+        self.json_string(value)
+    }
+
+    fn format_rpc_latency_stats_json(&self, stats: &[RpcLatencyStats]) -> String {
+        let entries: Vec<serde_json::Value> = stats
+            .iter()
+            .map(|entry| serde_json::json!({
+                "method": entry.method,
+                "count": entry.count,
+                "min_ms": entry.min.as_millis() as u64,
+                "p50_ms": entry.p50.as_millis() as u64,
+                "p95_ms": entry.p95.as_millis() as u64,
+                "max_ms": entry.max.as_millis() as u64,
+            }))
+            .collect();
+        self.json_string(serde_json::json!({ "rpc_latency": entries }))
+    }
+
+    fn format_sui_transaction_json(&self, transaction: &SuiTransaction) -> String {
+        let value = serde_json::to_value(transaction).unwrap_or_else(|e| {
+            serde_json::json!({ "error": format!("Failed to serialize transaction: {}", e) })
+        });
+        self.json_string(value)
     }
 
     // CSV formatting methods
     fn format_transaction_csv(&self, transaction: &Transaction) -> String {
-        format!(
-            "{},{},{},{},{},{},{},{},{},{}\n",
-            transaction.id,
-            transaction.sender,
-            transaction.recipient,
-            transaction.amount,
-            transaction.amount as f64 / 1_000_000_000.0,
-            transaction.token_type,
-            transaction.timestamp,
-            transaction.block_number,
-            transaction.gas_used.unwrap_or(0),
-            self.format_status(&transaction.status)
-        )
+        let mut csv = String::new();
+        if self.config.csv_header {
+            csv.push_str("ID,Sender,Recipient,Amount,Amount_SUI,Token_Type,Timestamp,Block_Number,Gas_Used,Status\n");
+        }
+        csv.push_str(&Self::csv_row(&[
+            transaction.id.clone(),
+            transaction.sender.clone(),
+            transaction.recipient.clone(),
+            transaction.amount.to_string(),
+            self.format_sui_value(transaction.amount, false),
+            transaction.token_type.clone(),
+            transaction.timestamp.to_string(),
+            transaction.block_number.to_string(),
+            transaction.gas_used.unwrap_or(0).to_string(),
+            self.format_status(&transaction.status),
+        ]));
+        csv
     }
 
     fn format_alert_csv(&self, alert: &Alert) -> String {
-        format!(
-            "{},{},{},{}\n",
+        let mut csv = String::new();
+        if self.config.csv_header {
+            csv.push_str("Timestamp,Severity,Type,Message\n");
+        }
+        csv.push_str(&Self::csv_row(&[
             alert.timestamp().to_rfc3339(),
             match alert.severity() {
                 crate::alert_system::AlertSeverity::Info => "info",
                 crate::alert_system::AlertSeverity::Warning => "warning",
                 crate::alert_system::AlertSeverity::Error => "error",
                 crate::alert_system::AlertSeverity::Critical => "critical",
-            },
+            }.to_string(),
             match alert {
                 Alert::LowBalance { .. } => "low_balance",
                 Alert::LargeTransfer { .. } => "large_transfer",
@@ -499,74 +981,111 @@ impl OutputFormatter {
                 Alert::NetworkError { .. } => "network_error",
                 Alert::SystemError { .. } => "system_error",
                 Alert::Custom { .. } => "custom",
-            },
-            self.format_alert_table(alert)
-        )
+            }.to_string(),
+            self.format_alert_table(alert),
+        ]));
+        csv
+    }
+
+    fn format_balance_summary_with_stats_csv(&self, balances: &HashMap<String, u64>, stats: &HashMap<String, AddressStats>) -> String {
+        let empty = AddressStats::default();
+        let mut csv = String::from("Address,Balance,Balance_SUI,Total_Sent,Total_Received,Net_Flow\n");
+        for (address, balance) in Self::sorted_balances(balances) {
+            let address_stats = stats.get(address).unwrap_or(&empty);
+            csv.push_str(&Self::csv_row(&[
+                address.clone(),
+                balance.to_string(),
+                self.format_sui_value(*balance, false),
+                address_stats.total_sent.to_string(),
+                address_stats.total_received.to_string(),
+                address_stats.net_flow().to_string(),
+            ]));
+        }
+        csv
     }
 
     fn format_balance_summary_csv(&self, balances: &HashMap<String, u64>) -> String {
         let mut csv = String::from("Address,Balance,Balance_SUI\n");
         for (address, balance) in balances {
-            csv.push_str(&format!(
-                "{},{},{:.9}\n",
-                address,
-                balance,
-                *balance as f64 / 1_000_000_000.0
-            ));
+            csv.push_str(&Self::csv_row(&[
+                address.clone(),
+                balance.to_string(),
+                self.format_sui_value(*balance, false),
+            ]));
         }
         csv
     }
 
     fn format_transaction_history_csv(&self, transactions: &[Transaction]) -> String {
-        let mut csv = String::from("ID,Sender,Recipient,Amount,Amount_SUI,Token_Type,Timestamp,Block_Number,Gas_Used,Gas_Price,Status\n");
+        self.format_transactions_csv(transactions, true)
+    }
+
+    /// 交易列表的CSV行，追加导出时可省略表头
+    pub fn format_transactions_csv(&self, transactions: &[Transaction], include_header: bool) -> String {
+        let mut csv = String::new();
+        if include_header {
+            csv.push_str(TRANSACTION_HISTORY_CSV_HEADER);
+        }
         for tx in transactions {
-            csv.push_str(&format!(
-                "{},{},{},{},{:.9},{},{},{},{},{},{}\n",
-                tx.id,
-                tx.sender,
-                tx.recipient,
-                tx.amount,
-                tx.amount as f64 / 1_000_000_000.0,
-                tx.token_type,
-                tx.timestamp,
-                tx.block_number,
-                tx.gas_used.unwrap_or(0),
-                tx.gas_price.unwrap_or(0),
-                self.format_status(&tx.status)
-            ));
+            csv.push_str(&self.transaction_history_csv_row(tx));
         }
         csv
     }
 
+    /// 交易历史CSV的单行，列与 `TRANSACTION_HISTORY_CSV_HEADER` 对应
+    pub fn transaction_history_csv_row(&self, tx: &Transaction) -> String {
+        Self::csv_row(&[
+            tx.id.clone(),
+            tx.sender.clone(),
+            tx.recipient.clone(),
+            tx.amount.to_string(),
+            self.format_sui_value(tx.amount, false),
+            tx.token_type.clone(),
+            tx.timestamp.to_string(),
+            tx.block_number.to_string(),
+            tx.gas_used.unwrap_or(0).to_string(),
+            tx.gas_price.unwrap_or(0).to_string(),
+            self.format_status(&tx.status),
+        ])
+    }
+
     fn format_address_stats_csv(&self, address: &str, stats: &AddressStats) -> String {
-        format!(
-            "Address,Total_Transactions,Total_Sent,Total_Received,Avg_Transaction,Largest_Transaction,Smallest_Transaction,First_Transaction,Last_Transaction\n{},{},{},{},{:.9},{:.9},{},{},{}\n",
-            address,
-            stats.total_transactions,
-            stats.total_sent,
-            stats.total_received,
-            stats.average_transaction_amount as f64 / 1_000_000_000.0,
-            stats.largest_transaction as f64 / 1_000_000_000.0,
+        let mut csv = String::from("Address,Total_Transactions,Total_Sent,Total_Received,Avg_Transaction,Largest_Transaction,Smallest_Transaction,First_Transaction,Last_Transaction,Total_Gas_Spent\n");
+        csv.push_str(&Self::csv_row(&[
+            address.to_string(),
+            stats.total_transactions.to_string(),
+            stats.total_sent.to_string(),
+            stats.total_received.to_string(),
+            format!("{:.9}", mist_to_sui(stats.average_transaction_amount, SUI_DECIMALS)),
+            format!("{:.9}", mist_to_sui(stats.largest_transaction, SUI_DECIMALS)),
             if stats.smallest_transaction == u64::MAX {
                 "N/A".to_string()
             } else {
                 stats.smallest_transaction.to_string()
             },
-            stats.first_transaction.unwrap_or(0),
-            stats.last_transaction.unwrap_or(0)
-        )
+            stats.first_transaction.unwrap_or(0).to_string(),
+            stats.last_transaction.unwrap_or(0).to_string(),
+            stats.total_gas_spent.to_string(),
+        ]));
+        csv
     }
 
     fn format_system_stats_csv(&self, stats: &ProcessorStats) -> String {
-        format!(
-            "Total Addresses,Total Transactions,Total Volume,Total Volume SUI,Max History Records,Cleanup Interval Hours\n{},{}.{:09},{:.9},{},{}\n",
-            stats.total_addresses,
-            stats.total_transactions,
-            stats.total_volume,
-            stats.total_volume as f64 / 1_000_000_000.0,
-            stats.config.max_history_records,
-            stats.config.cleanup_interval_hours
-        )
+        let mut csv = String::from("Total Addresses,Total Transactions,Total Volume,Total Volume SUI,Max History Records,Evicted Count,Cleanup Interval Hours,Processed Events,Processing Mean Ns,Processing P95 Ns,Processing Max Ns\n");
+        csv.push_str(&Self::csv_row(&[
+            stats.total_addresses.to_string(),
+            stats.total_transactions.to_string(),
+            stats.total_volume.to_string(),
+            format!("{:.9}", mist_to_sui(stats.total_volume, SUI_DECIMALS)),
+            stats.config.max_history_records.to_string(),
+            stats.evicted_count.to_string(),
+            stats.config.cleanup_interval_hours.to_string(),
+            stats.processing_time.count.to_string(),
+            stats.processing_time.mean_ns().to_string(),
+            stats.processing_time.p95_ns.to_string(),
+            stats.processing_time.max_ns.to_string(),
+        ]));
+        csv
     }
 
     fn format_alert_summary_csv(&self, alert_stats: &AlertStats) -> String {
@@ -575,20 +1094,265 @@ impl OutputFormatter {
         
         csv.push_str("Alerts by Type\n");
         for (alert_type, count) in &alert_stats.alerts_by_type {
-            csv.push_str(&format!("{},{}\n", alert_type, count));
+            csv.push_str(&Self::csv_row(&[alert_type.clone(), count.to_string()]));
         }
         
         csv.push_str("Alerts by Severity\n");
         for (severity, count) in &alert_stats.alerts_by_severity {
-            csv.push_str(&format!("{},{}\n", severity, count));
+            csv.push_str(&Self::csv_row(&[severity.clone(), count.to_string()]));
         }
         
         csv
     }
 
+    fn format_address_list_csv(&self, entries: &[AddressListEntry]) -> String {
+        let mut csv = String::from("Address,Balance,Balance_SUI,Total_Transactions,Label\n");
+        for entry in entries {
+            csv.push_str(&Self::csv_row(&[
+                entry.address.clone(),
+                entry.balance.to_string(),
+                self.format_sui_value(entry.balance, false),
+                entry.total_transactions.to_string(),
+                entry.label.clone().unwrap_or_default(),
+            ]));
+        }
+        csv
+    }
+
+    fn format_final_stats_csv(&self, stats: &TrackerStats, processor_stats: &ProcessorStats) -> String {
+        let mut csv = String::from("Uptime_Seconds,Events_Processed,Transactions_Processed,Alerts_Sent,Errors,Addresses_Monitored,Balances_Reconciled,Total_Balance_Drift,Events_Filtered,Events_Queued,Event_Backpressure_Waits,Alerts_Queued,Alerts_Dropped,Processor_Addresses,Total_Volume\n");
+        csv.push_str(&Self::csv_row(&[
+            stats.uptime_seconds.to_string(),
+            stats.total_events_processed.to_string(),
+            stats.total_transactions_processed.to_string(),
+            stats.total_alerts_sent.to_string(),
+            stats.total_errors.to_string(),
+            stats.addresses_monitored.to_string(),
+            stats.balances_reconciled.to_string(),
+            stats.total_balance_drift.to_string(),
+            stats.events_filtered.to_string(),
+            stats.events_queued.to_string(),
+            stats.event_backpressure_waits.to_string(),
+            stats.alerts_queued.to_string(),
+            stats.alerts_dropped.to_string(),
+            processor_stats.total_addresses.to_string(),
+            processor_stats.total_volume.to_string(),
+        ]));
+        csv
+    }
+
+    /// 交易量时间序列，每行一个桶（Unix 时间戳秒, 交易量 MIST），供图表工具导入
+    pub fn format_volume_timeseries_csv(series: &[(u64, u64)]) -> String {
+        let mut csv = String::from("timestamp,volume\n");
+        for (timestamp, volume) in series {
+            csv.push_str(&format!("{},{}\n", timestamp, volume));
+        }
+        csv
+    }
+
+    fn format_top_addresses_csv(&self, ranking: &[(String, u64)]) -> String {
+        let mut csv = String::from("Rank,Address,Volume,Volume_SUI\n");
+        for (rank, (address, volume)) in ranking.iter().enumerate() {
+            csv.push_str(&Self::csv_row(&[
+                (rank + 1).to_string(),
+                address.clone(),
+                volume.to_string(),
+                self.format_sui_value(*volume, false),
+            ]));
+        }
+        csv
+    }
+
+    fn format_group_summary_csv(&self, stats: &GroupStats) -> String {
+        let mut csv = String::from("Group,Addresses,Total_Balance,Total_Transactions,Total_Sent,Total_Received,Total_Gas_Spent\n");
+        csv.push_str(&Self::csv_row(&[
+            stats.name.clone(),
+            stats.addresses.to_string(),
+            stats.total_balance.to_string(),
+            stats.total_transactions.to_string(),
+            stats.total_sent.to_string(),
+            stats.total_received.to_string(),
+            stats.total_gas_spent.to_string(),
+        ]));
+        csv
+    }
+
+    fn format_coin_report_csv(&self, report: &CoinReport) -> String {
+        let mut csv = String::from("Address,Coin_Type,Coin_Count,Total_Balance,Largest_Coin,Smallest_Coin,Fragmentation_Score\n");
+        csv.push_str(&Self::csv_row(&[
+            report.address.clone(),
+            report.coin_type.clone(),
+            report.coin_count.to_string(),
+            report.total_balance.to_string(),
+            report.largest_coin.to_string(),
+            report.smallest_coin.to_string(),
+            format!("{:.4}", report.fragmentation_score),
+        ]));
+        csv
+    }
+
+    fn format_rpc_latency_stats_csv(&self, stats: &[RpcLatencyStats]) -> String {
+        let mut csv = String::from("Method,Count,Min_Ms,P50_Ms,P95_Ms,Max_Ms\n");
+        for entry in stats {
+            csv.push_str(&Self::csv_row(&[
+                entry.method.clone(),
+                entry.count.to_string(),
+                entry.min.as_millis().to_string(),
+                entry.p50.as_millis().to_string(),
+                entry.p95.as_millis().to_string(),
+                entry.max.as_millis().to_string(),
+            ]));
+        }
+        csv
+    }
+
+    /// 每个余额变化一行，没有余额变化时输出一行空的余额字段
+    fn format_sui_transaction_csv(&self, transaction: &SuiTransaction) -> String {
+        let mut csv = String::from("Digest,Timestamp,Status,Gas_Used,Owner,Coin_Type,Amount\n");
+        let row = |owner: String, coin_type: String, amount: String| {
+            Self::csv_row(&[
+                transaction.digest.clone(),
+                transaction.timestamp.map(|t| t.to_rfc3339()).unwrap_or_default(),
+                format!("{:?}", transaction.status),
+                transaction.gas_used.clone().unwrap_or_default(),
+                owner,
+                coin_type,
+                amount,
+            ])
+        };
+
+        if transaction.balance_changes.is_empty() {
+            csv.push_str(&row(String::new(), String::new(), String::new()));
+        }
+        for change in &transaction.balance_changes {
+            csv.push_str(&row(change.owner.clone(), change.coin_type.clone(), change.amount.to_string()));
+        }
+        csv
+    }
+
+    /// 以合适的单位显示纳秒耗时
+    fn format_nanos(ns: u64) -> String {
+        if ns >= 1_000_000_000 {
+            format!("{:.2} s", ns as f64 / 1e9)
+        } else if ns >= 1_000_000 {
+            format!("{:.2} ms", ns as f64 / 1e6)
+        } else if ns >= 1_000 {
+            format!("{:.2} µs", ns as f64 / 1e3)
+        } else {
+            format!("{} ns", ns)
+        }
+    }
+
+    /// 按 RFC 4180 拼接一行CSV（以换行结尾）
+    fn csv_row(fields: &[String]) -> String {
+        let mut row = fields
+            .iter()
+            .map(|field| Self::escape_csv_field(field))
+            .collect::<Vec<_>>()
+            .join(",");
+        row.push('\n');
+        row
+    }
+
+    /// 字段包含逗号、双引号或换行时用双引号包裹，内部双引号加倍
+    pub fn escape_csv_field(field: &str) -> Cow<'_, str> {
+        if field.contains([',', '"', '\n', '\r']) {
+            Cow::Owned(format!("\"{}\"", field.replace('"', "\"\"")))
+        } else {
+            Cow::Borrowed(field)
+        }
+    }
+
     // Helper methods
     pub fn format_amount(&self, amount: u64) -> String {
-        format!("{:.9} SUI", amount as f64 / 1_000_000_000.0)
+        match self.format_usd_value(amount).filter(|_| self.config.show_usd_value) {
+            Some(usd) => format!("{} SUI (≈ {})", self.format_sui_value(amount, true), usd),
+            None => format!("{} SUI", self.format_sui_value(amount, true)),
+        }
+    }
+
+    /// 更新SUI/USD价格，None 表示价格不可用（不再显示美元估值）
+    pub fn set_usd_price(&self, price: Option<f64>) {
+        *self.usd_price.write().unwrap() = price;
+    }
+
+    pub fn usd_price(&self) -> Option<f64> {
+        *self.usd_price.read().unwrap()
+    }
+
+    /// 金额的美元估值，如 `$12.34`；价格未知时返回 None
+    pub fn format_usd_value(&self, amount: u64) -> Option<String> {
+        let price = self.usd_price()?;
+        let usd = format!("{:.2}", mist_to_sui(amount, SUI_DECIMALS) * price);
+        let usd = if self.config.group_digits { Self::group_thousands(&usd) } else { usd };
+        Some(format!("${}", usd))
+    }
+
+    /// 带符号的SUI数值，正数前加 `+`
+    fn format_signed_sui_value(&self, amount: i64) -> String {
+        let sign = if amount > 0 { "+" } else if amount < 0 { "-" } else { "" };
+        format!("{}{}", sign, self.format_sui_value(amount.unsigned_abs(), true))
+    }
+
+    /// 带符号的金额（净流入/流出）
+    pub fn format_signed_amount(&self, amount: i64) -> String {
+        let sign = if amount > 0 { "+" } else if amount < 0 { "-" } else { "" };
+        format!("{}{}", sign, self.format_amount(amount.unsigned_abs()))
+    }
+
+    /// 将MIST金额转换为SUI数值字符串
+    ///
+    /// `human_readable` 为 true 时应用 `compact` / `group_digits` 设置（compact 优先）；
+    /// 为 false 时输出不带分隔符的完整精度数值，供CSV等需要可解析的输出使用。
+    pub fn format_sui_value(&self, amount: u64, human_readable: bool) -> String {
+        let sui = mist_to_sui(amount, SUI_DECIMALS);
+
+        if !human_readable {
+            return format!("{:.9}", sui);
+        }
+
+        if self.config.compact {
+            if let Some(compact) = Self::compact_notation(sui) {
+                return compact;
+            }
+        }
+
+        let formatted = format!("{:.*}", self.config.display_decimals as usize, sui);
+        if self.config.group_digits {
+            Self::group_thousands(&formatted)
+        } else {
+            formatted
+        }
+    }
+
+    fn compact_notation(value: f64) -> Option<String> {
+        const UNITS: [(f64, &str); 3] = [
+            (1_000_000_000.0, "B"),
+            (1_000_000.0, "M"),
+            (1_000.0, "K"),
+        ];
+
+        UNITS
+            .iter()
+            .find(|(scale, _)| value >= *scale)
+            .map(|(scale, suffix)| format!("{:.1}{}", value / scale, suffix))
+    }
+
+    fn group_thousands(number: &str) -> String {
+        let (integer, fraction) = match number.find('.') {
+            Some(pos) => number.split_at(pos),
+            None => (number, ""),
+        };
+
+        let mut grouped = String::with_capacity(number.len() + integer.len() / 3);
+        for (i, c) in integer.chars().enumerate() {
+            if i > 0 && (integer.len() - i) % 3 == 0 {
+                grouped.push(',');
+            }
+            grouped.push(c);
+        }
+        grouped.push_str(fraction);
+        grouped
     }
 
     fn format_token_type(&self, token_type: &str) -> String {
@@ -624,12 +1388,12 @@ impl OutputFormatter {
     }
 
     fn get_transaction_color(&self, transaction: &Transaction) -> String {
-        let amount_sui = transaction.amount as f64 / 1_000_000_000.0;
-        if amount_sui > 10.0 {
+        let amount = transaction.amount;
+        if amount > self.config.color_tier_high {
             "\x1b[33m" // Yellow for large transactions
-        } else if amount_sui > 1.0 {
+        } else if amount > self.config.color_tier_medium {
             "\x1b[32m" // Green for medium transactions
-        } else if amount_sui > 0.1 {
+        } else if amount > self.config.color_tier_low {
             "\x1b[36m" // Cyan for small transactions
         } else {
             "\x1b[37m" // White for very small transactions
@@ -646,11 +1410,14 @@ impl OutputFormatter {
         if self.use_colors {
             message.push_str("\x1b[1;32m"); // Bright green
         }
-        message.push_str("SUI Token Transfer Tracker\n");
+        let messages = self.messages();
+        message.push_str(&messages.welcome_title());
+        message.push('\n');
         if self.use_colors {
             message.push_str("\x1b[0m"); // Reset
         }
-        message.push_str("Real-time monitoring of SUI blockchain transfers\n");
+        message.push_str(&messages.welcome_subtitle());
+        message.push('\n');
         message
     }
 
@@ -670,6 +1437,27 @@ impl OutputFormatter {
         }
     }
 
+    /// 导出结果描述，如 "Exported 1,234 transactions (512 KB) to export.csv"
+    pub fn format_export_summary(summary: &ExportSummary, records_label: &str, path: &str) -> String {
+        format!(
+            "Exported {} {} ({}) to {}",
+            Self::group_thousands(&summary.records.to_string()),
+            records_label,
+            Self::format_byte_size(summary.bytes),
+            path
+        )
+    }
+
+    fn format_byte_size(bytes: usize) -> String {
+        const UNITS: [(usize, &str); 3] = [(1 << 30, "GB"), (1 << 20, "MB"), (1 << 10, "KB")];
+
+        match UNITS.iter().find(|(scale, _)| bytes >= *scale) {
+            Some((scale, unit)) if bytes.is_multiple_of(*scale) => format!("{} {}", bytes / scale, unit),
+            Some((scale, unit)) => format!("{:.1} {}", bytes as f64 / *scale as f64, unit),
+            None => format!("{} B", bytes),
+        }
+    }
+
     pub fn format_success(&self, message: &str) -> String {
         if self.use_colors {
             format!("\x1b[32m✓ {}\x1b[0m", message)
@@ -687,6 +1475,101 @@ impl OutputFormatter {
     }
 }
 
+/// 运行时输出的去向
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputDestination {
+    Stdout,
+    File,
+    /// 同时写入标准输出和文件（未配置文件时只写标准输出）
+    #[default]
+    Both,
+}
+
+/// 格式化后的输出写入标准输出和/或文件，写入文件时去掉颜色代码
+#[derive(Debug)]
+pub struct OutputSink {
+    to_stdout: bool,
+    file: Option<std::sync::Mutex<std::fs::File>>,
+}
+
+impl Default for OutputSink {
+    fn default() -> Self {
+        Self::stdout()
+    }
+}
+
+impl OutputSink {
+    pub fn stdout() -> Self {
+        Self { to_stdout: true, file: None }
+    }
+
+    /// 按配置打开输出文件（追加写入）
+    pub fn open(destination: OutputDestination, output_file: Option<&str>) -> TrackerResult<Self> {
+        let file = match (destination, output_file) {
+            (OutputDestination::Stdout, _) | (OutputDestination::Both, None) => None,
+            (OutputDestination::File, None) => {
+                return Err(TrackerError::validation_error(
+                    "output.output_file must be set when output_destination is \"file\""
+                ));
+            }
+            (_, Some(path)) => Some(std::sync::Mutex::new(
+                std::fs::OpenOptions::new().create(true).append(true).open(path)?
+            )),
+        };
+
+        Ok(Self {
+            to_stdout: destination != OutputDestination::File,
+            file,
+        })
+    }
+
+    /// 标准输出是否会显示运行时输出
+    pub fn writes_stdout(&self) -> bool {
+        self.to_stdout
+    }
+
+    pub fn write(&self, output: &str) {
+        if self.to_stdout {
+            println!("{}", output);
+        }
+        self.write_file(output);
+    }
+
+    /// 只写入文件（已由其他途径显示在控制台的内容，如控制台警报）
+    pub fn write_file(&self, output: &str) {
+        if let Some(file) = &self.file {
+            let mut file = file.lock().unwrap();
+            if let Err(e) = writeln!(file, "{}", strip_ansi_codes(output)) {
+                log::error!("Failed to write output file: {}", e);
+            }
+        }
+    }
+}
+
+/// 去掉 ANSI 颜色代码
+fn strip_ansi_codes(s: &str) -> Cow<'_, str> {
+    if !s.contains('\x1b') {
+        return Cow::Borrowed(s);
+    }
+
+    let mut result = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            // 跳过 ESC [ ... 终止字母
+            for c in chars.by_ref() {
+                if c.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+        } else {
+            result.push(c);
+        }
+    }
+    Cow::Owned(result)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -705,6 +1588,45 @@ mod tests {
         assert_eq!(formatter.format_amount(500000000), "0.500000000 SUI");
     }
 
+    #[test]
+    fn test_format_amount_with_decimals() {
+        let formatter = OutputFormatter::with_config(OutputConfig {
+            display_decimals: 2,
+            ..Default::default()
+        });
+        assert_eq!(formatter.format_amount(1_500_000_000), "1.50 SUI");
+        assert_eq!(formatter.format_amount(1_999_000_000), "2.00 SUI"); // 四舍五入而非截断
+
+        let formatter = OutputFormatter::with_config(OutputConfig {
+            display_decimals: 0,
+            ..Default::default()
+        });
+        assert_eq!(formatter.format_amount(2_000_000_000), "2 SUI");
+    }
+
+    #[test]
+    fn test_format_amount_grouping_and_compact() {
+        let formatter = OutputFormatter::with_config(OutputConfig {
+            display_decimals: 2,
+            group_digits: true,
+            ..Default::default()
+        });
+        assert_eq!(formatter.format_amount(1_234_567_890_000_000_000), "1,234,567,890.00 SUI");
+        assert_eq!(formatter.format_amount(999_000_000_000), "999.00 SUI");
+
+        let formatter = OutputFormatter::with_config(OutputConfig {
+            group_digits: true,
+            compact: true,
+            ..Default::default()
+        });
+        assert_eq!(formatter.format_amount(1_200_000_000_000_000), "1.2M SUI");
+        assert_eq!(formatter.format_amount(3_400_000_000_000), "3.4K SUI");
+        assert_eq!(formatter.format_amount(500_000_000), "0.500000000 SUI");
+
+        // CSV路径保持原始数值
+        assert_eq!(formatter.format_sui_value(3_400_000_000_000, false), "3400.000000000");
+    }
+
     #[test]
     fn test_truncate_address() {
         let formatter = OutputFormatter::new(false, false);
@@ -735,6 +1657,12 @@ mod tests {
         let message = formatter.format_welcome_message();
         assert!(message.contains("SUI Token Transfer Tracker"));
         assert!(message.contains("Real-time monitoring"));
+
+        let formatter = OutputFormatter::with_config(OutputConfig {
+            language: Language::Zh,
+            ..Default::default()
+        });
+        assert!(formatter.format_welcome_message().contains("SUI 代币转账追踪器"));
     }
 
     #[test]
@@ -754,4 +1682,278 @@ mod tests {
         let success_msg = formatter.format_success("Operation completed");
         assert!(success_msg.contains("✓ Operation completed"));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_transaction_csv_quoting_round_trip() {
+        let mut formatter = OutputFormatter::with_config(OutputConfig {
+            csv_header: true,
+            ..Default::default()
+        });
+        formatter.set_format(OutputFormat::Csv);
+
+        let transaction = Transaction {
+            id: "0xabc".to_string(),
+            sender: "0xsender".to_string(),
+            recipient: "0xrecipient".to_string(),
+            amount: 1_500_000_000,
+            token_type: "0x2::coin::Coin<0x2::pair::Pair<A, \"B\">>".to_string(),
+            timestamp: 1640995200,
+            block_number: 7,
+            gas_used: Some(1000),
+            gas_price: None,
+            status: crate::transaction_processor::TransactionStatus::Success,
+            network: String::new(),
+        };
+
+        let csv = formatter.format_transaction(&transaction);
+        let mut reader = csv::Reader::from_reader(csv.as_bytes());
+
+        let headers = reader.headers().unwrap().clone();
+        assert_eq!(headers.len(), 10);
+        assert_eq!(&headers[5], "Token_Type");
+
+        let records: Vec<csv::StringRecord> = reader.records().map(|r| r.unwrap()).collect();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].len(), 10);
+        assert_eq!(&records[0][0], "0xabc");
+        assert_eq!(&records[0][3], "1500000000");
+        assert_eq!(&records[0][5], transaction.token_type);
+        assert_eq!(&records[0][6], "1640995200");
+
+        // 未开启表头时只输出数据行
+        let mut formatter = OutputFormatter::new(false, false);
+        formatter.set_format(OutputFormat::Csv);
+        assert!(formatter.format_transaction(&transaction).starts_with("0xabc,"));
+    }
+
+    #[test]
+    fn test_transaction_history_json_matches_transaction_json() {
+        let transaction = Transaction {
+            id: "0xabc".to_string(),
+            sender: "0xsender".to_string(),
+            recipient: "0xrecipient".to_string(),
+            amount: 1_500_000_000,
+            token_type: "0x2::sui::SUI".to_string(),
+            timestamp: 1640995200,
+            block_number: 7,
+            gas_used: None,
+            gas_price: None,
+            status: crate::transaction_processor::TransactionStatus::Success,
+            network: "testnet".to_string(),
+        };
+        let mut formatter = OutputFormatter::new(false, false);
+        formatter.set_format(OutputFormat::Json);
+
+        let history: serde_json::Value = serde_json::from_str(&formatter.format_transaction_history(&[transaction.clone()])).unwrap();
+        assert_eq!(history["transactions"][0], formatter.transaction_json_value(&transaction));
+        assert_eq!(history["transactions"][0]["network"], "testnet");
+        assert_eq!(history["total_count"], 1);
+    }
+
+    #[test]
+    fn test_transaction_color_tiers() {
+        let mut transaction = Transaction {
+            id: "0xabc".to_string(),
+            sender: "0xsender".to_string(),
+            recipient: "0xrecipient".to_string(),
+            amount: 5_000_000_000,
+            token_type: "0x2::sui::SUI".to_string(),
+            timestamp: 1640995200,
+            block_number: 7,
+            gas_used: None,
+            gas_price: None,
+            status: crate::transaction_processor::TransactionStatus::Success,
+            network: String::new(),
+        };
+
+        // 默认分档：5 SUI 属于中档
+        let formatter = OutputFormatter::new(true, false);
+        assert_eq!(formatter.get_transaction_color(&transaction), "\x1b[32m");
+
+        let formatter = OutputFormatter::with_config(OutputConfig {
+            color_tier_high: 1_000,
+            color_tier_medium: 100,
+            color_tier_low: 10,
+            ..Default::default()
+        });
+        assert_eq!(formatter.get_transaction_color(&transaction), "\x1b[33m");
+        transaction.amount = 50;
+        assert_eq!(formatter.get_transaction_color(&transaction), "\x1b[36m");
+        transaction.amount = 10;
+        assert_eq!(formatter.get_transaction_color(&transaction), "\x1b[37m");
+
+        // 表格输出中金额带有分档颜色，关闭颜色时没有转义序列
+        transaction.amount = 5_000;
+        let line = formatter.format_transaction(&transaction);
+        assert!(line.contains(&format!("\x1b[33m{}\x1b[0m", formatter.format_amount(5_000))));
+        let plain = OutputFormatter::new(false, false).format_transaction(&transaction);
+        assert!(!plain.contains('\x1b'));
+    }
+
+    #[test]
+    fn test_json_pretty_toggle() {
+        let stats = AlertStats {
+            total_alerts: 1,
+            alerts_by_type: HashMap::new(),
+            alerts_by_severity: HashMap::new(),
+        };
+
+        let mut formatter = OutputFormatter::new(false, false);
+        formatter.set_format(OutputFormat::Json);
+        let compact = formatter.format_alert_summary(&stats);
+        assert!(!compact.contains('\n'));
+        assert!(!compact.contains("  "));
+
+        let mut formatter = OutputFormatter::with_config(OutputConfig {
+            json_pretty: true,
+            ..Default::default()
+        });
+        formatter.set_format(OutputFormat::Json);
+        let pretty = formatter.format_alert_summary(&stats);
+        assert!(pretty.contains("\n  \"total_alerts\": 1"));
+
+        let compact: serde_json::Value = serde_json::from_str(&compact).unwrap();
+        let pretty: serde_json::Value = serde_json::from_str(&pretty).unwrap();
+        assert_eq!(compact, pretty);
+    }
+
+    #[test]
+    fn test_format_export_summary() {
+        let summary = ExportSummary {
+            records: 1234,
+            bytes: 512 * 1024,
+            format: crate::transaction_processor::ExportFormat::Csv,
+        };
+        assert_eq!(
+            OutputFormatter::format_export_summary(&summary, "transactions", "export.csv"),
+            "Exported 1,234 transactions (512 KB) to export.csv"
+        );
+
+        let summary = ExportSummary { records: 3, bytes: 1536, ..summary };
+        assert_eq!(
+            OutputFormatter::format_export_summary(&summary, "addresses", "out.json"),
+            "Exported 3 addresses (1.5 KB) to out.json"
+        );
+        assert_eq!(OutputFormatter::format_byte_size(200), "200 B");
+    }
+
+    #[test]
+    fn test_format_amount_usd_value() {
+        let formatter = OutputFormatter::with_config(OutputConfig {
+            display_decimals: 2,
+            show_usd_value: true,
+            ..Default::default()
+        });
+
+        // 价格未知时不显示美元估值
+        assert_eq!(formatter.format_amount(2 * MIST_PER_SUI), "2.00 SUI");
+
+        formatter.set_usd_price(Some(1.5));
+        assert_eq!(formatter.format_amount(2 * MIST_PER_SUI), "2.00 SUI (≈ $3.00)");
+        assert_eq!(formatter.format_usd_value(MIST_PER_SUI / 3).as_deref(), Some("$0.50"));
+
+        // 未开启 show_usd_value 时金额格式不变
+        let plain = OutputFormatter::with_config(OutputConfig { display_decimals: 2, ..Default::default() });
+        plain.set_usd_price(Some(1.5));
+        assert_eq!(plain.format_amount(2 * MIST_PER_SUI), "2.00 SUI");
+    }
+
+    #[test]
+    fn test_balance_summary_top_n() {
+        let formatter = OutputFormatter::new(false, false);
+        let balances: HashMap<String, u64> = (1..=5u64)
+            .map(|i| (format!("0x{:064x}", i), i * 1_000_000_000))
+            .collect();
+
+        let summary = formatter.format_balance_summary_top(&balances, Some(2));
+        assert!(summary.contains("5.000000000"));
+        assert!(summary.contains("4.000000000"));
+        assert!(!summary.contains("3.000000000"));
+        assert!(summary.contains("... and 3 more addresses"));
+
+        let full = formatter.format_balance_summary(&balances);
+        assert!(full.contains("1.000000000"));
+        assert!(!full.contains("more addresses"));
+    }
+
+    #[test]
+    fn test_balance_summary_with_stats() {
+        let formatter = OutputFormatter::new(false, false);
+        let active = format!("0x{}", "a".repeat(64));
+        let idle = format!("0x{}", "b".repeat(64));
+        let balances: HashMap<String, u64> = [(active.clone(), 3_000_000_000), (idle.clone(), 1_000_000_000)].into();
+        let stats: HashMap<String, AddressStats> = [(active.clone(), AddressStats {
+            total_sent: 2_000_000_000,
+            total_received: 5_000_000_000,
+            ..Default::default()
+        })].into();
+
+        let table = formatter.format_balance_summary_with_stats(&balances, &stats);
+        let active_row = table.lines().find(|line| line.contains("3.000000000")).unwrap();
+        assert!(active_row.contains("2.000000000"));
+        assert!(active_row.contains("5.000000000"));
+        assert!(active_row.contains("+3.000000000"));
+
+        let mut formatter = formatter;
+        formatter.set_format(OutputFormat::Csv);
+        let csv = formatter.format_balance_summary_with_stats(&balances, &stats);
+        let mut lines = csv.lines();
+        assert_eq!(lines.next(), Some("Address,Balance,Balance_SUI,Total_Sent,Total_Received,Net_Flow"));
+        assert_eq!(lines.next(), Some(format!("{},3000000000,3.000000000,2000000000,5000000000,3000000000", active).as_str()));
+        assert_eq!(lines.next(), Some(format!("{},1000000000,1.000000000,0,0,0", idle).as_str()));
+    }
+
+    #[test]
+    fn test_format_address_list_json() {
+        let mut formatter = OutputFormatter::new(false, false);
+        formatter.set_format(OutputFormat::Json);
+
+        let entries = vec![AddressListEntry {
+            address: "0xabc".to_string(),
+            balance: 2_000_000_000,
+            total_transactions: 3,
+            label: Some("treasury".to_string()),
+        }];
+
+        let parsed: serde_json::Value = serde_json::from_str(&formatter.format_address_list(&entries)).unwrap();
+        let list = parsed.as_array().unwrap();
+        assert_eq!(list.len(), 1);
+        assert_eq!(list[0]["address"], "0xabc");
+        assert_eq!(list[0]["balance"], 2_000_000_000u64);
+        assert_eq!(list[0]["total_transactions"], 3);
+        assert_eq!(list[0]["label"], "treasury");
+    }
+
+    #[test]
+    fn test_format_volume_timeseries_csv() {
+        let csv = OutputFormatter::format_volume_timeseries_csv(&[(3600, 1_000), (7200, 0)]);
+        assert_eq!(csv, "timestamp,volume\n3600,1000\n7200,0\n");
+        assert_eq!(OutputFormatter::format_volume_timeseries_csv(&[]), "timestamp,volume\n");
+    }
+
+    #[test]
+    fn test_escape_csv_field() {
+        assert_eq!(OutputFormatter::escape_csv_field("plain"), "plain");
+        assert_eq!(OutputFormatter::escape_csv_field("a,b"), "\"a,b\"");
+        assert_eq!(OutputFormatter::escape_csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(OutputFormatter::escape_csv_field("line\nbreak"), "\"line\nbreak\"");
+    }
+
+    #[test]
+    fn test_output_sink_file() {
+        let path = std::env::temp_dir().join(format!("tracker_output_{}.log", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let sink = OutputSink::open(OutputDestination::File, path.to_str()).unwrap();
+        assert!(!sink.writes_stdout());
+        sink.write("\x1b[32m✓ added\x1b[0m");
+        sink.write_file("plain line");
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(content, "✓ added\nplain line\n");
+        let _ = std::fs::remove_file(&path);
+
+        assert!(OutputSink::open(OutputDestination::File, None).is_err());
+        assert!(OutputSink::open(OutputDestination::Both, None).unwrap().writes_stdout());
+    }
+}
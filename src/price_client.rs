@@ -0,0 +1,123 @@
+use crate::config::PriceConfig;
+use crate::error::{TrackerError, TrackerResult};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// SUI/USD 价格源，按 TTL 缓存最近一次查询结果
+///
+/// 价格从 `feed_url` 返回的JSON中按 `json_pointer` 取出，默认格式为 CoinGecko 的
+/// `{"sui": {"usd": 1.23}}`。数值或字符串形式的价格都可以解析。
+#[derive(Debug)]
+pub struct PriceClient {
+    feed_url: String,
+    json_pointer: String,
+    cache_ttl: Duration,
+    http_client: reqwest::Client,
+    cache: Mutex<Option<(Instant, f64)>>,
+}
+
+impl PriceClient {
+    /// 未配置 `feed_url` 时返回 None
+    pub fn from_config(config: &PriceConfig) -> TrackerResult<Option<Self>> {
+        if config.feed_url.is_empty() {
+            return Ok(None);
+        }
+
+        let http_client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(config.timeout_seconds))
+            .build()
+            .map_err(|e| TrackerError::network_error(format!("Failed to build price feed client: {}", e)))?;
+
+        Ok(Some(Self {
+            feed_url: config.feed_url.clone(),
+            json_pointer: config.json_pointer.clone(),
+            cache_ttl: Duration::from_secs(config.cache_ttl_seconds),
+            http_client,
+            cache: Mutex::new(None),
+        }))
+    }
+
+    /// 缓存时间，也是后台刷新价格的间隔
+    pub fn cache_ttl(&self) -> Duration {
+        self.cache_ttl
+    }
+
+    /// 当前 SUI/USD 价格；价格源不可用时返回 None，不影响调用方
+    pub async fn get_sui_price(&self) -> Option<f64> {
+        if let Some((fetched_at, price)) = *self.cache.lock().unwrap() {
+            if fetched_at.elapsed() < self.cache_ttl {
+                return Some(price);
+            }
+        }
+        self.refresh().await
+    }
+
+    /// 忽略缓存重新查询价格并更新缓存
+    pub async fn refresh(&self) -> Option<f64> {
+        match self.fetch_price().await {
+            Ok(price) => {
+                *self.cache.lock().unwrap() = Some((Instant::now(), price));
+                Some(price)
+            }
+            Err(e) => {
+                log::warn!("Failed to get SUI price from {}: {}", self.feed_url, e);
+                None
+            }
+        }
+    }
+
+    async fn fetch_price(&self) -> TrackerResult<f64> {
+        let response = self.http_client
+            .get(&self.feed_url)
+            .send()
+            .await
+            .map_err(|e| TrackerError::network_error(format!("Price feed request failed: {}", e)))?;
+
+        if !response.status().is_success() {
+            return Err(TrackerError::network_error(format!("Price feed HTTP error: {}", response.status())));
+        }
+
+        let body: serde_json::Value = response
+            .json()
+            .await
+            .map_err(|e| TrackerError::parse_error(format!("Invalid price feed response: {}", e)))?;
+
+        Self::extract_price(&body, &self.json_pointer).ok_or_else(|| {
+            TrackerError::parse_error(format!("No price at {} in price feed response", self.json_pointer))
+        })
+    }
+
+    /// 按JSON指针取出正的有限价格
+    pub fn extract_price(body: &serde_json::Value, json_pointer: &str) -> Option<f64> {
+        let value = body.pointer(json_pointer)?;
+        let price = match value {
+            serde_json::Value::Number(number) => number.as_f64()?,
+            serde_json::Value::String(text) => text.parse().ok()?,
+            _ => return None,
+        };
+        (price.is_finite() && price > 0.0).then_some(price)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_price() {
+        let coingecko = serde_json::json!({"sui": {"usd": 1.25}});
+        assert_eq!(PriceClient::extract_price(&coingecko, "/sui/usd"), Some(1.25));
+
+        let custom = serde_json::json!({"data": [{"price": "3.5"}]});
+        assert_eq!(PriceClient::extract_price(&custom, "/data/0/price"), Some(3.5));
+
+        assert_eq!(PriceClient::extract_price(&coingecko, "/sui/eur"), None);
+        assert_eq!(PriceClient::extract_price(&serde_json::json!({"sui": {"usd": 0}}), "/sui/usd"), None);
+        assert_eq!(PriceClient::extract_price(&serde_json::json!({"sui": {"usd": null}}), "/sui/usd"), None);
+    }
+
+    #[test]
+    fn test_price_client_disabled_without_url() {
+        assert!(PriceClient::from_config(&PriceConfig::default()).unwrap().is_none());
+    }
+}
@@ -0,0 +1,114 @@
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+/// 按大小轮转的追加写入文件
+///
+/// 写入后超过 `max_bytes` 时，当前文件依次改名为 `<path>.1`、`<path>.2` …，
+/// 最多保留 `keep` 个归档，更早的被删除；`keep` 为 0 时直接清空当前文件。
+/// `max_bytes` 为 0 表示不轮转。
+#[derive(Debug)]
+pub struct RotatingFile {
+    path: PathBuf,
+    max_bytes: u64,
+    keep: u32,
+    file: File,
+    written: u64,
+}
+
+impl RotatingFile {
+    pub fn open(path: impl AsRef<Path>, max_bytes: u64, keep: u32) -> io::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let file = Self::open_append(&path)?;
+        let written = file.metadata()?.len();
+        Ok(Self { path, max_bytes, keep, file, written })
+    }
+
+    /// 以MB为单位设置上限，对应配置中的 `max_file_size_mb`
+    pub fn open_mb(path: impl AsRef<Path>, max_file_size_mb: u32, keep: u32) -> io::Result<Self> {
+        Self::open(path, max_file_size_mb as u64 * 1024 * 1024, keep)
+    }
+
+    fn open_append(path: &Path) -> io::Result<File> {
+        OpenOptions::new().create(true).append(true).open(path)
+    }
+
+    fn archive_path(&self, index: u32) -> PathBuf {
+        let mut name = self.path.as_os_str().to_os_string();
+        name.push(format!(".{}", index));
+        PathBuf::from(name)
+    }
+
+    fn rotate(&mut self) -> io::Result<()> {
+        self.file.flush()?;
+
+        if self.keep == 0 {
+            self.file.set_len(0)?;
+        } else {
+            let oldest = self.archive_path(self.keep);
+            if oldest.exists() {
+                fs::remove_file(&oldest)?;
+            }
+            for index in (1..self.keep).rev() {
+                let from = self.archive_path(index);
+                if from.exists() {
+                    fs::rename(&from, self.archive_path(index + 1))?;
+                }
+            }
+            fs::rename(&self.path, self.archive_path(1))?;
+            self.file = Self::open_append(&self.path)?;
+        }
+
+        self.written = 0;
+        Ok(())
+    }
+}
+
+impl Write for RotatingFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        // 单条记录不拆分到两个文件
+        if self.max_bytes > 0 && self.written > 0 && self.written + buf.len() as u64 > self.max_bytes {
+            self.rotate()?;
+        }
+        let written = self.file.write(buf)?;
+        self.written += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rotation_keeps_configured_archives() {
+        let dir = std::env::temp_dir().join(format!("rotating_file_test_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("tracker.log");
+
+        let mut file = RotatingFile::open(&path, 10, 2).unwrap();
+        for line in ["first-line\n", "second-line\n", "third-line\n", "fourth-line\n"] {
+            file.write_all(line.as_bytes()).unwrap();
+        }
+        file.flush().unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "fourth-line\n");
+        assert_eq!(fs::read_to_string(dir.join("tracker.log.1")).unwrap(), "third-line\n");
+        assert_eq!(fs::read_to_string(dir.join("tracker.log.2")).unwrap(), "second-line\n");
+        assert!(!dir.join("tracker.log.3").exists());
+
+        // 重新打开时接着已有大小计算
+        let mut file = RotatingFile::open(&path, 20, 0).unwrap();
+        file.write_all(b"fifth-line\n").unwrap();
+        file.flush().unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "fifth-line\n");
+        assert_eq!(fs::read_to_string(dir.join("tracker.log.1")).unwrap(), "third-line\n");
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
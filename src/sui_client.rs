@@ -4,11 +4,103 @@ use sui_graphql_client::{
 };
 use sui_sdk_types::Address;
 use crate::error::{TrackerError, TrackerResult};
+use crate::transaction_processor::TransactionStatus;
 use chrono::{DateTime, Utc};
+use std::collections::{HashMap, HashSet};
 use std::str::FromStr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
 use serde::{Deserialize, Serialize};
+use futures::future::BoxFuture;
 use reqwest;
 
+/// 监控和处理流程使用的链上查询接口
+///
+/// `SuiClient` 通过 JSON-RPC 实现；测试中可使用 [`crate::mock_sui_client::MockSuiClient`] 返回预设数据。
+pub trait SuiRpc: Send + Sync + std::fmt::Debug {
+    /// 网络名称，写入查询到的事件
+    fn network_name(&self) -> &str;
+
+    fn get_balance<'a>(&'a self, address: &'a str, coin_type: Option<&'a str>) -> BoxFuture<'a, TrackerResult<u64>>;
+
+    /// 绕过缓存的余额查询，没有缓存的实现直接使用 get_balance
+    fn get_balance_uncached<'a>(&'a self, address: &'a str, coin_type: Option<&'a str>) -> BoxFuture<'a, TrackerResult<u64>> {
+        self.get_balance(address, coin_type)
+    }
+
+    fn get_balance_detailed<'a>(&'a self, address: &'a str, coin_type: Option<&'a str>) -> BoxFuture<'a, TrackerResult<BalanceDetail>>;
+
+    /// 地址的所有代币余额，包括无法解析的余额
+    fn get_all_balances_report<'a>(&'a self, address: &'a str) -> BoxFuture<'a, TrackerResult<AllBalances>>;
+
+    /// 只返回成功解析的代币余额
+    fn get_all_balances<'a>(&'a self, address: &'a str) -> BoxFuture<'a, TrackerResult<Vec<(String, u64)>>> {
+        Box::pin(async move { self.get_all_balances_report(address).await.map(|report| report.balances) })
+    }
+
+    fn query_transactions_sent<'a>(&'a self, address: &'a str, limit: Option<u16>) -> BoxFuture<'a, TrackerResult<Vec<SuiTransaction>>>;
+
+    fn query_transactions_received<'a>(&'a self, address: &'a str, limit: Option<u16>) -> BoxFuture<'a, TrackerResult<Vec<SuiTransaction>>>;
+
+    fn query_transactions_since<'a>(
+        &'a self,
+        address: &'a str,
+        direction: TransactionDirection,
+        since: DateTime<Utc>,
+    ) -> BoxFuture<'a, TrackerResult<Vec<SuiTransaction>>>;
+
+    fn get_transaction<'a>(&'a self, digest: &'a str) -> BoxFuture<'a, TrackerResult<SuiTransaction>>;
+
+    fn get_chain_id(&self) -> BoxFuture<'_, TrackerResult<String>>;
+
+    fn health_check(&self) -> BoxFuture<'_, TrackerResult<bool>>;
+
+    fn get_latest_checkpoint(&self) -> BoxFuture<'_, TrackerResult<u64>>;
+
+    fn is_healthy(&self) -> BoxFuture<'_, bool> {
+        Box::pin(async move { self.health_check().await.unwrap_or(false) })
+    }
+
+    fn query_transfer_events<'a>(&'a self, address: &'a str, limit: u32) -> BoxFuture<'a, TrackerResult<Vec<SuiEvent>>>;
+
+    fn query_transfer_events_after<'a>(
+        &'a self,
+        address: &'a str,
+        cursor: Option<&'a str>,
+        limit: u32,
+    ) -> BoxFuture<'a, TrackerResult<EventPage>>;
+
+    fn get_coins<'a>(
+        &'a self,
+        address: &'a str,
+        coin_type: Option<&'a str>,
+        cursor: Option<&'a str>,
+        limit: Option<u32>,
+    ) -> BoxFuture<'a, TrackerResult<(Vec<SuiCoin>, Option<String>)>>;
+
+    /// 查询包/模块发出的 Move 事件，游标语义与 `query_transfer_events_after` 相同
+    fn query_package_events_after<'a>(
+        &'a self,
+        filter: &'a PackageFilter,
+        cursor: Option<&'a str>,
+        limit: u32,
+    ) -> BoxFuture<'a, TrackerResult<MoveEventPage>>;
+
+    /// 熔断器状态，没有熔断器的实现始终为 Closed
+    fn circuit_state(&self) -> CircuitState {
+        CircuitState::Closed
+    }
+
+    fn take_circuit_transitions(&self) -> Vec<CircuitTransition> {
+        Vec::new()
+    }
+
+    fn rpc_latency_stats(&self) -> Vec<RpcLatencyStats> {
+        Vec::new()
+    }
+}
+
 /// JSON-RPC请求结构
 #[derive(Serialize, Debug)]
 struct JsonRpcRequest {
@@ -47,17 +139,37 @@ struct SuiBalance {
 }
 
 /// SUI Coin对象响应结构
-#[derive(Deserialize, Debug)]
-struct SuiCoin {
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SuiCoin {
     #[serde(rename = "coinType")]
-    coin_type: String,
+    pub coin_type: String,
     #[serde(rename = "coinObjectId")]
-    coin_object_id: String,
-    version: String,
-    digest: String,
-    balance: String,
+    pub coin_object_id: String,
+    pub version: String,
+    pub digest: String,
+    /// 节点以字符串返回余额
+    #[serde(with = "u64_string")]
+    pub balance: u64,
     #[serde(rename = "previousTransaction")]
-    previous_transaction: String,
+    pub previous_transaction: String,
+}
+
+/// suix_getCoins 响应结构
+#[derive(Deserialize, Debug)]
+struct CoinsResponse {
+    data: Vec<SuiCoin>,
+    #[serde(rename = "nextCursor")]
+    next_cursor: Option<String>,
+    #[serde(rename = "hasNextPage")]
+    has_next_page: bool,
+}
+
+/// suix_queryEvents 响应结构，事件保留原始JSON交给 `EventMonitor::parse_raw_event` 解析
+#[derive(Deserialize, Debug)]
+struct EventsResponse {
+    data: Vec<serde_json::Value>,
+    #[serde(rename = "hasNextPage")]
+    has_next_page: bool,
 }
 
 /// SUI交易块查询响应结构
@@ -147,21 +259,579 @@ enum OwnerInfo {
 pub struct SuiClient {
     client: Client,
     network_url: String,
+    network_name: String,
     rpc_url: String,
+    /// JSON-RPC请求专用，带有 `rpc_headers`，只发往 `rpc_url`
     http_client: reqwest::Client,
+    /// 访问水龙头等其他服务的客户端，不带自定义请求头，避免把API密钥发给其他主机
+    faucet_http_client: reqwest::Client,
+    balance_cache: BalanceCache,
+    response_cache: ResponseCache,
+    rpc_latency: RpcLatencyTracker,
+    circuit_breaker: CircuitBreaker,
+    /// 单次RPC请求的超时时间，为零时不限时
+    request_timeout: Duration,
+    /// 自定义请求头名称（值可能是密钥，不保存以免出现在日志中）
+    rpc_header_names: Vec<String>,
+}
+
+/// 按 (地址, 代币类型) 缓存的余额，ttl 为零时不缓存
+#[derive(Default)]
+struct BalanceCache {
+    ttl: Duration,
+    entries: Mutex<HashMap<(String, String), (Instant, BalanceDetail)>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl BalanceCache {
+    fn get(&self, address: &str, coin_type: &str) -> Option<BalanceDetail> {
+        if self.ttl.is_zero() {
+            return None;
+        }
+
+        let entries = self.entries.lock().unwrap();
+        let cached = entries
+            .get(&(address.to_string(), coin_type.to_string()))
+            .filter(|(fetched_at, _)| fetched_at.elapsed() < self.ttl)
+            .map(|(_, detail)| detail.clone());
+
+        let counter = if cached.is_some() { &self.hits } else { &self.misses };
+        counter.fetch_add(1, Ordering::Relaxed);
+        let hits = self.hits.load(Ordering::Relaxed);
+        let total = hits + self.misses.load(Ordering::Relaxed);
+        log::debug!(
+            "Balance cache {} for {} ({}/{} hits, {:.1}%)",
+            if cached.is_some() { "hit" } else { "miss" },
+            address,
+            hits,
+            total,
+            hits as f64 * 100.0 / total as f64
+        );
+
+        cached
+    }
+
+    fn insert(&self, address: &str, coin_type: &str, detail: &BalanceDetail) {
+        if self.ttl.is_zero() {
+            return;
+        }
+
+        let mut entries = self.entries.lock().unwrap();
+        // 顺便清理过期条目，避免缓存无限增长
+        entries.retain(|_, (fetched_at, _)| fetched_at.elapsed() < self.ttl);
+        entries.insert((address.to_string(), coin_type.to_string()), (Instant::now(), detail.clone()));
+    }
+}
+
+/// 默认缓存响应的只读RPC方法
+///
+/// 不包含监控轮询使用的 `suix_queryTransactionBlocks`，否则轮询间隔不超过缓存时间时会拿到旧的结果。
+pub const DEFAULT_CACHED_RPC_METHODS: &[&str] = &["sui_getTransactionBlock"];
+
+/// 响应缓存键：(方法, 序列化后的参数)
+type ResponseCacheKey = (String, String);
+
+/// 按 (方法, 参数) 缓存的JSON-RPC响应，只缓存允许列表中的方法，ttl 为零时不缓存
+#[derive(Default)]
+struct ResponseCache {
+    ttl: Duration,
+    methods: HashSet<String>,
+    entries: Mutex<HashMap<ResponseCacheKey, (Instant, serde_json::Value)>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl ResponseCache {
+    /// 可缓存的请求返回缓存键，其他请求返回 None
+    fn key(&self, method: &str, params: &serde_json::Value) -> Option<ResponseCacheKey> {
+        if self.ttl.is_zero() || !self.methods.contains(method) {
+            return None;
+        }
+        Some((method.to_string(), params.to_string()))
+    }
+
+    fn get(&self, method: &str, key: &ResponseCacheKey) -> Option<serde_json::Value> {
+        let entries = self.entries.lock().unwrap();
+        let cached = entries
+            .get(key)
+            .filter(|(fetched_at, _)| fetched_at.elapsed() < self.ttl)
+            .map(|(_, value)| value.clone());
+
+        let counter = if cached.is_some() { &self.hits } else { &self.misses };
+        counter.fetch_add(1, Ordering::Relaxed);
+        let hits = self.hits.load(Ordering::Relaxed);
+        let total = hits + self.misses.load(Ordering::Relaxed);
+        log::debug!(
+            "RPC response cache {} for {} ({}/{} hits, {:.1}%)",
+            if cached.is_some() { "hit" } else { "miss" },
+            method,
+            hits,
+            total,
+            hits as f64 * 100.0 / total as f64
+        );
+
+        cached
+    }
+
+    fn insert(&self, key: ResponseCacheKey, value: &serde_json::Value) {
+        let mut entries = self.entries.lock().unwrap();
+        // 顺便清理过期条目，避免缓存无限增长
+        entries.retain(|_, (fetched_at, _)| fetched_at.elapsed() < self.ttl);
+        entries.insert(key, (Instant::now(), value.clone()));
+    }
+
+    fn clear(&self) {
+        self.entries.lock().unwrap().clear();
+    }
+}
+
+/// 每个RPC方法保留用于计算分位数的最近样本数
+const MAX_LATENCY_SAMPLES: usize = 1000;
+
+/// 默认慢请求告警阈值
+pub const DEFAULT_SLOW_RPC_THRESHOLD: Duration = Duration::from_secs(2);
+
+/// 单个RPC方法的耗时统计
+#[derive(Debug, Clone)]
+pub struct RpcLatencyStats {
+    pub method: String,
+    pub count: u64,
+    pub min: Duration,
+    pub max: Duration,
+    pub p50: Duration,
+    pub p95: Duration,
+}
+
+#[derive(Debug, Default)]
+struct MethodLatency {
+    count: u64,
+    min: Duration,
+    max: Duration,
+    /// 最近的样本，用于计算分位数
+    samples: std::collections::VecDeque<Duration>,
+}
+
+impl MethodLatency {
+    fn record(&mut self, elapsed: Duration) {
+        if self.count == 0 || elapsed < self.min {
+            self.min = elapsed;
+        }
+        self.max = self.max.max(elapsed);
+        self.count += 1;
+        if self.samples.len() >= MAX_LATENCY_SAMPLES {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(elapsed);
+    }
+
+    fn stats(&self, method: &str) -> RpcLatencyStats {
+        let mut sorted: Vec<Duration> = self.samples.iter().copied().collect();
+        sorted.sort();
+        RpcLatencyStats {
+            method: method.to_string(),
+            count: self.count,
+            min: self.min,
+            max: self.max,
+            p50: percentile(&sorted, 50),
+            p95: percentile(&sorted, 95),
+        }
+    }
+}
+
+/// 最近秩法计算分位数，`sorted` 须已升序排列
+fn percentile(sorted: &[Duration], pct: usize) -> Duration {
+    if sorted.is_empty() {
+        return Duration::ZERO;
+    }
+    let rank = (sorted.len() * pct).div_ceil(100).max(1);
+    sorted[rank - 1]
+}
+
+/// 按方法记录RPC请求耗时
+struct RpcLatencyTracker {
+    slow_threshold: Duration,
+    methods: Mutex<HashMap<String, MethodLatency>>,
+}
+
+impl Default for RpcLatencyTracker {
+    fn default() -> Self {
+        Self {
+            slow_threshold: DEFAULT_SLOW_RPC_THRESHOLD,
+            methods: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl RpcLatencyTracker {
+    fn record(&self, method: &str, elapsed: Duration) {
+        if !self.slow_threshold.is_zero() && elapsed > self.slow_threshold {
+            log::warn!(
+                "Slow RPC call {}: {} ms (threshold {} ms)",
+                method,
+                elapsed.as_millis(),
+                self.slow_threshold.as_millis()
+            );
+        }
+        self.methods
+            .lock()
+            .unwrap()
+            .entry(method.to_string())
+            .or_default()
+            .record(elapsed);
+    }
+
+    fn stats(&self) -> Vec<RpcLatencyStats> {
+        let methods = self.methods.lock().unwrap();
+        let mut stats: Vec<RpcLatencyStats> = methods
+            .iter()
+            .map(|(method, latency)| latency.stats(method))
+            .collect();
+        stats.sort_by(|a, b| a.method.cmp(&b.method));
+        stats
+    }
+}
+
+/// 熔断器状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum CircuitState {
+    /// 正常请求
+    Closed,
+    /// 连续失败过多，冷却期内直接失败
+    Open,
+    /// 冷却结束，放行一次试探请求
+    HalfOpen,
+}
+
+/// 熔断器状态变化
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CircuitTransition {
+    Opened { consecutive_failures: u32 },
+    Closed { open_for: Duration },
 }
 
-/// 交易信息结构
 #[derive(Debug, Clone)]
+pub struct CircuitBreakerConfig {
+    /// 时间窗口内连续失败达到该次数时熔断，0 表示关闭熔断
+    pub failure_threshold: u32,
+    pub failure_window: Duration,
+    /// 熔断后等待多久放行试探请求
+    pub cooldown: Duration,
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 5,
+            failure_window: Duration::from_secs(60),
+            cooldown: Duration::from_secs(30),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct CircuitInner {
+    state: CircuitState,
+    /// 连续失败的时间点，成功后清空
+    failures: std::collections::VecDeque<Instant>,
+    opened_at: Option<Instant>,
+    /// 首次熔断的时间，恢复时用于计算中断时长
+    outage_started: Option<Instant>,
+    trial_in_flight: bool,
+    transitions: Vec<CircuitTransition>,
+}
+
+#[derive(Debug)]
+struct CircuitBreaker {
+    config: CircuitBreakerConfig,
+    inner: Mutex<CircuitInner>,
+}
+
+impl Default for CircuitBreaker {
+    fn default() -> Self {
+        Self::new(CircuitBreakerConfig::default())
+    }
+}
+
+impl CircuitBreaker {
+    fn new(config: CircuitBreakerConfig) -> Self {
+        Self {
+            config,
+            inner: Mutex::new(CircuitInner {
+                state: CircuitState::Closed,
+                failures: std::collections::VecDeque::new(),
+                opened_at: None,
+                outage_started: None,
+                trial_in_flight: false,
+                transitions: Vec::new(),
+            }),
+        }
+    }
+
+    fn state(&self) -> CircuitState {
+        self.inner.lock().unwrap().state
+    }
+
+    /// 请求前检查：熔断中返回错误；冷却结束后只放行一次试探请求
+    ///
+    /// 返回的许可须在请求完成后调用 `finish`；请求被取消时许可被丢弃，释放试探名额。
+    fn before_request(&self, network: &str) -> TrackerResult<CircuitPermit<'_>> {
+        if self.config.failure_threshold == 0 {
+            return Ok(CircuitPermit::new(self, false));
+        }
+
+        let mut inner = self.inner.lock().unwrap();
+        match inner.state {
+            CircuitState::Closed => Ok(CircuitPermit::new(self, false)),
+            CircuitState::Open => {
+                let cooled_down = inner.opened_at.is_none_or(|at| at.elapsed() >= self.config.cooldown);
+                if cooled_down {
+                    log::info!("RPC circuit for '{}' half-open, sending trial request", network);
+                    inner.state = CircuitState::HalfOpen;
+                    inner.trial_in_flight = true;
+                    Ok(CircuitPermit::new(self, true))
+                } else {
+                    Err(TrackerError::circuit_open(format!(
+                        "RPC circuit for '{}' is open, failing fast",
+                        network
+                    )))
+                }
+            }
+            CircuitState::HalfOpen => {
+                if inner.trial_in_flight {
+                    Err(TrackerError::circuit_open(format!(
+                        "RPC circuit for '{}' is half-open, waiting for trial request",
+                        network
+                    )))
+                } else {
+                    inner.trial_in_flight = true;
+                    Ok(CircuitPermit::new(self, true))
+                }
+            }
+        }
+    }
+
+    fn record(&self, success: bool) {
+        if self.config.failure_threshold == 0 {
+            return;
+        }
+
+        let mut inner = self.inner.lock().unwrap();
+        let now = Instant::now();
+        inner.trial_in_flight = false;
+
+        if success {
+            inner.failures.clear();
+            if inner.state != CircuitState::Closed {
+                let open_for = inner.outage_started.map(|at| now.duration_since(at)).unwrap_or_default();
+                inner.state = CircuitState::Closed;
+                inner.opened_at = None;
+                inner.outage_started = None;
+                inner.transitions.push(CircuitTransition::Closed { open_for });
+            }
+            return;
+        }
+
+        match inner.state {
+            // 试探失败，重新进入冷却（不重复告警）
+            CircuitState::HalfOpen | CircuitState::Open => {
+                inner.state = CircuitState::Open;
+                inner.opened_at = Some(now);
+            }
+            CircuitState::Closed => {
+                let window = self.config.failure_window;
+                inner.failures.retain(|at| now.duration_since(*at) <= window);
+                inner.failures.push_back(now);
+
+                if inner.failures.len() as u32 >= self.config.failure_threshold {
+                    let consecutive_failures = inner.failures.len() as u32;
+                    log::warn!("RPC circuit opened after {} consecutive failures", consecutive_failures);
+                    inner.state = CircuitState::Open;
+                    inner.opened_at = Some(now);
+                    inner.outage_started = Some(now);
+                    inner.failures.clear();
+                    inner.transitions.push(CircuitTransition::Opened { consecutive_failures });
+                }
+            }
+        }
+    }
+
+    fn take_transitions(&self) -> Vec<CircuitTransition> {
+        std::mem::take(&mut self.inner.lock().unwrap().transitions)
+    }
+}
+
+/// 一次请求的熔断许可
+///
+/// 试探请求的future被取消（超时、select、任务中止）时没有结果可记录，
+/// 丢弃许可会释放试探名额，避免熔断器一直停在半开状态。
+struct CircuitPermit<'a> {
+    breaker: &'a CircuitBreaker,
+    trial: bool,
+    finished: bool,
+}
+
+impl<'a> CircuitPermit<'a> {
+    fn new(breaker: &'a CircuitBreaker, trial: bool) -> Self {
+        Self { breaker, trial, finished: false }
+    }
+
+    /// 记录请求结果
+    fn finish(mut self, success: bool) {
+        self.finished = true;
+        self.breaker.record(success);
+    }
+}
+
+impl Drop for CircuitPermit<'_> {
+    fn drop(&mut self) {
+        if self.trial && !self.finished {
+            self.breaker.inner.lock().unwrap().trial_in_flight = false;
+        }
+    }
+}
+
+/// 未指定时单次RPC请求的超时（秒）
+const DEFAULT_REQUEST_TIMEOUT_SECONDS: u64 = 30;
+
+/// 交易信息结构
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SuiTransaction {
     pub digest: String,
+    #[serde(default, with = "rfc3339_option")]
     pub timestamp: Option<DateTime<Utc>>,
     pub gas_used: Option<String>,
     pub balance_changes: Vec<BalanceChange>,
+    pub status: TransactionStatus,
+    /// 交易所在的检查点序号
+    #[serde(default)]
+    pub checkpoint: Option<u64>,
 }
 
-/// 余额变化信息
+/// 余额详情
 #[derive(Debug, Clone)]
+pub struct BalanceDetail {
+    pub coin_type: String,
+    pub total_balance: u64,
+    /// 持有的Coin对象数量，碎片化严重时可能影响gas coin的选择
+    pub coin_object_count: u64,
+    /// 锁定（不可花费）的数量
+    pub locked_balance: u64,
+}
+
+/// 地址所有代币的余额查询结果
+#[derive(Debug, Clone, Default)]
+pub struct AllBalances {
+    /// 成功解析的 (代币类型, 余额)
+    pub balances: Vec<(String, u64)>,
+    /// 无法解析为 u64 的 (代币类型, 原始余额)，如格式错误或超出范围
+    pub unparsed: Vec<(String, String)>,
+}
+
+impl AllBalances {
+    fn from_response(response: Vec<SuiBalance>) -> Self {
+        let mut result = Self::default();
+        for balance in response {
+            match balance.total_balance.parse::<u64>() {
+                Ok(amount) => result.balances.push((balance.coin_type, amount)),
+                Err(e) => {
+                    log::warn!("Failed to parse balance '{}' for coin type '{}': {}",
+                        balance.total_balance, balance.coin_type, e);
+                    result.unparsed.push((balance.coin_type, balance.total_balance));
+                }
+            }
+        }
+        result
+    }
+}
+
+impl BalanceDetail {
+    /// 可花费余额
+    pub fn available_balance(&self) -> u64 {
+        self.total_balance.saturating_sub(self.locked_balance)
+    }
+}
+
+impl SuiTransaction {
+    /// 从 JSON（如缓存的查询结果）还原交易
+    pub fn from_json(json: &str) -> TrackerResult<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+}
+
+/// 时间戳以 RFC3339 字符串序列化
+mod rfc3339_option {
+    use chrono::{DateTime, Utc};
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(value: &Option<DateTime<Utc>>, serializer: S) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(dt) => serializer.serialize_some(&dt.to_rfc3339()),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<DateTime<Utc>>, D::Error> {
+        Option::<String>::deserialize(deserializer)?
+            .map(|s| {
+                DateTime::parse_from_rfc3339(&s)
+                    .map(|dt| dt.with_timezone(&Utc))
+                    .map_err(serde::de::Error::custom)
+            })
+            .transpose()
+    }
+}
+
+mod u64_string {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(value: &u64, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&value.to_string())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+        String::deserialize(deserializer)?.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// 地址某一代币的 Coin 对象分布，用于判断是否需要合并 Coin
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CoinReport {
+    pub address: String,
+    pub coin_type: String,
+    pub coin_count: usize,
+    pub total_balance: u64,
+    pub largest_coin: u64,
+    pub smallest_coin: u64,
+    /// 碎片化程度：1 - 最大Coin/总额，0 表示全部在一个Coin中，越接近 1 越分散
+    pub fragmentation_score: f64,
+}
+
+impl CoinReport {
+    pub fn from_coins(address: &str, coin_type: &str, coins: &[SuiCoin]) -> Self {
+        let total_balance = coins.iter().fold(0u64, |total, coin| total.saturating_add(coin.balance));
+        let largest_coin = coins.iter().map(|coin| coin.balance).max().unwrap_or(0);
+        let smallest_coin = coins.iter().map(|coin| coin.balance).min().unwrap_or(0);
+        let fragmentation_score = if total_balance == 0 {
+            0.0
+        } else {
+            1.0 - largest_coin as f64 / total_balance as f64
+        };
+
+        Self {
+            address: address.to_string(),
+            coin_type: coin_type.to_string(),
+            coin_count: coins.len(),
+            total_balance,
+            largest_coin,
+            smallest_coin,
+            fragmentation_score,
+        }
+    }
+}
+
+/// 余额变化信息
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BalanceChange {
     pub owner: String,
     pub coin_type: String,
@@ -171,7 +841,9 @@ pub struct BalanceChange {
 impl std::fmt::Debug for SuiClient {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("SuiClient")
+            .field("network_name", &self.network_name)
             .field("network_url", &self.network_url)
+            .field("rpc_headers", &self.rpc_header_names.iter().map(|name| (name.as_str(), "<redacted>")).collect::<Vec<_>>())
             .finish()
     }
 }
@@ -187,10 +859,35 @@ impl SuiClient {
             _ => Client::new_mainnet(), // 默认使用主网
         };
 
-        // 确定JSON-RPC URL
-        let rpc_url = if network_url.contains("mainnet") {
-            "https://fullnode.mainnet.sui.io:443".to_string()
-        } else if network_url.contains("testnet") {
+        let rpc_url = Self::resolve_rpc_url(network_url);
+        let http_client = Self::build_http_client(&HashMap::new())?;
+        let faucet_http_client = Self::build_http_client(&HashMap::new())?;
+
+        log::info!("Initializing SUI client with GraphQL: {} and RPC: {}", network_url, rpc_url);
+
+        Ok(Self {
+            client,
+            network_url: network_url.to_string(),
+            network_name: String::new(),
+            rpc_url,
+            http_client,
+            faucet_http_client,
+            balance_cache: BalanceCache::default(),
+            response_cache: ResponseCache::default(),
+            rpc_latency: RpcLatencyTracker::default(),
+            circuit_breaker: CircuitBreaker::default(),
+            request_timeout: Duration::from_secs(DEFAULT_REQUEST_TIMEOUT_SECONDS),
+            rpc_header_names: Vec::new(),
+        })
+    }
+
+    /// JSON-RPC地址：配置的 http(s) 地址（如带API密钥的商业节点）直接使用，
+    /// 只给出网络名称（如 "testnet"）时使用该网络的公共全节点
+    fn resolve_rpc_url(network_url: &str) -> String {
+        if network_url.starts_with("http://") || network_url.starts_with("https://") {
+            return network_url.to_string();
+        }
+        if network_url.contains("testnet") {
             "https://fullnode.testnet.sui.io:443".to_string()
         } else if network_url.contains("devnet") {
             "https://fullnode.devnet.sui.io:443".to_string()
@@ -198,22 +895,88 @@ impl SuiClient {
             "http://localhost:9000".to_string()
         } else {
             "https://fullnode.mainnet.sui.io:443".to_string() // 默认主网
-        };
+        }
+    }
 
-        let http_client = reqwest::Client::new();
+    /// 构建带默认请求头的HTTP客户端，所有JSON-RPC请求共用
+    fn build_http_client(headers: &HashMap<String, String>) -> TrackerResult<reqwest::Client> {
+        use reqwest::header::{HeaderMap, HeaderName, HeaderValue, CONTENT_TYPE};
 
-        log::info!("Initializing SUI client with GraphQL: {} and RPC: {}", network_url, rpc_url);
+        let mut default_headers = HeaderMap::new();
+        default_headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        for (name, value) in headers {
+            let header_name = HeaderName::from_bytes(name.as_bytes())
+                .map_err(|_| TrackerError::validation_error(format!("Invalid RPC header name: {}", name)))?;
+            let mut header_value = HeaderValue::from_str(value)
+                .map_err(|_| TrackerError::validation_error(format!("Invalid value for RPC header {}", name)))?;
+            header_value.set_sensitive(true);
+            default_headers.insert(header_name, header_value);
+        }
 
-        Ok(Self {
-            client,
-            network_url: network_url.to_string(),
-            rpc_url,
-            http_client,
-        })
+        reqwest::Client::builder()
+            .default_headers(default_headers)
+            .build()
+            .map_err(|e| TrackerError::network_error(format!("Failed to build HTTP client: {}", e)))
+    }
+
+    /// 设置每个JSON-RPC请求附带的请求头（如服务商的API密钥），只发往 `rpc_url`
+    pub fn set_rpc_headers(&mut self, headers: &HashMap<String, String>) -> TrackerResult<()> {
+        self.http_client = Self::build_http_client(headers)?;
+        self.rpc_header_names = headers.keys().cloned().collect();
+        self.rpc_header_names.sort();
+        Ok(())
     }
 
-    /// 发送JSON-RPC请求
+    /// 发送JSON-RPC请求；允许缓存的只读方法在缓存时间内直接返回缓存的响应
     async fn send_rpc_request<T>(&self, method: &str, params: serde_json::Value) -> TrackerResult<T>
+    where
+        T: for<'de> serde::Deserialize<'de>,
+    {
+        let Some(key) = self.response_cache.key(method, &params) else {
+            return self.send_rpc_request_uncached(method, params).await;
+        };
+
+        let value = match self.response_cache.get(method, &key) {
+            Some(value) => value,
+            None => {
+                let value: serde_json::Value = self.send_rpc_request_uncached(method, params).await?;
+                self.response_cache.insert(key, &value);
+                value
+            }
+        };
+        serde_json::from_value(value)
+            .map_err(|e| TrackerError::parse_error(format!("Failed to parse {} response: {}", method, e)))
+    }
+
+    /// 发送JSON-RPC请求，并记录该方法的耗时
+    async fn send_rpc_request_uncached<T>(&self, method: &str, params: serde_json::Value) -> TrackerResult<T>
+    where
+        T: for<'de> serde::Deserialize<'de>,
+    {
+        // 熔断期间直接失败，不再请求节点
+        let permit = self.circuit_breaker.before_request(&self.network_name)?;
+
+        let (result, elapsed) = crate::error::utils::measure_async_time(|| async {
+            if self.request_timeout.is_zero() {
+                return self.send_rpc_request_untimed(method, params).await;
+            }
+            match tokio::time::timeout(self.request_timeout, self.send_rpc_request_untimed(method, params)).await {
+                Ok(result) => result,
+                Err(_) => Err(TrackerError::timeout_error(format!(
+                    "RPC call {} timed out after {:?}",
+                    method, self.request_timeout
+                ))),
+            }
+        }).await;
+        self.rpc_latency.record(method, elapsed);
+
+        // 只有网络类错误计入熔断，解析错误说明节点仍可用
+        let node_failed = result.as_ref().is_err_and(|e| e.is_retriable());
+        permit.finish(!node_failed);
+        result
+    }
+
+    async fn send_rpc_request_untimed<T>(&self, method: &str, params: serde_json::Value) -> TrackerResult<T>
     where
         T: for<'de> serde::Deserialize<'de>,
     {
@@ -228,7 +991,6 @@ impl SuiClient {
 
         let response = self.http_client
             .post(&self.rpc_url)
-            .header("Content-Type", "application/json")
             .json(&request)
             .send()
             .await
@@ -247,9 +1009,10 @@ impl SuiClient {
             .await
             .map_err(|e| TrackerError::parse_error(&format!("Failed to parse JSON response: {}", e)))?;
 
+        // 节点返回了错误对象说明节点可达，不重试也不计入熔断
         if let Some(error) = rpc_response.error {
-            return Err(TrackerError::network_error(format!(
-                "RPC error {}: {}",
+            return Err(TrackerError::rpc_error(format!(
+                "{}: {}",
                 error.code, error.message
             )));
         }
@@ -259,16 +1022,90 @@ impl SuiClient {
         })
     }
 
-    /// 获取指定地址和代币类型的余额
-    /// 使用真实的JSON-RPC API调用
+    /// 设置网络名称，查询到的事件会带上该名称
+    pub fn set_network_name(&mut self, name: impl Into<String>) {
+        self.network_name = name.into();
+    }
+
+    pub fn network_name(&self) -> &str {
+        &self.network_name
+    }
+
+    /// 设置熔断参数
+    pub fn set_circuit_breaker(&mut self, config: CircuitBreakerConfig) {
+        self.circuit_breaker = CircuitBreaker::new(config);
+    }
+
+    /// 当前熔断器状态
+    pub fn circuit_state(&self) -> CircuitState {
+        self.circuit_breaker.state()
+    }
+
+    /// 取出自上次调用以来的熔断状态变化，用于发送警报
+    pub fn take_circuit_transitions(&self) -> Vec<CircuitTransition> {
+        self.circuit_breaker.take_transitions()
+    }
+
+    /// 设置慢请求告警阈值，为零时不告警
+    pub fn set_slow_rpc_threshold(&mut self, threshold: Duration) {
+        self.rpc_latency.slow_threshold = threshold;
+    }
+
+    /// 各RPC方法的耗时统计（count/min/max/p50/p95），按方法名排序
+    pub fn rpc_latency_stats(&self) -> Vec<RpcLatencyStats> {
+        self.rpc_latency.stats()
+    }
+
+    /// 设置余额缓存时间，为零时每次都查询节点
+    pub fn set_balance_cache_ttl(&mut self, ttl: Duration) {
+        self.balance_cache.ttl = ttl;
+    }
+
+    /// 设置RPC响应缓存：缓存时间和允许缓存的方法，缓存时间为零时不缓存
+    pub fn set_response_cache(&mut self, ttl: Duration, methods: &[String]) {
+        self.response_cache = ResponseCache {
+            ttl,
+            methods: methods.iter().cloned().collect(),
+            ..ResponseCache::default()
+        };
+    }
+
+    /// 清空RPC响应缓存和余额缓存，之后的查询都会请求节点
+    pub fn clear_cache(&self) {
+        self.response_cache.clear();
+        self.balance_cache.entries.lock().unwrap().clear();
+    }
+
+    /// 获取指定地址和代币类型的余额
     pub async fn get_balance(&self, address: &str, coin_type: Option<&str>) -> TrackerResult<u64> {
+        self.get_balance_detailed(address, coin_type)
+            .await
+            .map(|detail| detail.total_balance)
+    }
+
+    /// 绕过缓存直接查询节点，用于余额对账等需要最新值的场景
+    pub async fn get_balance_uncached(&self, address: &str, coin_type: Option<&str>) -> TrackerResult<u64> {
+        self.get_balance_detailed_uncached(address, coin_type)
+            .await
+            .map(|detail| detail.total_balance)
+    }
+
+    /// 获取余额详情（总额、Coin对象数量、锁定数量），缓存时间内直接返回缓存值
+    pub async fn get_balance_detailed(&self, address: &str, coin_type: Option<&str>) -> TrackerResult<BalanceDetail> {
+        let cache_coin_type = coin_type.unwrap_or("0x2::sui::SUI");
+        if let Some(detail) = self.balance_cache.get(address, cache_coin_type) {
+            return Ok(detail);
+        }
+        self.get_balance_detailed_uncached(address, coin_type).await
+    }
+
+    /// 获取余额详情，总是查询节点并刷新缓存
+    /// 使用真实的JSON-RPC API调用
+    pub async fn get_balance_detailed_uncached(&self, address: &str, coin_type: Option<&str>) -> TrackerResult<BalanceDetail> {
         // 验证地址格式
         Address::from_str(address)
             .map_err(|e| TrackerError::invalid_address(format!("Invalid address: {}", e)))?;
 
-        // 检查网络连接
-        self.health_check().await?;
-
         let coin_type = coin_type.unwrap_or("0x2::sui::SUI");
         
         // 使用真实的JSON-RPC API调用
@@ -284,7 +1121,14 @@ impl SuiClient {
                 match balance_response.total_balance.parse::<u64>() {
                     Ok(balance) => {
                         log::info!("Parsed balance: {} for address: {}", balance, address);
-                        Ok(balance)
+                        let detail = BalanceDetail {
+                            locked_balance: Self::sum_locked_balance(balance_response.locked_balance.as_ref()),
+                            coin_type: balance_response.coin_type,
+                            total_balance: balance,
+                            coin_object_count: balance_response.coin_object_count,
+                        };
+                        self.balance_cache.insert(address, coin_type, &detail);
+                        Ok(detail)
                     },
                     Err(e) => {
                         log::error!("Failed to parse balance '{}': {}", balance_response.total_balance, e);
@@ -299,16 +1143,34 @@ impl SuiClient {
         }
     }
 
-    /// 获取地址的所有代币余额
-    /// 使用真实的JSON-RPC API调用
+    /// lockedBalance 为 epoch -> 数量 的映射，数量可能是字符串或数字
+    fn sum_locked_balance(locked: Option<&serde_json::Value>) -> u64 {
+        let Some(serde_json::Value::Object(entries)) = locked else {
+            return 0;
+        };
+
+        entries
+            .values()
+            .filter_map(|amount| match amount {
+                serde_json::Value::String(s) => s.parse::<u64>().ok(),
+                serde_json::Value::Number(n) => n.as_u64(),
+                _ => None,
+            })
+            .fold(0u64, |total, amount| total.saturating_add(amount))
+    }
+
+    /// 获取地址的所有代币余额，只返回成功解析的余额
     pub async fn get_all_balances(&self, address: &str) -> TrackerResult<Vec<(String, u64)>> {
+        self.get_all_balances_report(address).await.map(|report| report.balances)
+    }
+
+    /// 获取地址的所有代币余额，无法解析的余额单独列出
+    /// 使用真实的JSON-RPC API调用
+    pub async fn get_all_balances_report(&self, address: &str) -> TrackerResult<AllBalances> {
         // 验证地址格式
         Address::from_str(address)
             .map_err(|e| TrackerError::invalid_address(format!("Invalid address: {}", e)))?;
 
-        // 检查网络连接
-        self.health_check().await?;
-
         // 使用真实的JSON-RPC API调用
         log::info!("Querying all real balances for address: {}", address);
         
@@ -318,21 +1180,10 @@ impl SuiClient {
             Ok(balances_response) => {
                 log::info!("Successfully got all balances response: {:?}", balances_response);
                 
-                let mut result = Vec::new();
+                let result = AllBalances::from_response(balances_response);
                 
-                for balance in balances_response {
-                    match balance.total_balance.parse::<u64>() {
-                        Ok(amount) => {
-                            result.push((balance.coin_type, amount));
-                        },
-                        Err(e) => {
-                            log::warn!("Failed to parse balance '{}' for coin type '{}': {}", 
-                                balance.total_balance, balance.coin_type, e);
-                        }
-                    }
-                }
-                
-                log::info!("Parsed {} balances for address: {}", result.len(), address);
+                log::info!("Parsed {} balances for address: {} ({} unparseable)",
+                    result.balances.len(), address, result.unparsed.len());
                 Ok(result)
             },
             Err(e) => {
@@ -342,6 +1193,26 @@ impl SuiClient {
         }
     }
 
+    /// 分页获取地址持有的 Coin 对象，返回下一页游标（没有更多时为 None）
+    pub async fn get_coins(
+        &self,
+        address: &str,
+        coin_type: Option<&str>,
+        cursor: Option<&str>,
+        limit: Option<u32>,
+    ) -> TrackerResult<(Vec<SuiCoin>, Option<String>)> {
+        Address::from_str(address)
+            .map_err(|e| TrackerError::invalid_address(format!("Invalid address: {}", e)))?;
+
+        let coin_type = coin_type.unwrap_or("0x2::sui::SUI");
+        let params = serde_json::json!([address, coin_type, cursor, limit]);
+        let response: CoinsResponse = self.send_rpc_request("suix_getCoins", params).await?;
+
+        log::debug!("Got {} coin objects of {} for address: {}", response.data.len(), coin_type, address);
+        let next_cursor = if response.has_next_page { response.next_cursor } else { None };
+        Ok((response.data, next_cursor))
+    }
+
     /// 查询发送的交易
     pub async fn query_transactions_sent(&self, address: &str, limit: Option<u16>) -> TrackerResult<Vec<SuiTransaction>> {
         self.query_transactions(address, limit).await
@@ -349,28 +1220,77 @@ impl SuiClient {
 
     /// 查询接收的交易  
     pub async fn query_transactions_received(&self, address: &str, limit: Option<u16>) -> TrackerResult<Vec<SuiTransaction>> {
-        self.query_transactions(address, limit).await
+        Ok(self.query_transactions_page(address, TransactionDirection::Received, None, limit, false).await?.transactions)
     }
 
     /// 通用交易查询方法
     /// 使用真实的JSON-RPC API调用
     async fn query_transactions(&self, address: &str, limit: Option<u16>) -> TrackerResult<Vec<SuiTransaction>> {
+        Ok(self.query_transactions_page(address, TransactionDirection::Sent, None, limit, false).await?.transactions)
+    }
+
+    /// 查询 `since` 及之后的全部交易（不限条数）
+    ///
+    /// 从最新交易开始逐页向前翻，遇到早于 `since` 的交易即停止；时间恰好等于 `since` 的交易会被包含。
+    pub async fn query_transactions_since(
+        &self,
+        address: &str,
+        direction: TransactionDirection,
+        since: DateTime<Utc>,
+    ) -> TrackerResult<Vec<SuiTransaction>> {
+        let mut result = Vec::new();
+        let mut cursor: Option<String> = None;
+
+        loop {
+            let page = self
+                .query_transactions_page(address, direction, cursor.as_deref(), Some(SINCE_PAGE_SIZE), true)
+                .await?;
+
+            let mut reached_older = false;
+            for tx in page.transactions {
+                // 没有时间戳的交易（尚未确认）无法判断，保留
+                if tx.timestamp.is_some_and(|ts| ts < since) {
+                    reached_older = true;
+                    break;
+                }
+                result.push(tx);
+            }
+
+            if reached_older || !page.has_next_page {
+                break;
+            }
+            match page.next_cursor {
+                Some(next) => cursor = Some(next),
+                None => break,
+            }
+        }
+
+        log::info!("Found {} transactions for {} since {}", result.len(), address, since);
+        Ok(result)
+    }
+
+    /// 分页查询交易
+    ///
+    /// `cursor` 为上一页最后一笔交易的digest（不包含在结果中）；`descending` 为 true 时从最新交易开始。
+    pub async fn query_transactions_page(
+        &self,
+        address: &str,
+        direction: TransactionDirection,
+        cursor: Option<&str>,
+        limit: Option<u16>,
+        descending: bool,
+    ) -> TrackerResult<TransactionPage> {
         // 验证地址格式
         Address::from_str(address)
             .map_err(|e| TrackerError::invalid_address(format!("Invalid address: {}", e)))?;
 
-        // 检查网络连接
-        self.health_check().await?;
-
         let limit = limit.unwrap_or(10) as u64;
 
         // 使用真实的JSON-RPC API调用
         log::info!("Querying real transactions for address: {} limit: {}", address, limit);
         
         // 构建查询参数
-        let filter = serde_json::json!({
-            "FromAddress": address
-        });
+        let filter = direction.filter(address);
 
         let options = serde_json::json!({
             "showInput": false,
@@ -386,84 +1306,29 @@ impl SuiClient {
                 "filter": filter,
                 "options": options
             },
-            null, // cursor
+            cursor,
             limit,
-            false // descending order
+            descending
         ]);
         
         match self.send_rpc_request::<TransactionBlocksResponse>("suix_queryTransactionBlocks", params).await {
             Ok(response) => {
                 log::info!("Successfully got transaction blocks response with {} transactions", response.data.len());
                 
-                let mut result = Vec::new();
+                let next_cursor = response.next_cursor;
+                let has_next_page = response.has_next_page;
                 
-                for tx_data in response.data {
-                    let mut balance_changes = Vec::new();
-                    
-                    // 解析余额变化
-                    if let Some(effects) = &tx_data.effects {
-                        if let Some(changes) = &effects.balance_changes {
-                            for change in changes {
-                                match change.amount.parse::<i64>() {
-                                    Ok(amount) => {
-                                        let owner_address = match &change.owner {
-                                            OwnerInfo::AddressOwner { address_owner } => address_owner.clone(),
-                                            _ => address.to_string(), // 默认使用查询地址
-                                        };
-                                        
-                                        balance_changes.push(BalanceChange {
-                                            owner: owner_address,
-                                            coin_type: change.coin_type.clone(),
-                                            amount,
-                                        });
-                                    },
-                                    Err(e) => {
-                                        log::warn!("Failed to parse amount '{}': {}", change.amount, e);
-                                    }
-                                }
-                            }
-                        }
-                    }
-
-                    // 解析gas消耗
-                    let gas_used = tx_data.effects
-                        .as_ref()
-                        .and_then(|e| e.gas_used.as_ref())
-                        .map(|g| {
-                            // 计算总gas消耗（避免溢出）
-                            let computation_cost: u64 = g.computation_cost.parse().unwrap_or(0);
-                            let storage_cost: u64 = g.storage_cost.parse().unwrap_or(0);
-                            let storage_rebate: u64 = g.storage_rebate.parse().unwrap_or(0);
-                            let non_refundable: u64 = g.non_refundable_storage_fee.parse().unwrap_or(0);
-                            
-                            // 使用安全的减法避免溢出
-                            let total_costs = computation_cost + storage_cost + non_refundable;
-                            let total_gas = if total_costs >= storage_rebate {
-                                total_costs - storage_rebate
-                            } else {
-                                0
-                            };
-                            total_gas.to_string()
-                        });
-
-                    // 解析时间戳
-                    let timestamp = tx_data.timestamp_ms
-                        .and_then(|ts| ts.parse::<i64>().ok())
-                        .map(|ts_ms| {
-                            let dt = chrono::DateTime::from_timestamp_millis(ts_ms);
-                            dt.unwrap_or_else(|| Utc::now())
-                        });
-
-                    result.push(SuiTransaction {
-                        digest: tx_data.digest,
-                        timestamp,
-                        gas_used,
-                        balance_changes,
-                    });
-                }
+                let result: Vec<SuiTransaction> = response.data
+                    .into_iter()
+                    .map(|tx_data| Self::parse_transaction_block(tx_data, address))
+                    .collect();
                 
                 log::info!("Parsed {} transactions for address: {}", result.len(), address);
-                Ok(result)
+                Ok(TransactionPage {
+                    transactions: result,
+                    next_cursor,
+                    has_next_page,
+                })
             },
             Err(e) => {
                 log::error!("Failed to get transactions: {}", e);
@@ -472,6 +1337,114 @@ impl SuiClient {
         }
     }
 
+    /// 将节点返回的交易块解析为 `SuiTransaction`，非地址所有者的余额变化记到 `fallback_owner`
+    fn parse_transaction_block(tx_data: TransactionBlockData, fallback_owner: &str) -> SuiTransaction {
+        let mut balance_changes = Vec::new();
+        
+        // 解析余额变化
+        if let Some(effects) = &tx_data.effects {
+            if let Some(changes) = &effects.balance_changes {
+                for change in changes {
+                    match change.amount.parse::<i64>() {
+                        Ok(amount) => {
+                            let owner_address = match &change.owner {
+                                OwnerInfo::AddressOwner { address_owner } => address_owner.clone(),
+                                _ => fallback_owner.to_string(), // 非地址所有者
+                            };
+                            
+                            balance_changes.push(BalanceChange {
+                                owner: owner_address,
+                                coin_type: change.coin_type.clone(),
+                                amount,
+                            });
+                        },
+                        Err(e) => {
+                            log::warn!("Failed to parse amount '{}': {}", change.amount, e);
+                        }
+                    }
+                }
+            }
+        }
+
+        // 解析gas消耗
+        let gas_used = tx_data.effects
+            .as_ref()
+            .and_then(|e| e.gas_used.as_ref())
+            .map(|g| {
+                // 计算总gas消耗（避免溢出）
+                let computation_cost: u64 = g.computation_cost.parse().unwrap_or(0);
+                let storage_cost: u64 = g.storage_cost.parse().unwrap_or(0);
+                let storage_rebate: u64 = g.storage_rebate.parse().unwrap_or(0);
+                let non_refundable: u64 = g.non_refundable_storage_fee.parse().unwrap_or(0);
+                
+                // 使用安全的减法避免溢出
+                let total_costs = computation_cost + storage_cost + non_refundable;
+                let total_gas = if total_costs >= storage_rebate {
+                    total_costs - storage_rebate
+                } else {
+                    0
+                };
+                total_gas.to_string()
+            });
+
+        // 解析时间戳
+        let timestamp = tx_data.timestamp_ms
+            .and_then(|ts| ts.parse::<i64>().ok())
+            .map(|ts_ms| {
+                let dt = chrono::DateTime::from_timestamp_millis(ts_ms);
+                dt.unwrap_or_else(|| Utc::now())
+            });
+
+        // 解析链上执行状态，没有effects时视为待确认
+        let status = match tx_data.effects.as_ref().map(|e| e.status.status.as_str()) {
+            Some("success") => TransactionStatus::Success,
+            Some(_) => TransactionStatus::Failed,
+            None => TransactionStatus::Pending,
+        };
+
+        SuiTransaction {
+            digest: tx_data.digest,
+            timestamp,
+            gas_used,
+            balance_changes,
+            status,
+            checkpoint: tx_data.checkpoint.and_then(|c| c.parse().ok()),
+        }
+    }
+
+    /// 按digest查询单笔交易
+    pub async fn get_transaction(&self, digest: &str) -> TrackerResult<SuiTransaction> {
+        if digest.is_empty() {
+            return Err(TrackerError::validation_error("Transaction digest cannot be empty"));
+        }
+
+        let params = serde_json::json!([
+            digest,
+            {
+                "showInput": false,
+                "showRawInput": false,
+                "showEffects": true,
+                "showEvents": false,
+                "showObjectChanges": false,
+                "showBalanceChanges": true
+            }
+        ]);
+
+        let tx_data = self
+            .send_rpc_request::<TransactionBlockData>("sui_getTransactionBlock", params)
+            .await
+            .map_err(|e| {
+                // 节点对不存在的digest返回 "Could not find the referenced transaction"
+                if e.to_string().contains("Could not find") {
+                    TrackerError::validation_error(format!("Transaction {} not found", digest))
+                } else {
+                    e
+                }
+            })?;
+
+        Ok(Self::parse_transaction_block(tx_data, "unknown"))
+    }
+
     /// 获取链ID
     pub async fn get_chain_id(&self) -> TrackerResult<String> {
         self.client
@@ -480,6 +1453,14 @@ impl SuiClient {
             .map_err(|e| TrackerError::network_error(format!("Failed to get chain ID: {:?}", e)))
     }
 
+    /// 最新已执行检查点的序号
+    pub async fn get_latest_checkpoint(&self) -> TrackerResult<u64> {
+        let sequence: String = self.send_rpc_request("sui_getLatestCheckpointSequenceNumber", serde_json::json!([])).await?;
+        sequence.parse().map_err(|_| {
+            TrackerError::parse_error(format!("Invalid checkpoint sequence number: {}", sequence))
+        })
+    }
+
     /// 健康检查
     pub async fn health_check(&self) -> TrackerResult<bool> {
         match self.get_chain_id().await {
@@ -498,7 +1479,7 @@ impl SuiClient {
         } else if self.network_url.contains("testnet") {
             FaucetClient::testnet()
         } else {
-            return Err(TrackerError::config_error("Faucet only available on devnet/testnet"));
+            return Err(self.faucet_unavailable());
         };
 
         faucet
@@ -509,46 +1490,483 @@ impl SuiClient {
         Ok(())
     }
 
+    /// 当前网络的水龙头服务地址，主网等没有水龙头的网络返回 None
+    pub fn faucet_url(&self) -> Option<&'static str> {
+        if self.network_url.contains("devnet") {
+            Some("https://faucet.devnet.sui.io")
+        } else if self.network_url.contains("testnet") {
+            Some("https://faucet.testnet.sui.io")
+        } else {
+            None
+        }
+    }
+
+    /// 当前网络没有水龙头时的错误，主网单独说明
+    fn faucet_unavailable(&self) -> TrackerError {
+        if self.network_url.contains("mainnet") {
+            TrackerError::config_error("Faucet not available on mainnet; use a testnet or devnet RPC URL")
+        } else {
+            TrackerError::config_error(format!("Faucet only available on devnet/testnet (RPC URL: {})", self.network_url))
+        }
+    }
+
+    /// 检查水龙头服务是否可访问（不会申请代币）
+    pub async fn check_faucet(&self) -> TrackerResult<()> {
+        let url = self.faucet_url()
+            .ok_or_else(|| self.faucet_unavailable())?;
+
+        let response = self.faucet_http_client
+            .get(url)
+            .send()
+            .await
+            .map_err(|e| TrackerError::network_error(format!("Faucet unreachable: {}", e)))?;
+
+        if response.status().is_server_error() {
+            return Err(TrackerError::network_error(format!("Faucet returned HTTP {}", response.status())));
+        }
+        Ok(())
+    }
+
     /// 检查是否健康（兼容性方法）
     pub async fn is_healthy(&self) -> bool {
         self.health_check().await.unwrap_or(false)
     }
 
     /// 创建带超时的客户端（兼容性方法）
-    pub async fn with_timeout(network_url: &str, _timeout_seconds: u64) -> TrackerResult<Self> {
-        Self::new(network_url).await
+    pub async fn with_timeout(network_url: &str, timeout_seconds: u64) -> TrackerResult<Self> {
+        let mut client = Self::new(network_url).await?;
+        client.request_timeout = Duration::from_secs(timeout_seconds);
+        Ok(client)
+    }
+
+    /// 单次RPC请求的超时时间
+    pub fn request_timeout(&self) -> Duration {
+        self.request_timeout
     }
 
     /// 查询转移事件（兼容性方法）
     pub async fn query_transfer_events(&self, address: &str, limit: u32) -> TrackerResult<Vec<SuiEvent>> {
         let transactions = self.query_transactions(address, Some(limit as u16)).await?;
-        
-        // 转换为事件格式
-        let events: Vec<SuiEvent> = transactions
-            .into_iter()
-            .map(|tx| SuiEvent {
-                id: tx.digest.clone(),
-                package_id: "0x2".to_string(),
-                transaction_module: "sui".to_string(),
-                sender: address.to_string(),
-                recipient: tx.balance_changes.get(0)
-                    .map(|bc| bc.owner.clone())
-                    .unwrap_or_else(|| "unknown".to_string()),
-                amount: tx.balance_changes.get(0)
-                    .map(|bc| bc.amount.abs() as u64)
-                    .unwrap_or(0),
-                token_type: "0x2::sui::SUI".to_string(),
-                timestamp: tx.timestamp.map(|t| t.timestamp() as u64).unwrap_or(0),
-                block_number: 0,
-            })
-            .collect();
+        Ok(self.transactions_to_events(address, TransactionDirection::Sent, transactions))
+    }
+
+    /// 查询 `cursor`（交易digest）之后的转移事件，按时间从旧到新排列
+    ///
+    /// 没有游标时返回最新的一页。返回的游标指向本页最新的交易，没有新交易时保持不变。
+    pub async fn query_transfer_events_after(
+        &self,
+        address: &str,
+        cursor: Option<&str>,
+        limit: u32,
+    ) -> TrackerResult<EventPage> {
+        let limit = Some(limit.min(u16::MAX as u32) as u16);
+        let (mut transactions, has_next_page) = match cursor {
+            Some(cursor) => {
+                let page = self.query_transactions_page(address, TransactionDirection::Sent, Some(cursor), limit, false).await?;
+                (page.transactions, page.has_next_page)
+            }
+            None => {
+                let mut page = self.query_transactions_page(address, TransactionDirection::Sent, None, limit, true).await?;
+                page.transactions.reverse();
+                (page.transactions, false)
+            }
+        };
+        transactions.retain(|tx| !tx.digest.is_empty());
+
+        let cursor = transactions
+            .last()
+            .map(|tx| tx.digest.clone())
+            .or_else(|| cursor.map(str::to_string));
+
+        Ok(EventPage {
+            events: self.transactions_to_events(address, TransactionDirection::Sent, transactions),
+            cursor,
+            has_next_page,
+        })
+    }
+
+    /// 查询 `cursor` 之后由包/模块发出的事件，按时间从旧到新排列
+    ///
+    /// 没有游标时返回最新的一页。游标为 `txDigest:eventSeq` 形式，没有新事件时保持不变。
+    pub async fn query_package_events_after(
+        &self,
+        filter: &PackageFilter,
+        cursor: Option<&str>,
+        limit: u32,
+    ) -> TrackerResult<MoveEventPage> {
+        let limit = limit.min(u16::MAX as u32);
+        let descending = cursor.is_none();
+        let params = serde_json::json!([
+            filter.to_rpc_filter(),
+            cursor.map(MoveEventPage::cursor_to_json),
+            limit,
+            descending
+        ]);
+
+        let response: EventsResponse = self.send_rpc_request("suix_queryEvents", params).await?;
+        let mut events = response.data;
+        if descending {
+            events.reverse();
+        }
+
+        let next_cursor = events
+            .last()
+            .and_then(|event| event.get("id"))
+            .and_then(MoveEventPage::cursor_from_json)
+            .or_else(|| cursor.map(str::to_string));
+
+        log::debug!("Got {} events for package filter {}", events.len(), filter);
+        Ok(MoveEventPage {
+            events,
+            cursor: next_cursor,
+            has_next_page: !descending && response.has_next_page,
+        })
+    }
 
-        Ok(events)
+    /// 将地址的交易转换为转移事件，见 [`transactions_to_events`]
+    pub fn transactions_to_events(
+        &self,
+        address: &str,
+        direction: TransactionDirection,
+        transactions: Vec<SuiTransaction>,
+    ) -> Vec<SuiEvent> {
+        transactions_to_events(&self.network_name, address, direction, transactions)
     }
 }
 
-/// SUI事件结构（兼容性）
+impl SuiRpc for SuiClient {
+    fn network_name(&self) -> &str {
+        &self.network_name
+    }
+
+    fn get_balance<'a>(&'a self, address: &'a str, coin_type: Option<&'a str>) -> BoxFuture<'a, TrackerResult<u64>> {
+        Box::pin(SuiClient::get_balance(self, address, coin_type))
+    }
+
+    fn get_balance_uncached<'a>(&'a self, address: &'a str, coin_type: Option<&'a str>) -> BoxFuture<'a, TrackerResult<u64>> {
+        Box::pin(SuiClient::get_balance_uncached(self, address, coin_type))
+    }
+
+    fn get_balance_detailed<'a>(&'a self, address: &'a str, coin_type: Option<&'a str>) -> BoxFuture<'a, TrackerResult<BalanceDetail>> {
+        Box::pin(SuiClient::get_balance_detailed(self, address, coin_type))
+    }
+
+    fn get_all_balances_report<'a>(&'a self, address: &'a str) -> BoxFuture<'a, TrackerResult<AllBalances>> {
+        Box::pin(SuiClient::get_all_balances_report(self, address))
+    }
+
+    fn get_all_balances<'a>(&'a self, address: &'a str) -> BoxFuture<'a, TrackerResult<Vec<(String, u64)>>> {
+        Box::pin(SuiClient::get_all_balances(self, address))
+    }
+
+    fn query_transactions_sent<'a>(&'a self, address: &'a str, limit: Option<u16>) -> BoxFuture<'a, TrackerResult<Vec<SuiTransaction>>> {
+        Box::pin(SuiClient::query_transactions_sent(self, address, limit))
+    }
+
+    fn query_transactions_received<'a>(&'a self, address: &'a str, limit: Option<u16>) -> BoxFuture<'a, TrackerResult<Vec<SuiTransaction>>> {
+        Box::pin(SuiClient::query_transactions_received(self, address, limit))
+    }
+
+    fn query_transactions_since<'a>(
+        &'a self,
+        address: &'a str,
+        direction: TransactionDirection,
+        since: DateTime<Utc>,
+    ) -> BoxFuture<'a, TrackerResult<Vec<SuiTransaction>>> {
+        Box::pin(SuiClient::query_transactions_since(self, address, direction, since))
+    }
+
+    fn get_transaction<'a>(&'a self, digest: &'a str) -> BoxFuture<'a, TrackerResult<SuiTransaction>> {
+        Box::pin(SuiClient::get_transaction(self, digest))
+    }
+
+    fn get_chain_id(&self) -> BoxFuture<'_, TrackerResult<String>> {
+        Box::pin(SuiClient::get_chain_id(self))
+    }
+
+    fn get_latest_checkpoint(&self) -> BoxFuture<'_, TrackerResult<u64>> {
+        Box::pin(SuiClient::get_latest_checkpoint(self))
+    }
+
+    fn health_check(&self) -> BoxFuture<'_, TrackerResult<bool>> {
+        Box::pin(SuiClient::health_check(self))
+    }
+
+    fn is_healthy(&self) -> BoxFuture<'_, bool> {
+        Box::pin(SuiClient::is_healthy(self))
+    }
+
+    fn query_transfer_events<'a>(&'a self, address: &'a str, limit: u32) -> BoxFuture<'a, TrackerResult<Vec<SuiEvent>>> {
+        Box::pin(SuiClient::query_transfer_events(self, address, limit))
+    }
+
+    fn get_coins<'a>(
+        &'a self,
+        address: &'a str,
+        coin_type: Option<&'a str>,
+        cursor: Option<&'a str>,
+        limit: Option<u32>,
+    ) -> BoxFuture<'a, TrackerResult<(Vec<SuiCoin>, Option<String>)>> {
+        Box::pin(SuiClient::get_coins(self, address, coin_type, cursor, limit))
+    }
+
+    fn query_package_events_after<'a>(
+        &'a self,
+        filter: &'a PackageFilter,
+        cursor: Option<&'a str>,
+        limit: u32,
+    ) -> BoxFuture<'a, TrackerResult<MoveEventPage>> {
+        Box::pin(SuiClient::query_package_events_after(self, filter, cursor, limit))
+    }
+
+    fn query_transfer_events_after<'a>(
+        &'a self,
+        address: &'a str,
+        cursor: Option<&'a str>,
+        limit: u32,
+    ) -> BoxFuture<'a, TrackerResult<EventPage>> {
+        Box::pin(SuiClient::query_transfer_events_after(self, address, cursor, limit))
+    }
+
+    fn circuit_state(&self) -> CircuitState {
+        SuiClient::circuit_state(self)
+    }
+
+    fn take_circuit_transitions(&self) -> Vec<CircuitTransition> {
+        SuiClient::take_circuit_transitions(self)
+    }
+
+    fn rpc_latency_stats(&self) -> Vec<RpcLatencyStats> {
+        SuiClient::rpc_latency_stats(self)
+    }
+}
+
+/// SUI原生代币类型
+const SUI_COIN_TYPE: &str = "0x2::sui::SUI";
+
+/// 将地址的交易转换为转移事件
+///
+/// 发送的交易以该地址为发送方；接收的交易以该地址为接收方，发送方取余额减少的一方。
+/// 每种该地址有余额变化的代币各生成一个事件（同一交易的事件共用交易摘要作为ID），
+/// gas 只记在 SUI 事件上；该地址没有任何余额变化时按 SUI 生成一个事件。
+pub fn transactions_to_events(
+    network_name: &str,
+    address: &str,
+    direction: TransactionDirection,
+    transactions: Vec<SuiTransaction>,
+) -> Vec<SuiEvent> {
+    transactions
+        .into_iter()
+        .flat_map(|tx| {
+            let mut coin_types: Vec<String> = Vec::new();
+            for bc in tx.balance_changes.iter().filter(|bc| bc.owner == address) {
+                if !coin_types.contains(&bc.coin_type) {
+                    coin_types.push(bc.coin_type.clone());
+                }
+            }
+            if coin_types.is_empty() {
+                coin_types.push(SUI_COIN_TYPE.to_string());
+            }
+            coin_types
+                .into_iter()
+                .map(move |coin_type| coin_type_event(network_name, address, direction, &tx, &coin_type))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// 交易中某一种代币的转移事件，金额和对方只取该代币的余额变化
+fn coin_type_event(
+    network_name: &str,
+    address: &str,
+    direction: TransactionDirection,
+    tx: &SuiTransaction,
+    coin_type: &str,
+) -> SuiEvent {
+    let is_sui = coin_type == SUI_COIN_TYPE;
+
+    // 按余额变化的符号判断被查询地址是发送方还是接收方，没有变化时按查询方向判断
+    let own_change: i128 = tx.balance_changes.iter()
+        .filter(|bc| bc.owner == address && bc.coin_type == coin_type)
+        .map(|bc| bc.amount as i128)
+        .sum();
+    let is_recipient = own_change > 0 || (own_change == 0 && direction == TransactionDirection::Received);
+    let gas_only = is_sui && !is_recipient && is_gas_only(address, tx);
+
+    let (sender, recipient, amount) = if is_recipient {
+        let sender = resolve_sender(address, coin_type, &tx.balance_changes)
+            .map(|bc| bc.owner.clone())
+            .unwrap_or_else(|| "unknown".to_string());
+        (sender, address.to_string(), u64::try_from(own_change).unwrap_or(u64::MAX))
+    } else if gas_only {
+        (address.to_string(), address.to_string(), 0)
+    } else {
+        // 没有明确的接收方时留空，解析为转移事件时会被跳过
+        let (recipient, amount) = resolve_recipient(address, coin_type, &tx.balance_changes)
+            .map(|bc| (bc.owner.clone(), bc.amount.unsigned_abs()))
+            .unwrap_or_default();
+        (address.to_string(), recipient, amount)
+    };
+
+    SuiEvent {
+        id: tx.digest.clone(),
+        package_id: "0x2".to_string(),
+        transaction_module: "sui".to_string(),
+        sender,
+        recipient,
+        amount,
+        token_type: coin_type.to_string(),
+        timestamp: tx.timestamp.map(|t| t.timestamp() as u64).unwrap_or(0),
+        block_number: tx.checkpoint.unwrap_or(0),
+        gas_used: if is_sui { tx.gas_used.as_deref().and_then(|g| g.parse::<u64>().ok()) } else { None },
+        status: tx.status.clone(),
+        network: network_name.to_string(),
+        gas_only,
+    }
+}
+
+/// 发送交易的接收方：同一代币类型中不属于发送方、金额为正的余额变化中金额最大的一个
+///
+/// 多方交易的第一个余额变化往往是gas coin所有者或发送方本身，不能直接作为接收方；
+/// 其他代币的变化（如gas或同一交易中的其他转账）也不作为该事件的对方。
+fn resolve_recipient<'a>(sender: &str, coin_type: &str, balance_changes: &'a [BalanceChange]) -> Option<&'a BalanceChange> {
+    balance_changes
+        .iter()
+        .filter(|bc| bc.coin_type == coin_type && bc.amount > 0 && bc.owner != sender && bc.owner != "unknown")
+        .max_by_key(|bc| bc.amount)
+}
+
+/// 接收交易的发送方：同一代币类型中不属于接收方、金额为负的余额变化中减少最多的一个
+fn resolve_sender<'a>(recipient: &str, coin_type: &str, balance_changes: &'a [BalanceChange]) -> Option<&'a BalanceChange> {
+    balance_changes
+        .iter()
+        .filter(|bc| bc.coin_type == coin_type && bc.amount < 0 && bc.owner != recipient && bc.owner != "unknown")
+        .min_by_key(|bc| bc.amount)
+}
+
+/// 发送方只有不超过gas消耗的负SUI余额变化、且没有其他地址收到SUI时，交易的SUI部分只是支付gas
+fn is_gas_only(sender: &str, tx: &SuiTransaction) -> bool {
+    let Some(gas_used) = tx.gas_used.as_deref().and_then(|g| g.parse::<u64>().ok()) else {
+        return false;
+    };
+    let mut sender_changes = tx.balance_changes.iter()
+        .filter(|bc| bc.owner == sender && bc.coin_type == SUI_COIN_TYPE)
+        .peekable();
+    sender_changes.peek().is_some()
+        && sender_changes.all(|bc| bc.amount < 0 && bc.amount.unsigned_abs() <= gas_used)
+        && resolve_recipient(sender, SUI_COIN_TYPE, &tx.balance_changes).is_none()
+}
+
+/// 按时间范围查询时每页的交易数
+const SINCE_PAGE_SIZE: u16 = 50;
+
+/// 交易方向，相对于被查询的地址
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransactionDirection {
+    Sent,
+    Received,
+}
+
+impl TransactionDirection {
+    fn filter(self, address: &str) -> serde_json::Value {
+        match self {
+            TransactionDirection::Sent => serde_json::json!({ "FromAddress": address }),
+            TransactionDirection::Received => serde_json::json!({ "ToAddress": address }),
+        }
+    }
+}
+
+/// 按包或模块监控事件的过滤条件，写作 `0xPACKAGE` 或 `0xPACKAGE::module`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PackageFilter {
+    pub package_id: String,
+    pub module: Option<String>,
+}
+
+impl PackageFilter {
+    /// 解析 `0xPACKAGE` 或 `0xPACKAGE::module`，包ID按地址规则规范化
+    pub fn parse(filter: &str) -> Option<Self> {
+        let (package, module) = match filter.trim().split_once("::") {
+            Some((package, module)) => (package, Some(module)),
+            None => (filter.trim(), None),
+        };
+        let package_id = crate::config::Config::normalize_address(package)?;
+        let module = match module {
+            Some(module) if !module.is_empty() && module.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') => {
+                Some(module.to_string())
+            }
+            Some(_) => return None,
+            None => None,
+        };
+        Some(Self { package_id, module })
+    }
+
+    /// suix_queryEvents 的事件过滤条件
+    fn to_rpc_filter(&self) -> serde_json::Value {
+        match &self.module {
+            Some(module) => serde_json::json!({ "MoveModule": { "package": self.package_id, "module": module } }),
+            None => serde_json::json!({ "Package": self.package_id }),
+        }
+    }
+}
+
+impl std::fmt::Display for PackageFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.module {
+            Some(module) => write!(f, "{}::{}", self.package_id, module),
+            None => write!(f, "{}", self.package_id),
+        }
+    }
+}
+
+/// 基于游标的一页 Move 事件（suix_queryEvents 返回的原始JSON）
+#[derive(Debug, Clone)]
+pub struct MoveEventPage {
+    pub events: Vec<serde_json::Value>,
+    /// 下次查询使用的游标（最新已返回事件的 `txDigest:eventSeq`）
+    pub cursor: Option<String>,
+    /// 游标之后是否还有更多事件
+    pub has_next_page: bool,
+}
+
+impl MoveEventPage {
+    /// 事件ID `{"txDigest", "eventSeq"}` 编码为游标字符串
+    pub fn cursor_from_json(id: &serde_json::Value) -> Option<String> {
+        let digest = id.get("txDigest")?.as_str()?;
+        let sequence = match id.get("eventSeq")? {
+            serde_json::Value::String(sequence) => sequence.clone(),
+            serde_json::Value::Number(sequence) => sequence.to_string(),
+            _ => return None,
+        };
+        Some(format!("{}:{}", digest, sequence))
+    }
+
+    fn cursor_to_json(cursor: &str) -> serde_json::Value {
+        let (digest, sequence) = cursor.rsplit_once(':').unwrap_or((cursor, "0"));
+        serde_json::json!({ "txDigest": digest, "eventSeq": sequence })
+    }
+}
+
+/// 一页交易查询结果
+#[derive(Debug, Clone)]
+pub struct TransactionPage {
+    pub transactions: Vec<SuiTransaction>,
+    pub next_cursor: Option<String>,
+    pub has_next_page: bool,
+}
+
+/// 基于游标的一页转移事件
 #[derive(Debug, Clone)]
+pub struct EventPage {
+    pub events: Vec<SuiEvent>,
+    /// 下次查询使用的游标（最新已返回交易的digest）
+    pub cursor: Option<String>,
+    /// 游标之后是否还有更多交易
+    pub has_next_page: bool,
+}
+
+/// SUI事件结构（兼容性）
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SuiEvent {
     pub id: String,
     pub package_id: String,
@@ -559,6 +1977,15 @@ pub struct SuiEvent {
     pub token_type: String,
     pub timestamp: u64,
     pub block_number: u64,
+    /// 交易净gas消耗（MIST），由发送方支付
+    pub gas_used: Option<u64>,
+    pub status: TransactionStatus,
+    /// 事件来源网络名称
+    #[serde(default)]
+    pub network: String,
+    /// 只消耗gas、没有代币转移（接收方为发送方本身，金额为0）
+    #[serde(default)]
+    pub gas_only: bool,
 }
 
 #[cfg(test)]
@@ -600,4 +2027,462 @@ mod tests {
             }
         }
     }
+
+    #[tokio::test]
+    async fn test_rpc_request_timeout() {
+        // 只接受连接、从不响应的节点
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let mut connections = Vec::new();
+            while let Ok((socket, _)) = listener.accept().await {
+                connections.push(socket);
+            }
+        });
+
+        let mut client = SuiClient::with_timeout(&format!("http://{}", addr), 30).await.unwrap();
+        assert_eq!(client.request_timeout(), Duration::from_secs(30));
+        client.request_timeout = Duration::from_millis(200);
+
+        let started = Instant::now();
+        let result = client.send_rpc_request::<String>("sui_getChainIdentifier", serde_json::json!([])).await;
+        assert!(matches!(result, Err(TrackerError::TimeoutError(_))));
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_resolve_rpc_url() {
+        let custom = "https://sui-mainnet.example.com/v1/rpc";
+        assert_eq!(SuiClient::resolve_rpc_url(custom), custom);
+        assert_eq!(SuiClient::resolve_rpc_url("testnet"), "https://fullnode.testnet.sui.io:443");
+        assert_eq!(SuiClient::resolve_rpc_url("localnet"), "http://localhost:9000");
+    }
+
+    #[tokio::test]
+    async fn test_rpc_headers_sent_and_redacted() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        // 返回收到的请求头中是否带有API密钥
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = vec![0u8; 4096];
+            let n = socket.read(&mut buf).await.unwrap();
+            let request = String::from_utf8_lossy(&buf[..n]).to_lowercase();
+            let body = format!(r#"{{"jsonrpc":"2.0","id":1,"result":"{}"}}"#, request.contains("x-api-key: secret-key"));
+            let response = format!(
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n{}",
+                body.len(), body
+            );
+            socket.write_all(response.as_bytes()).await.unwrap();
+        });
+
+        // 请求发往配置的地址，而不是按网络名称选择的公共全节点
+        let mut client = SuiClient::with_timeout(&format!("http://{}", addr), 5).await.unwrap();
+        assert!(client.set_rpc_headers(&HashMap::from([("bad header".to_string(), "x".to_string())])).is_err());
+        client.set_rpc_headers(&HashMap::from([("x-api-key".to_string(), "secret-key".to_string())])).unwrap();
+
+        assert!(!format!("{:?}", client).contains("secret-key"));
+        let has_key = client.send_rpc_request::<String>("sui_getChainIdentifier", serde_json::json!([])).await.unwrap();
+        assert_eq!(has_key, "true");
+    }
+
+    #[test]
+    fn test_balance_detail_parsing() {
+        let response: SuiBalance = serde_json::from_value(serde_json::json!({
+            "coinType": "0x2::sui::SUI",
+            "coinObjectCount": 42,
+            "totalBalance": "5000000000",
+            "lockedBalance": {"10": "1000000000", "12": 500000000}
+        })).unwrap();
+        assert_eq!(response.coin_object_count, 42);
+        assert_eq!(SuiClient::sum_locked_balance(response.locked_balance.as_ref()), 1500000000);
+        assert_eq!(SuiClient::sum_locked_balance(Some(&serde_json::json!({}))), 0);
+        assert_eq!(SuiClient::sum_locked_balance(None), 0);
+
+        let detail = BalanceDetail {
+            coin_type: response.coin_type,
+            total_balance: 5000000000,
+            coin_object_count: 42,
+            locked_balance: 1500000000,
+        };
+        assert_eq!(detail.available_balance(), 3500000000);
+    }
+
+    #[test]
+    fn test_balance_cache_ttl() {
+        let detail = BalanceDetail {
+            coin_type: "0x2::sui::SUI".to_string(),
+            total_balance: 1000,
+            coin_object_count: 1,
+            locked_balance: 0,
+        };
+
+        // ttl 为零时不缓存
+        let disabled = BalanceCache::default();
+        disabled.insert("0xabc", "0x2::sui::SUI", &detail);
+        assert!(disabled.get("0xabc", "0x2::sui::SUI").is_none());
+
+        let cache = BalanceCache { ttl: Duration::from_millis(50), ..BalanceCache::default() };
+        cache.insert("0xabc", "0x2::sui::SUI", &detail);
+        assert_eq!(cache.get("0xabc", "0x2::sui::SUI").unwrap().total_balance, 1000);
+        assert!(cache.get("0xabc", "0xdef::coin::COIN").is_none());
+
+        std::thread::sleep(Duration::from_millis(60));
+        assert!(cache.get("0xabc", "0x2::sui::SUI").is_none());
+        assert_eq!(cache.hits.load(Ordering::Relaxed), 1);
+        assert_eq!(cache.misses.load(Ordering::Relaxed), 2);
+    }
+
+    #[test]
+    fn test_response_cache() {
+        let params = serde_json::json!(["0xabc", null, 10, true]);
+        let value = serde_json::json!({ "data": [], "hasNextPage": false });
+
+        // 不在允许列表中的方法和 ttl 为零时都不缓存
+        let disabled = ResponseCache {
+            methods: HashSet::from(["suix_queryTransactionBlocks".to_string()]),
+            ..ResponseCache::default()
+        };
+        assert!(disabled.key("suix_queryTransactionBlocks", &params).is_none());
+
+        let cache = ResponseCache {
+            ttl: Duration::from_millis(50),
+            methods: HashSet::from(["suix_queryTransactionBlocks".to_string()]),
+            ..ResponseCache::default()
+        };
+        assert!(cache.key("suix_getBalance", &params).is_none());
+
+        let key = cache.key("suix_queryTransactionBlocks", &params).unwrap();
+        assert_ne!(Some(key.clone()), cache.key("suix_queryTransactionBlocks", &serde_json::json!(["0xdef", null, 10, true])));
+        assert!(cache.get("suix_queryTransactionBlocks", &key).is_none());
+        cache.insert(key.clone(), &value);
+        assert_eq!(cache.get("suix_queryTransactionBlocks", &key), Some(value.clone()));
+
+        cache.clear();
+        assert!(cache.get("suix_queryTransactionBlocks", &key).is_none());
+
+        cache.insert(key.clone(), &value);
+        std::thread::sleep(Duration::from_millis(60));
+        assert!(cache.get("suix_queryTransactionBlocks", &key).is_none());
+        assert_eq!(cache.hits.load(Ordering::Relaxed), 1);
+        assert_eq!(cache.misses.load(Ordering::Relaxed), 3);
+    }
+
+    #[test]
+    fn test_all_balances_reports_unparsed() {
+        let response: Vec<SuiBalance> = serde_json::from_value(serde_json::json!([
+            { "coinType": "0x2::sui::SUI", "coinObjectCount": 2, "totalBalance": "1500", "lockedBalance": {} },
+            { "coinType": "0xa::big::BIG", "coinObjectCount": 1, "totalBalance": "99999999999999999999999", "lockedBalance": {} },
+            { "coinType": "0xb::bad::BAD", "coinObjectCount": 1, "totalBalance": "n/a", "lockedBalance": {} },
+        ])).unwrap();
+
+        let report = AllBalances::from_response(response);
+        assert_eq!(report.balances, vec![("0x2::sui::SUI".to_string(), 1500)]);
+        assert_eq!(report.unparsed.len(), 2);
+        assert_eq!(report.unparsed[0], ("0xa::big::BIG".to_string(), "99999999999999999999999".to_string()));
+        assert_eq!(report.unparsed[1].0, "0xb::bad::BAD");
+    }
+
+    #[test]
+    fn test_parse_transaction_block() {
+        let tx_data: TransactionBlockData = serde_json::from_value(serde_json::json!({
+            "digest": "digest1",
+            "timestampMs": "1700000000000",
+            "checkpoint": "1234",
+            "effects": {
+                "messageVersion": "v1",
+                "status": { "status": "failure", "error": "InsufficientGas" },
+                "executedEpoch": "1",
+                "gasUsed": {
+                    "computationCost": "1000",
+                    "storageCost": "500",
+                    "storageRebate": "300",
+                    "nonRefundableStorageFee": "10"
+                },
+                "transactionDigest": "digest1",
+                "balanceChanges": [
+                    { "owner": { "AddressOwner": "0xa" }, "coinType": "0x2::sui::SUI", "amount": "-1210" },
+                    { "owner": { "ObjectOwner": "0xobj" }, "coinType": "0x2::sui::SUI", "amount": "5" }
+                ]
+            }
+        })).unwrap();
+
+        let tx = SuiClient::parse_transaction_block(tx_data, "unknown");
+        assert_eq!(tx.digest, "digest1");
+        assert_eq!(tx.status, TransactionStatus::Failed);
+        assert_eq!(tx.gas_used.as_deref(), Some("1210"));
+        assert_eq!(tx.timestamp, chrono::DateTime::from_timestamp_millis(1_700_000_000_000));
+        assert_eq!(tx.balance_changes.len(), 2);
+        assert_eq!(tx.balance_changes[0].amount, -1210);
+        assert_eq!(tx.balance_changes[1].owner, "unknown");
+        assert_eq!(tx.checkpoint, Some(1234));
+    }
+
+    fn change(owner: &str, amount: i64) -> BalanceChange {
+        BalanceChange { owner: owner.to_string(), coin_type: "0x2::sui::SUI".to_string(), amount }
+    }
+
+    fn sent_event(balance_changes: Vec<BalanceChange>) -> SuiEvent {
+        query_event("0xsender", TransactionDirection::Sent, balance_changes)
+    }
+
+    fn query_event(address: &str, direction: TransactionDirection, balance_changes: Vec<BalanceChange>) -> SuiEvent {
+        let tx = SuiTransaction {
+            digest: "0xdigest".to_string(),
+            timestamp: None,
+            gas_used: Some("1000".to_string()),
+            balance_changes,
+            status: TransactionStatus::Success,
+            checkpoint: None,
+        };
+        transactions_to_events("testnet", address, direction, vec![tx]).remove(0)
+    }
+
+    #[test]
+    fn test_package_filter() {
+        let package = format!("0x{}", "a".repeat(64));
+        let filter = PackageFilter::parse(&format!("0x{}::pool", "A".repeat(64))).unwrap();
+        assert_eq!(filter.package_id, package);
+        assert_eq!(filter.module.as_deref(), Some("pool"));
+        assert_eq!(filter.to_rpc_filter(), serde_json::json!({ "MoveModule": { "package": package, "module": "pool" } }));
+        assert_eq!(filter.to_string(), format!("{}::pool", package));
+
+        let filter = PackageFilter::parse("0x2").unwrap();
+        assert_eq!(filter.to_rpc_filter(), serde_json::json!({ "Package": format!("0x{:0>64}", "2") }));
+
+        assert!(PackageFilter::parse("not-a-package").is_none());
+        assert!(PackageFilter::parse("0x2::").is_none());
+        assert!(PackageFilter::parse("0x2::bad-module").is_none());
+
+        let id = serde_json::json!({ "txDigest": "Dig3st", "eventSeq": "4" });
+        let cursor = MoveEventPage::cursor_from_json(&id).unwrap();
+        assert_eq!(cursor, "Dig3st:4");
+        assert_eq!(MoveEventPage::cursor_to_json(&cursor), id);
+    }
+
+    #[test]
+    fn test_coin_report() {
+        let response: CoinsResponse = serde_json::from_value(serde_json::json!({
+            "data": [
+                { "coinType": "0x2::sui::SUI", "coinObjectId": "0xc1", "version": "1", "digest": "d1",
+                  "balance": "7000000000", "previousTransaction": "t1" },
+                { "coinType": "0x2::sui::SUI", "coinObjectId": "0xc2", "version": "2", "digest": "d2",
+                  "balance": "2000000000", "previousTransaction": "t2" },
+                { "coinType": "0x2::sui::SUI", "coinObjectId": "0xc3", "version": "3", "digest": "d3",
+                  "balance": "1000000000", "previousTransaction": "t3" }
+            ],
+            "nextCursor": "0xc3",
+            "hasNextPage": false
+        })).unwrap();
+        assert_eq!(response.data[0].balance, 7_000_000_000);
+
+        let report = CoinReport::from_coins("0xa", "0x2::sui::SUI", &response.data);
+        assert_eq!(report.coin_count, 3);
+        assert_eq!(report.total_balance, 10_000_000_000);
+        assert_eq!(report.largest_coin, 7_000_000_000);
+        assert_eq!(report.smallest_coin, 1_000_000_000);
+        assert!((report.fragmentation_score - 0.3).abs() < 1e-9);
+
+        let empty = CoinReport::from_coins("0xa", "0x2::sui::SUI", &[]);
+        assert_eq!(empty.coin_count, 0);
+        assert_eq!(empty.fragmentation_score, 0.0);
+    }
+
+    #[test]
+    fn test_transactions_to_events_direction_from_signs() {
+        // 接收查询：被查询地址增加，减少最多的其他地址是发送方
+        let event = query_event("0xbob", TransactionDirection::Received, vec![
+            change("0xgas", -1_000),
+            change("0xalice", -3_001_000),
+            change("0xbob", 3_000_000),
+        ]);
+        assert_eq!((event.sender.as_str(), event.recipient.as_str()), ("0xalice", "0xbob"));
+        assert_eq!(event.amount, 3_000_000);
+
+        // 接收查询中被查询地址减少时，它才是发送方
+        let event = query_event("0xbob", TransactionDirection::Received, vec![
+            change("0xbob", -2_001_000),
+            change("0xcarol", 2_000_000),
+        ]);
+        assert_eq!((event.sender.as_str(), event.recipient.as_str()), ("0xbob", "0xcarol"));
+        assert_eq!(event.amount, 2_000_000);
+
+        // 发送查询中被查询地址增加（如从合约提取），它是接收方
+        let event = query_event("0xsender", TransactionDirection::Sent, vec![
+            change("0xpool", -7_000_000),
+            change("0xsender", 6_999_000),
+        ]);
+        assert_eq!((event.sender.as_str(), event.recipient.as_str()), ("0xpool", "0xsender"));
+        assert_eq!(event.amount, 6_999_000);
+        assert!(!event.gas_only);
+
+        // 没有余额变化时按查询方向判断，发送方未知
+        let event = query_event("0xbob", TransactionDirection::Received, vec![change("unknown", -1_000)]);
+        assert_eq!((event.sender.as_str(), event.recipient.as_str()), ("unknown", "0xbob"));
+        assert_eq!(event.amount, 0);
+    }
+
+    #[test]
+    fn test_transactions_to_events_recipient_resolution() {
+        // 简单转账：发送方减少（含gas），接收方增加
+        let event = sent_event(vec![change("0xsender", -501_000), change("0xbob", 500_000)]);
+        assert_eq!(event.recipient, "0xbob");
+        assert_eq!(event.amount, 500_000);
+
+        // 第一个余额变化是发送方自己时不能当作接收方
+        let event = sent_event(vec![change("0xsender", -2_001_000), change("0xgas", 1_000), change("0xcarol", 2_000_000)]);
+        assert_eq!(event.recipient, "0xcarol");
+        assert_eq!(event.amount, 2_000_000);
+
+        // 只支付gas的交易标记为 gas_only，接收方为发送方本身
+        let event = sent_event(vec![change("0xsender", -1_000)]);
+        assert!(event.gas_only);
+        assert_eq!(event.recipient, "0xsender");
+        assert_eq!(event.amount, 0);
+
+        // 发送方减少的金额超过gas但没有接收方，不是gas交易也不是可识别的转移
+        let event = sent_event(vec![change("0xsender", -50_000)]);
+        assert!(!event.gas_only);
+        assert!(event.recipient.is_empty());
+
+        // 所有者无法识别的变化不作为接收方
+        let event = sent_event(vec![change("0xsender", -5_000), change("unknown", 4_000)]);
+        assert!(event.recipient.is_empty());
+
+        // 其他代币的变化不作为SUI事件的对方
+        let usdc = |owner: &str, amount| BalanceChange { owner: owner.to_string(), coin_type: "0xabc::usdc::USDC".to_string(), amount };
+        let event = sent_event(vec![change("0xsender", -501_000), usdc("0xdave", 9_000_000), change("0xbob", 500_000)]);
+        assert_eq!(event.recipient, "0xbob");
+        let event = query_event("0xbob", TransactionDirection::Received, vec![
+            usdc("0xdave", -9_000_000),
+            change("0xalice", -3_001_000),
+            change("0xbob", 3_000_000),
+        ]);
+        assert_eq!(event.sender, "0xalice");
+    }
+
+    #[test]
+    fn test_transactions_to_events_per_coin_type() {
+        // 转出USDC并用SUI支付gas：USDC事件只含USDC金额，SUI部分只是gas
+        let usdc_type = "0xabc::usdc::USDC";
+        let usdc = |owner: &str, amount| BalanceChange { owner: owner.to_string(), coin_type: usdc_type.to_string(), amount };
+        let tx = SuiTransaction {
+            digest: "0xdigest".to_string(),
+            timestamp: None,
+            gas_used: Some("1000".to_string()),
+            balance_changes: vec![change("0xsender", -1_000), usdc("0xsender", -9_000_000), usdc("0xbob", 9_000_000)],
+            status: TransactionStatus::Success,
+            checkpoint: None,
+        };
+        let events = transactions_to_events("testnet", "0xsender", TransactionDirection::Sent, vec![tx]);
+        assert_eq!(events.len(), 2);
+
+        let sui = &events[0];
+        assert_eq!(sui.token_type, "0x2::sui::SUI");
+        assert!(sui.gas_only);
+        assert_eq!(sui.gas_used, Some(1_000));
+
+        let token = &events[1];
+        assert_eq!(token.id, sui.id);
+        assert_eq!(token.token_type, usdc_type);
+        assert_eq!((token.sender.as_str(), token.recipient.as_str()), ("0xsender", "0xbob"));
+        assert_eq!(token.amount, 9_000_000);
+        assert!(!token.gas_only);
+        assert_eq!(token.gas_used, None);
+    }
+
+    #[test]
+    fn test_sui_transaction_json_roundtrip() {
+        let tx = SuiTransaction {
+            digest: "digest1".to_string(),
+            timestamp: chrono::DateTime::from_timestamp_millis(1_700_000_000_000),
+            gas_used: Some("1000".to_string()),
+            balance_changes: vec![BalanceChange {
+                owner: "0x1".to_string(),
+                coin_type: "0x2::sui::SUI".to_string(),
+                amount: -5_000,
+            }],
+            status: TransactionStatus::Success,
+            checkpoint: Some(42),
+        };
+
+        let json = serde_json::to_string(&tx).unwrap();
+        assert!(json.contains("2023-11-14T22:13:20+00:00"));
+
+        let restored = SuiTransaction::from_json(&json).unwrap();
+        assert_eq!(restored.digest, tx.digest);
+        assert_eq!(restored.timestamp, tx.timestamp);
+        assert_eq!(restored.balance_changes[0].amount, -5_000);
+        assert_eq!(restored.status, TransactionStatus::Success);
+        assert_eq!(restored.checkpoint, Some(42));
+
+        let no_timestamp = SuiTransaction::from_json(
+            r#"{"digest":"d","gas_used":null,"balance_changes":[],"status":"Pending"}"#
+        ).unwrap();
+        assert!(no_timestamp.timestamp.is_none());
+        assert!(SuiTransaction::from_json("{}").is_err());
+    }
+
+    #[test]
+    fn test_circuit_breaker() {
+        let breaker = CircuitBreaker::new(CircuitBreakerConfig {
+            failure_threshold: 3,
+            failure_window: Duration::from_secs(60),
+            cooldown: Duration::from_millis(50),
+        });
+
+        // 成功会清空连续失败计数
+        breaker.record(false);
+        breaker.record(false);
+        breaker.record(true);
+        breaker.record(false);
+        breaker.record(false);
+        assert_eq!(breaker.state(), CircuitState::Closed);
+
+        breaker.record(false);
+        assert_eq!(breaker.state(), CircuitState::Open);
+        assert_eq!(breaker.take_transitions(), vec![CircuitTransition::Opened { consecutive_failures: 3 }]);
+        assert!(matches!(breaker.before_request("test"), Err(TrackerError::CircuitOpen(_))));
+
+        // 冷却后只放行一次试探请求，试探失败则继续熔断
+        std::thread::sleep(Duration::from_millis(60));
+        let trial = breaker.before_request("test").unwrap();
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+        assert!(breaker.before_request("test").is_err());
+        trial.finish(false);
+        assert_eq!(breaker.state(), CircuitState::Open);
+        assert!(breaker.take_transitions().is_empty());
+
+        // 试探请求被取消时释放名额，下一个请求成为新的试探
+        std::thread::sleep(Duration::from_millis(60));
+        drop(breaker.before_request("test").unwrap());
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+        let trial = breaker.before_request("test").unwrap();
+        trial.finish(true);
+        assert_eq!(breaker.state(), CircuitState::Closed);
+        assert!(matches!(breaker.take_transitions()[..], [CircuitTransition::Closed { .. }]));
+    }
+
+    #[test]
+    fn test_rpc_latency_stats() {
+        let tracker = RpcLatencyTracker::default();
+        for ms in 1..=100 {
+            tracker.record("suix_getBalance", Duration::from_millis(ms));
+        }
+        tracker.record("sui_getChainIdentifier", Duration::from_millis(7));
+
+        let stats = tracker.stats();
+        assert_eq!(stats.len(), 2);
+        assert_eq!(stats[0].method, "sui_getChainIdentifier");
+        assert_eq!(stats[0].p95, Duration::from_millis(7));
+
+        let balance = &stats[1];
+        assert_eq!(balance.count, 100);
+        assert_eq!(balance.min, Duration::from_millis(1));
+        assert_eq!(balance.max, Duration::from_millis(100));
+        assert_eq!(balance.p50, Duration::from_millis(50));
+        assert_eq!(balance.p95, Duration::from_millis(95));
+    }
 }
\ No newline at end of file
@@ -1,24 +1,108 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
 use tokio::sync::RwLock;
 use crate::event_monitor::TransferEvent;
 use crate::error::{TrackerError, TrackerResult};
 use serde::{Deserialize, Serialize};
 use chrono::Utc;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::io::Write;
+use std::path::Path;
+use std::time::Instant;
+
+/// SUI原生代币类型，gas以该代币支付
+const SUI_COIN_TYPE: &str = "0x2::sui::SUI";
+
+/// 计算处理耗时分位数时保留的最近样本数
+const MAX_PROCESSING_SAMPLES: usize = 1000;
 
 #[derive(Debug)]
 pub struct TransactionProcessor {
     address_balances: RwLock<HashMap<String, u64>>,
-    transaction_history: RwLock<HashMap<String, Vec<Transaction>>>,
+    /// 按代币类型拆分的地址余额（地址 -> 代币类型 -> 余额），用于与链上余额逐币种对账
+    token_balances: RwLock<HashMap<String, HashMap<String, u64>>>,
+    transaction_history: RwLock<HashMap<String, VecDeque<Transaction>>>,
     address_stats: RwLock<HashMap<String, AddressStats>>,
+    /// 因超出 max_history_records 而被淘汰的历史记录数
+    evicted_count: AtomicU64,
+    /// 按来源网络汇总的交易数和交易量
+    network_stats: RwLock<BTreeMap<String, NetworkStats>>,
+    /// 待确认的交易（交易ID -> 各代币类型的记录），确认后由 update_transaction_status 移除
+    pending_transactions: RwLock<HashMap<String, Vec<Transaction>>>,
+    /// 每个事件的处理耗时
+    processing_times: RwLock<ProcessingTimeHistogram>,
     config: ProcessorConfig,
 }
 
+/// 事件处理耗时的累计统计（纳秒）
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProcessingTimeStats {
+    pub count: u64,
+    pub total_ns: u64,
+    pub max_ns: u64,
+    /// 基于最近样本计算的95分位
+    pub p95_ns: u64,
+}
+
+impl ProcessingTimeStats {
+    pub fn mean_ns(&self) -> u64 {
+        self.total_ns.checked_div(self.count).unwrap_or(0)
+    }
+}
+
+#[derive(Debug, Default)]
+struct ProcessingTimeHistogram {
+    count: u64,
+    total_ns: u64,
+    max_ns: u64,
+    /// 最近的样本，用于计算分位数
+    samples: VecDeque<u64>,
+}
+
+impl ProcessingTimeHistogram {
+    fn record(&mut self, elapsed_ns: u64) {
+        self.count += 1;
+        self.total_ns = self.total_ns.saturating_add(elapsed_ns);
+        self.max_ns = self.max_ns.max(elapsed_ns);
+        if self.samples.len() >= MAX_PROCESSING_SAMPLES {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(elapsed_ns);
+    }
+
+    fn stats(&self) -> ProcessingTimeStats {
+        let mut sorted: Vec<u64> = self.samples.iter().copied().collect();
+        sorted.sort_unstable();
+        // 最近秩法
+        let p95_ns = if sorted.is_empty() {
+            0
+        } else {
+            sorted[(sorted.len() * 95).div_ceil(100).max(1) - 1]
+        };
+        ProcessingTimeStats {
+            count: self.count,
+            total_ns: self.total_ns,
+            max_ns: self.max_ns,
+            p95_ns,
+        }
+    }
+}
+
+/// 单个网络的交易汇总
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NetworkStats {
+    pub total_transactions: u64,
+    pub total_volume: u64,
+}
+
 #[derive(Debug, Clone)]
 pub struct ProcessorConfig {
     pub max_history_records: u32,
     pub cleanup_interval_hours: u64,
     pub enable_detailed_stats: bool,
+    /// 失败的交易不计入地址统计和交易量
+    pub exclude_failed_from_volume: bool,
+    /// JSON导出是否缩进
+    pub export_json_pretty: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -33,21 +117,68 @@ pub struct Transaction {
     pub gas_used: Option<u64>,
     pub gas_price: Option<u64>,
     pub status: TransactionStatus,
+    /// 来源网络名称
+    #[serde(default)]
+    pub network: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TransactionStatus {
+    #[default]
     Success,
     Failed,
     Pending,
 }
 
+/// 交易搜索条件，所有字段均可选，多个条件同时满足才匹配
+#[derive(Debug, Clone, Default)]
+pub struct SearchCriteria {
+    /// 发送方或接收方
+    pub address: Option<String>,
+    pub min_amount: Option<u64>,
+    pub max_amount: Option<u64>,
+    pub token_type: Option<String>,
+    pub start_ts: Option<u64>,
+    pub end_ts: Option<u64>,
+    pub status: Option<TransactionStatus>,
+    /// 最多返回的条数，未设置且没有任何过滤条件时使用 DEFAULT_SEARCH_LIMIT
+    pub limit: Option<usize>,
+}
+
+pub const DEFAULT_SEARCH_LIMIT: usize = 100;
+
+impl SearchCriteria {
+    pub fn is_empty(&self) -> bool {
+        self.address.is_none()
+            && self.min_amount.is_none()
+            && self.max_amount.is_none()
+            && self.token_type.is_none()
+            && self.start_ts.is_none()
+            && self.end_ts.is_none()
+            && self.status.is_none()
+    }
+
+    pub fn matches(&self, tx: &Transaction) -> bool {
+        self.address.as_ref().is_none_or(|a| &tx.sender == a || &tx.recipient == a)
+            && self.min_amount.is_none_or(|min| tx.amount >= min)
+            && self.max_amount.is_none_or(|max| tx.amount <= max)
+            && self.token_type.as_ref().is_none_or(|t| &tx.token_type == t)
+            && self.start_ts.is_none_or(|start| tx.timestamp >= start)
+            && self.end_ts.is_none_or(|end| tx.timestamp <= end)
+            && self.status.as_ref().is_none_or(|s| &tx.status == s)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProcessedTransaction {
     pub transaction: Transaction,
     pub sender_balance_change: i64,
     pub receiver_balance_change: i64,
     pub processing_time_ms: u64,
+    /// 处理耗时（纳秒），毫秒值对单个事件通常为0
+    pub processing_time_ns: u64,
+    /// 处理本笔交易之前发送方最后一次交易的时间，用于识别休眠地址重新活跃
+    pub sender_last_transaction: Option<u64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -60,12 +191,58 @@ pub struct AddressStats {
     pub average_transaction_amount: u64,
     pub largest_transaction: u64,
     pub smallest_transaction: u64,
+    /// 作为发送方累计支付的gas（MIST）
+    #[serde(default)]
+    pub total_gas_spent: u64,
+}
+
+impl Default for AddressStats {
+    fn default() -> Self {
+        Self {
+            total_transactions: 0,
+            total_sent: 0,
+            total_received: 0,
+            first_transaction: None,
+            last_transaction: None,
+            average_transaction_amount: 0,
+            largest_transaction: 0,
+            smallest_transaction: u64::MAX,
+            total_gas_spent: 0,
+        }
+    }
+}
+
+impl AddressStats {
+    /// 累计净流入（收到减去发出）
+    pub fn net_flow(&self) -> i64 {
+        net_flow(self.total_received, self.total_sent)
+    }
+}
+
+/// 以 i128 计算收到减去发出，再饱和到 i64，避免 u64 相减溢出
+pub fn net_flow(received: u64, sent: u64) -> i64 {
+    let net = received as i128 - sent as i128;
+    net.clamp(i64::MIN as i128, i64::MAX as i128) as i64
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BalanceHistory {
     pub address: String,
     pub history: Vec<BalanceSnapshot>,
+    /// 重建快照时使用的锚点
+    pub anchor: BalanceAnchor,
+}
+
+/// 余额历史的锚定方式
+///
+/// 本地只记录观测到的交易，需要一个已知余额作为起点才能还原真实余额。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "mode", content = "balance", rename_all = "snake_case")]
+pub enum BalanceAnchor {
+    /// 以最早一笔交易之前的余额为起点向后推算
+    Start(u64),
+    /// 以最新一笔交易之后的余额（通常为链上当前余额）为终点向前回推
+    Latest(u64),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -81,33 +258,51 @@ impl TransactionProcessor {
             max_history_records: 1000,
             cleanup_interval_hours: 24,
             enable_detailed_stats: true,
+            exclude_failed_from_volume: true,
+            export_json_pretty: true,
         })
     }
 
     pub fn with_config(config: ProcessorConfig) -> Self {
         Self {
             address_balances: RwLock::new(HashMap::new()),
+            token_balances: RwLock::new(HashMap::new()),
             transaction_history: RwLock::new(HashMap::new()),
             address_stats: RwLock::new(HashMap::new()),
+            evicted_count: AtomicU64::new(0),
+            network_stats: RwLock::new(BTreeMap::new()),
+            pending_transactions: RwLock::new(HashMap::new()),
+            processing_times: RwLock::new(ProcessingTimeHistogram::default()),
             config,
         }
     }
 
     pub async fn process_transfer_event(&self, event: TransferEvent) -> TrackerResult<ProcessedTransaction> {
-        let start_time = SystemTime::now();
-        let processing_start = start_time.duration_since(UNIX_EPOCH).unwrap().as_millis() as u64;
+        let processing_start = Instant::now();
 
         let mut balances = self.address_balances.write().await;
         let mut history = self.transaction_history.write().await;
         let mut stats = self.address_stats.write().await;
 
-        // 更新发送方余额
+        // 更新发送方余额；SUI转账的gas同样从发送方余额中扣除
+        let gas_paid = if event.token_type == SUI_COIN_TYPE {
+            event.gas_used.unwrap_or(0)
+        } else {
+            0
+        };
+        // 失败的交易不转移金额，但发送方仍需支付gas
+        let transferred = if event.status == TransactionStatus::Failed { 0 } else { event.amount };
         let sender_balance = balances.entry(event.sender.clone()).or_insert(0);
-        *sender_balance = sender_balance.saturating_sub(event.amount);
+        *sender_balance = sender_balance.saturating_sub(transferred.saturating_add(gas_paid));
 
         // 更新接收方余额
         let receiver_balance = balances.entry(event.recipient.clone()).or_insert(0);
-        *receiver_balance = receiver_balance.saturating_add(event.amount);
+        *receiver_balance = receiver_balance.saturating_add(transferred);
+
+        let mut token_balances = self.token_balances.write().await;
+        Self::adjust_token_balance(&mut token_balances, &event.sender, &event.token_type, transferred.saturating_add(gas_paid), false);
+        Self::adjust_token_balance(&mut token_balances, &event.recipient, &event.token_type, transferred, true);
+        drop(token_balances);
 
         // 创建交易记录
         let transaction = Transaction {
@@ -118,37 +313,145 @@ impl TransactionProcessor {
             token_type: event.token_type,
             timestamp: event.timestamp,
             block_number: event.block_number,
-            gas_used: None, // 可以从交易详情中获取
+            gas_used: event.gas_used,
             gas_price: None, // 可以从交易详情中获取
-            status: TransactionStatus::Success,
+            status: event.status,
+            network: event.network,
         };
 
-        // 添加到历史记录
-        history.entry(event.sender.clone())
-            .or_insert_with(Vec::new)
-            .push(transaction.clone());
-        
-        history.entry(event.recipient.clone())
-            .or_insert_with(Vec::new)
-            .push(transaction.clone());
+        if transaction.status == TransactionStatus::Pending {
+            self.pending_transactions.write().await.entry(transaction.id.clone()).or_default().push(transaction.clone());
+        }
+
+        // 添加到历史记录，超出上限时淘汰最旧的记录
+        self.push_history(&mut history, &event.sender, transaction.clone());
+        // 自转账只记录一次
+        if event.recipient != event.sender {
+            self.push_history(&mut history, &event.recipient, transaction.clone());
+        }
 
         // 更新统计信息
+        let sender_last_transaction = stats.get(&event.sender).and_then(|s| s.last_transaction);
         self.update_address_stats(&mut stats, &event.sender, &event.recipient, &transaction).await?;
+        if self.counts_as_volume(&transaction) {
+            let mut network_stats = self.network_stats.write().await;
+            let entry = network_stats.entry(transaction.network.clone()).or_default();
+            entry.total_transactions += 1;
+            entry.total_volume = entry.total_volume.saturating_add(transaction.amount);
+        }
 
-        // 处理历史记录限制
-        self.enforce_history_limits(&mut history).await;
-
-        let processing_end = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as u64;
-        let processing_time = processing_end.saturating_sub(processing_start);
+        let elapsed = processing_start.elapsed();
+        let processing_time_ns = u64::try_from(elapsed.as_nanos()).unwrap_or(u64::MAX);
+        self.processing_times.write().await.record(processing_time_ns);
 
         Ok(ProcessedTransaction {
             transaction,
-            sender_balance_change: -(event.amount as i64),
-            receiver_balance_change: event.amount as i64,
-            processing_time_ms: processing_time,
+            sender_balance_change: -(transferred.saturating_add(gas_paid) as i64),
+            receiver_balance_change: transferred as i64,
+            processing_time_ms: elapsed.as_millis() as u64,
+            processing_time_ns,
+            sender_last_transaction,
         })
     }
 
+    /// 待确认交易的ID列表，用于重新查询确认状态
+    pub async fn pending_transaction_ids(&self) -> Vec<String> {
+        let pending = self.pending_transactions.read().await;
+        let mut ids: Vec<String> = pending.keys().cloned().collect();
+        ids.sort();
+        ids
+    }
+
+    /// 待确认交易的记录（同一交易有多种代币时返回第一条），已确认或不存在时返回 None
+    pub async fn get_pending_transaction(&self, tx_id: &str) -> Option<Transaction> {
+        self.pending_transactions.read().await.get(tx_id).and_then(|records| records.first()).cloned()
+    }
+
+    /// 更新待确认交易的最终状态
+    ///
+    /// 只接受 Pending 交易转为 Success 或 Failed，同一交易各代币类型的记录一起更新。转为 Failed 时
+    /// 退回已转移的金额（gas 仍由发送方承担），并在 exclude_failed_from_volume 时从统计和交易量中扣除。
+    pub async fn update_transaction_status(&self, tx_id: &str, new_status: TransactionStatus) -> TrackerResult<()> {
+        if new_status == TransactionStatus::Pending {
+            return Err(TrackerError::validation_error(format!(
+                "Transaction {} can only move from Pending to Success or Failed", tx_id
+            )));
+        }
+
+        let mut balances = self.address_balances.write().await;
+        let mut history = self.transaction_history.write().await;
+        let mut stats = self.address_stats.write().await;
+        let mut pending = self.pending_transactions.write().await;
+
+        let transactions = pending.remove(tx_id).ok_or_else(|| TrackerError::validation_error(
+            format!("Transaction {} is not pending", tx_id)
+        ))?;
+
+        for transaction in transactions {
+            self.apply_final_status(&mut balances, &mut history, &mut stats, transaction, &new_status).await;
+        }
+
+        log::debug!("Transaction {} confirmed as {:?}", tx_id, new_status);
+        Ok(())
+    }
+
+    /// 将一条待确认记录更新为最终状态，Failed 时退回金额并扣除交易量
+    async fn apply_final_status(
+        &self,
+        balances: &mut HashMap<String, u64>,
+        history: &mut HashMap<String, VecDeque<Transaction>>,
+        stats: &mut HashMap<String, AddressStats>,
+        mut transaction: Transaction,
+        new_status: &TransactionStatus,
+    ) {
+        let tx_id = transaction.id.clone();
+        for address in [&transaction.sender, &transaction.recipient] {
+            if let Some(records) = history.get_mut(address) {
+                for record in records.iter_mut().filter(|record| record.id == tx_id && record.token_type == transaction.token_type) {
+                    record.status = new_status.clone();
+                }
+            }
+        }
+
+        if *new_status == TransactionStatus::Failed {
+            let sender_balance = balances.entry(transaction.sender.clone()).or_insert(0);
+            *sender_balance = sender_balance.saturating_add(transaction.amount);
+            let receiver_balance = balances.entry(transaction.recipient.clone()).or_insert(0);
+            *receiver_balance = receiver_balance.saturating_sub(transaction.amount);
+            let mut token_balances = self.token_balances.write().await;
+            Self::adjust_token_balance(&mut token_balances, &transaction.sender, &transaction.token_type, transaction.amount, true);
+            Self::adjust_token_balance(&mut token_balances, &transaction.recipient, &transaction.token_type, transaction.amount, false);
+            drop(token_balances);
+
+            transaction.status = TransactionStatus::Failed;
+            if !self.counts_as_volume(&transaction) {
+                Self::revert_volume_stats(stats, &transaction);
+                let mut network_stats = self.network_stats.write().await;
+                if let Some(entry) = network_stats.get_mut(&transaction.network) {
+                    entry.total_transactions = entry.total_transactions.saturating_sub(1);
+                    entry.total_volume = entry.total_volume.saturating_sub(transaction.amount);
+                }
+            }
+        }
+    }
+
+    /// 从地址统计中扣除一笔已计入交易量的交易（gas 保留）
+    fn revert_volume_stats(stats: &mut HashMap<String, AddressStats>, transaction: &Transaction) {
+        if let Some(sender_stats) = stats.get_mut(&transaction.sender) {
+            sender_stats.total_transactions = sender_stats.total_transactions.saturating_sub(1);
+            if transaction.sender != transaction.recipient {
+                sender_stats.total_sent = sender_stats.total_sent.saturating_sub(transaction.amount);
+            }
+        }
+        if transaction.sender != transaction.recipient {
+            if let Some(receiver_stats) = stats.get_mut(&transaction.recipient) {
+                receiver_stats.total_transactions = receiver_stats.total_transactions.saturating_sub(1);
+                receiver_stats.total_received = receiver_stats.total_received.saturating_sub(transaction.amount);
+            }
+        }
+        Self::update_average_amounts(stats);
+    }
+
     async fn update_address_stats(
         &self,
         stats: &mut HashMap<String, AddressStats>,
@@ -156,20 +459,36 @@ impl TransactionProcessor {
         recipient: &str,
         transaction: &Transaction,
     ) -> TrackerResult<()> {
+        // 不计入交易量的失败交易只累计发送方支付的gas
+        if !self.counts_as_volume(transaction) {
+            let sender_stats = stats.entry(sender.to_string()).or_default();
+            sender_stats.total_gas_spent = sender_stats.total_gas_spent.saturating_add(transaction.gas_used.unwrap_or(0));
+            return Ok(());
+        }
+
+        // 自转账只计一笔交易，资金没有离开地址，不计入发送和接收金额
+        if sender == recipient {
+            let address_stats = stats.entry(sender.to_string()).or_default();
+            address_stats.total_transactions += 1;
+            address_stats.total_gas_spent = address_stats.total_gas_spent.saturating_add(transaction.gas_used.unwrap_or(0));
+            address_stats.largest_transaction = address_stats.largest_transaction.max(transaction.amount);
+            address_stats.smallest_transaction = address_stats.smallest_transaction.min(transaction.amount);
+            if address_stats.first_transaction.is_none_or(|first| transaction.timestamp < first) {
+                address_stats.first_transaction = Some(transaction.timestamp);
+            }
+            if address_stats.last_transaction.is_none_or(|last| transaction.timestamp > last) {
+                address_stats.last_transaction = Some(transaction.timestamp);
+            }
+            Self::update_average_amounts(stats);
+            return Ok(());
+        }
+
         // 更新发送方统计
-        let sender_stats = stats.entry(sender.to_string()).or_insert(AddressStats {
-            total_transactions: 0,
-            total_sent: 0,
-            total_received: 0,
-            first_transaction: None,
-            last_transaction: None,
-            average_transaction_amount: 0,
-            largest_transaction: 0,
-            smallest_transaction: u64::MAX,
-        });
+        let sender_stats = stats.entry(sender.to_string()).or_default();
 
         sender_stats.total_transactions += 1;
         sender_stats.total_sent += transaction.amount;
+        sender_stats.total_gas_spent = sender_stats.total_gas_spent.saturating_add(transaction.gas_used.unwrap_or(0));
         sender_stats.largest_transaction = sender_stats.largest_transaction.max(transaction.amount);
         sender_stats.smallest_transaction = sender_stats.smallest_transaction.min(transaction.amount);
         
@@ -181,16 +500,7 @@ impl TransactionProcessor {
         }
 
         // 更新接收方统计
-        let receiver_stats = stats.entry(recipient.to_string()).or_insert(AddressStats {
-            total_transactions: 0,
-            total_sent: 0,
-            total_received: 0,
-            first_transaction: None,
-            last_transaction: None,
-            average_transaction_amount: 0,
-            largest_transaction: 0,
-            smallest_transaction: u64::MAX,
-        });
+        let receiver_stats = stats.entry(recipient.to_string()).or_default();
 
         receiver_stats.total_transactions += 1;
         receiver_stats.total_received += transaction.amount;
@@ -204,38 +514,93 @@ impl TransactionProcessor {
             receiver_stats.last_transaction = Some(transaction.timestamp);
         }
 
-        // 计算平均交易金额
+        Self::update_average_amounts(stats);
+
+        Ok(())
+    }
+
+    /// 计算平均交易金额
+    fn update_average_amounts(stats: &mut HashMap<String, AddressStats>) {
         for (_, address_stats) in stats.iter_mut() {
             if address_stats.total_transactions > 0 {
                 let total_amount = address_stats.total_sent + address_stats.total_received;
                 address_stats.average_transaction_amount = total_amount / address_stats.total_transactions;
             }
         }
+    }
 
-        Ok(())
+    fn counts_as_volume(&self, transaction: &Transaction) -> bool {
+        transaction.status != TransactionStatus::Failed || !self.config.exclude_failed_from_volume
     }
 
-    async fn enforce_history_limits(&self, history: &mut HashMap<String, Vec<Transaction>>) {
+    fn push_history(&self, history: &mut HashMap<String, VecDeque<Transaction>>, address: &str, transaction: Transaction) {
         let max_records = self.config.max_history_records as usize;
-        
-        for (_, transactions) in history.iter_mut() {
-            if transactions.len() > max_records {
-                transactions.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
-                transactions.truncate(max_records);
-            }
+        let transactions = history.entry(address.to_string()).or_default();
+
+        transactions.push_back(transaction);
+        while transactions.len() > max_records {
+            transactions.pop_front();
+            self.evicted_count.fetch_add(1, Ordering::Relaxed);
         }
     }
 
+    /// 因历史记录上限被淘汰的记录总数
+    pub fn evicted_count(&self) -> u64 {
+        self.evicted_count.load(Ordering::Relaxed)
+    }
+
     pub async fn get_address_balance(&self, address: &str) -> u64 {
         let balances = self.address_balances.read().await;
         balances.get(address).copied().unwrap_or(0)
     }
 
+    /// 用链上余额覆盖计算得到的余额，返回覆盖前的值
+    pub async fn set_address_balance(&self, address: &str, balance: u64) -> u64 {
+        let mut balances = self.address_balances.write().await;
+        balances.insert(address.to_string(), balance).unwrap_or(0)
+    }
+
+    /// 地址各代币类型的计算余额
+    pub async fn get_token_balances(&self, address: &str) -> HashMap<String, u64> {
+        let token_balances = self.token_balances.read().await;
+        token_balances.get(address).cloned().unwrap_or_default()
+    }
+
+    /// 用链上余额覆盖某一代币类型的计算余额，地址总余额按差额调整，返回覆盖前的值
+    pub async fn set_token_balance(&self, address: &str, coin_type: &str, balance: u64) -> u64 {
+        let mut balances = self.address_balances.write().await;
+        let mut token_balances = self.token_balances.write().await;
+        let previous = token_balances
+            .entry(address.to_string())
+            .or_default()
+            .insert(coin_type.to_string(), balance)
+            .unwrap_or(0);
+        let total = balances.entry(address.to_string()).or_insert(0);
+        *total = total.saturating_sub(previous).saturating_add(balance);
+        previous
+    }
+
+    /// 增加（`credit`）或扣减某地址某代币类型的余额，扣减不低于零
+    fn adjust_token_balance(
+        token_balances: &mut HashMap<String, HashMap<String, u64>>,
+        address: &str,
+        coin_type: &str,
+        amount: u64,
+        credit: bool,
+    ) {
+        let balance = token_balances
+            .entry(address.to_string())
+            .or_default()
+            .entry(coin_type.to_string())
+            .or_insert(0);
+        *balance = if credit { balance.saturating_add(amount) } else { balance.saturating_sub(amount) };
+    }
+
     pub async fn get_address_history(&self, address: &str, limit: u32) -> Vec<Transaction> {
         let history = self.transaction_history.read().await;
         history.get(address)
             .map(|transactions| {
-                let mut txs = transactions.clone();
+                let mut txs: Vec<Transaction> = transactions.iter().cloned().collect();
                 txs.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
                 txs.into_iter().take(limit as usize).collect()
             })
@@ -274,45 +639,195 @@ impl TransactionProcessor {
         Ok(removed_count as u64)
     }
 
+    /// 从 0 开始按本地交易推算的余额历史（最早的 `limit` 笔）
+    ///
+    /// 忽略了地址原有余额和未观测到的转账，需要真实余额时使用
+    /// [`Self::get_balance_history_anchored`]。
     pub async fn get_balance_history(&self, address: &str, limit: u32) -> BalanceHistory {
+        self.get_balance_history_anchored(address, limit, BalanceAnchor::Start(0)).await
+    }
+
+    /// 以已知余额为锚点重建余额历史，快照按时间升序
+    ///
+    /// `Start` 取最早的 `limit` 笔交易向后推算；`Latest` 取最近的 `limit` 笔交易，
+    /// 令最后一个快照等于锚点余额并向前回推。只计入转账金额，不含 gas。
+    pub async fn get_balance_history_anchored(&self, address: &str, limit: u32, anchor: BalanceAnchor) -> BalanceHistory {
         let history = self.transaction_history.read().await;
         let mut snapshots = Vec::new();
 
         if let Some(transactions) = history.get(address) {
-            let mut sorted_txs = transactions.clone();
-            sorted_txs.sort_by(|a, b| a.timestamp.cmp(&b.timestamp));
+            let mut sorted_txs: Vec<&Transaction> = transactions.iter().collect();
+            sorted_txs.sort_by(|a, b| a.timestamp.cmp(&b.timestamp).then_with(|| a.id.cmp(&b.id)));
 
-            let mut current_balance = 0u64;
-            for tx in sorted_txs.iter().take(limit as usize) {
-                if tx.sender == address {
-                    current_balance = current_balance.saturating_sub(tx.amount);
-                } else {
-                    current_balance = current_balance.saturating_add(tx.amount);
+            match anchor {
+                BalanceAnchor::Start(start_balance) => {
+                    let mut current_balance = start_balance as i128;
+                    for tx in sorted_txs.iter().take(limit as usize) {
+                        current_balance += Self::balance_delta(tx, address);
+                        snapshots.push(Self::balance_snapshot(tx, current_balance));
+                    }
+                }
+                BalanceAnchor::Latest(latest_balance) => {
+                    let skip = sorted_txs.len().saturating_sub(limit as usize);
+                    let mut current_balance = latest_balance as i128;
+                    for tx in sorted_txs[skip..].iter().rev() {
+                        snapshots.push(Self::balance_snapshot(tx, current_balance));
+                        current_balance -= Self::balance_delta(tx, address);
+                    }
+                    snapshots.reverse();
                 }
-
-                snapshots.push(BalanceSnapshot {
-                    timestamp: tx.timestamp,
-                    balance: current_balance,
-                    transaction_id: Some(tx.id.clone()),
-                });
             }
         }
 
         BalanceHistory {
             address: address.to_string(),
             history: snapshots,
+            anchor,
         }
     }
 
+    /// 单笔交易对地址余额的影响；失败交易和自转账不改变余额
+    fn balance_delta(tx: &Transaction, address: &str) -> i128 {
+        if tx.status == TransactionStatus::Failed || tx.sender == tx.recipient {
+            0
+        } else if tx.sender == address {
+            -(tx.amount as i128)
+        } else {
+            tx.amount as i128
+        }
+    }
+
+    fn balance_snapshot(tx: &Transaction, balance: i128) -> BalanceSnapshot {
+        BalanceSnapshot {
+            timestamp: tx.timestamp,
+            balance: balance.clamp(0, u64::MAX as i128) as u64,
+            transaction_id: Some(tx.id.clone()),
+        }
+    }
+
+    /// 最近的交易，按时间倒序（同一时间按交易ID排序）；监控地址之间的转账只出现一次
     pub async fn get_recent_transactions(&self, limit: u32) -> Vec<Transaction> {
         let history = self.transaction_history.read().await;
-        let mut all_transactions: Vec<Transaction> = history
+        // 同一笔交易同时记录在发送方和接收方的历史中，按交易ID和代币类型去重
+        let mut seen = std::collections::HashSet::new();
+        let mut all_transactions: Vec<&Transaction> = history
             .values()
-            .flat_map(|txs| txs.clone())
+            .flatten()
+            .filter(|tx| seen.insert((tx.id.as_str(), tx.token_type.as_str())))
+            .collect();
+
+        all_transactions.sort_by(|a, b| b.timestamp.cmp(&a.timestamp).then_with(|| a.id.cmp(&b.id)));
+        all_transactions.into_iter().take(limit as usize).cloned().collect()
+    }
+
+    /// 按条件搜索已记录的交易，结果按时间升序；条件为空时返回最近的交易
+    pub async fn search_transactions(&self, criteria: SearchCriteria) -> Vec<Transaction> {
+        let mut matches: Vec<Transaction> = self.get_all_transactions().await
+            .into_iter()
+            .filter(|tx| criteria.matches(tx))
+            .collect();
+
+        let limit = match criteria.limit {
+            Some(limit) => Some(limit),
+            None if criteria.is_empty() => Some(DEFAULT_SEARCH_LIMIT),
+            None => None,
+        };
+
+        // 超出上限时保留最新的交易
+        if let Some(limit) = limit {
+            if matches.len() > limit {
+                matches.drain(..matches.len() - limit);
+            }
+        }
+
+        matches
+    }
+
+    /// 时间窗口内地址的净流入（收到减去发出），负数表示净流出
+    pub async fn get_net_flow(&self, address: &str, window_hours: u64) -> i64 {
+        let current_time = Utc::now().timestamp() as u64;
+        let start_time = current_time.saturating_sub(window_hours.saturating_mul(3600));
+
+        let history = self.transaction_history.read().await;
+        let mut received: u64 = 0;
+        let mut sent: u64 = 0;
+
+        if let Some(transactions) = history.get(address) {
+            for tx in transactions.iter().filter(|tx| tx.timestamp >= start_time && tx.status != TransactionStatus::Failed) {
+                if tx.recipient == address {
+                    received = received.saturating_add(tx.amount);
+                }
+                if tx.sender == address {
+                    sent = sent.saturating_add(tx.amount);
+                }
+            }
+        }
+
+        net_flow(received, sent)
+    }
+
+    /// 按时间窗口内的平均净流出速率（含SUI gas）估算余额耗尽的剩余时间，净流入或持平时返回 None
+    pub async fn estimate_depletion(&self, address: &str, window_hours: u64) -> Option<std::time::Duration> {
+        if window_hours == 0 {
+            return None;
+        }
+
+        let current_time = Utc::now().timestamp() as u64;
+        let window_seconds = window_hours.saturating_mul(3600);
+        let start_time = current_time.saturating_sub(window_seconds);
+
+        let (received, spent) = {
+            let history = self.transaction_history.read().await;
+            let mut received: u128 = 0;
+            let mut spent: u128 = 0;
+            for tx in history.get(address).into_iter().flatten().filter(|tx| tx.timestamp >= start_time) {
+                let transferred = if tx.status == TransactionStatus::Failed { 0 } else { tx.amount as u128 };
+                if tx.recipient == address {
+                    received += transferred;
+                }
+                if tx.sender == address {
+                    spent += transferred;
+                    if tx.token_type == SUI_COIN_TYPE {
+                        spent += tx.gas_used.unwrap_or(0) as u128;
+                    }
+                }
+            }
+            (received, spent)
+        };
+
+        if spent <= received {
+            return None;
+        }
+
+        // 剩余时间 = 余额 / (净流出 / 窗口时长)
+        let outflow = spent - received;
+        let balance = self.get_address_balance(address).await as u128;
+        let seconds = balance * window_seconds as u128 / outflow;
+        Some(std::time::Duration::from_secs(seconds.min(u64::MAX as u128) as u64))
+    }
+
+    /// 按时间窗口内收发总量排序的前 n 个地址，总量相同时交易数多者在前
+    pub async fn get_top_addresses_by_volume(&self, n: usize, window_hours: u64) -> Vec<(String, u64)> {
+        let current_time = Utc::now().timestamp() as u64;
+        let start_time = current_time.saturating_sub(window_hours.saturating_mul(3600));
+
+        let history = self.transaction_history.read().await;
+        let mut ranking: Vec<(String, u64, usize)> = history
+            .iter()
+            .filter_map(|(address, transactions)| {
+                let (volume, count) = transactions
+                    .iter()
+                    .filter(|tx| tx.timestamp >= start_time && self.counts_as_volume(tx))
+                    .fold((0u64, 0usize), |(volume, count), tx| (volume.saturating_add(tx.amount), count + 1));
+                (count > 0).then(|| (address.clone(), volume, count))
+            })
             .collect();
 
-        all_transactions.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
-        all_transactions.into_iter().take(limit as usize).collect()
+        ranking.sort_by(|a, b| b.1.cmp(&a.1).then(b.2.cmp(&a.2)).then(a.0.cmp(&b.0)));
+        ranking.into_iter()
+            .take(n)
+            .map(|(address, volume, _)| (address, volume))
+            .collect()
     }
 
     pub async fn get_transaction_volume_stats(&self, time_range_hours: u64) -> HashMap<String, u64> {
@@ -324,7 +839,7 @@ impl TransactionProcessor {
 
         for (_, transactions) in history.iter() {
             for tx in transactions {
-                if tx.timestamp >= start_time {
+                if tx.timestamp >= start_time && self.counts_as_volume(tx) {
                     *volume_stats.entry(tx.token_type.clone()).or_insert(0) += tx.amount;
                 }
             }
@@ -333,42 +848,162 @@ impl TransactionProcessor {
         volume_stats
     }
 
+    /// 某代币在最近 `window_hours` 小时内按 `bucket_seconds` 分桶的交易量，返回 (桶起始时间戳, 交易量)
+    ///
+    /// 桶按时间戳整除对齐，从旧到新排列；没有交易的桶交易量为 0，序列保持连续。
+    pub async fn get_volume_timeseries(&self, token_type: &str, bucket_seconds: u64, window_hours: u64) -> Vec<(u64, u64)> {
+        if bucket_seconds == 0 {
+            return Vec::new();
+        }
+
+        let current_time = Utc::now().timestamp() as u64;
+        let start_time = current_time.saturating_sub(window_hours.saturating_mul(3600));
+        let first_bucket = start_time / bucket_seconds * bucket_seconds;
+        let last_bucket = current_time / bucket_seconds * bucket_seconds;
+        let mut series: Vec<(u64, u64)> = (first_bucket..=last_bucket)
+            .step_by(bucket_seconds as usize)
+            .map(|bucket| (bucket, 0))
+            .collect();
+
+        let canonical = |coin_type: &str| {
+            crate::config::Config::normalize_coin_type(coin_type).unwrap_or_else(|| coin_type.to_string())
+        };
+        let token_type = canonical(token_type);
+
+        let history = self.transaction_history.read().await;
+        // 同一笔交易同时记录在发送方和接收方的历史中，只计一次
+        let mut seen = std::collections::HashSet::new();
+        for tx in history.values().flatten() {
+            if tx.timestamp < start_time
+                || tx.timestamp > current_time
+                || !self.counts_as_volume(tx)
+                || canonical(&tx.token_type) != token_type
+                || !seen.insert((&tx.id, &tx.sender, &tx.recipient))
+            {
+                continue;
+            }
+            let index = ((tx.timestamp / bucket_seconds * bucket_seconds - first_bucket) / bucket_seconds) as usize;
+            if let Some((_, volume)) = series.get_mut(index) {
+                *volume = volume.saturating_add(tx.amount);
+            }
+        }
+
+        series
+    }
+
     pub async fn export_data(&self, format: ExportFormat) -> Result<String, TrackerError> {
-        match format {
+        let mut buffer = Vec::new();
+        self.export_to_writer(format, &mut buffer).await?;
+        String::from_utf8(buffer).map_err(|e| TrackerError::export_error(format!("Export is not valid UTF-8: {}", e)))
+    }
+
+    /// 将导出内容逐条写入 writer，不在内存中拼接完整结果
+    pub async fn export_to_writer(&self, format: ExportFormat, writer: impl Write) -> TrackerResult<ExportSummary> {
+        let mut writer = ByteCounter { inner: writer, bytes: 0 };
+        let records = match format {
             ExportFormat::Json => {
-                let data = serde_json::json!({
-                    "balances": *self.address_balances.read().await,
-                    "stats": *self.address_stats.read().await,
-                    "export_time": Utc::now().to_rfc3339()
-                });
-                serde_json::to_string_pretty(&data)
-                    .map_err(|e| TrackerError::SerializationError(e))
+                let balances = self.address_balances.read().await;
+                let stats = self.address_stats.read().await;
+                let document = ExportDocument {
+                    balances: &balances,
+                    export_time: Utc::now().to_rfc3339(),
+                    stats: &stats,
+                };
+                if self.config.export_json_pretty {
+                    serde_json::to_writer_pretty(&mut writer, &document)?;
+                } else {
+                    serde_json::to_writer(&mut writer, &document)?;
+                }
+                balances.len()
             }
             ExportFormat::Csv => {
-                let mut csv = String::new();
-                csv.push_str("Address,Balance,Total Transactions,Total Sent,Total Received\n");
+                writer.write_all(b"Address,Balance,Total Transactions,Total Sent,Total Received\n")?;
                 
                 let balances = self.address_balances.read().await;
                 let stats = self.address_stats.read().await;
                 
+                let mut rows = 0;
                 for (address, balance) in balances.iter() {
                     if let Some(address_stats) = stats.get(address) {
-                        csv.push_str(&format!(
-                            "{},{},{},{},{}\n",
+                        rows += 1;
+                        writeln!(
+                            writer,
+                            "{},{},{},{},{}",
                             address,
                             balance,
                             address_stats.total_transactions,
                             address_stats.total_sent,
                             address_stats.total_received
-                        ));
+                        )?;
                     }
                 }
-                
-                Ok(csv)
+                rows
+            }
+            #[cfg(feature = "parquet")]
+            ExportFormat::Parquet => return Err(TrackerError::export_error(
+                "Parquet is a binary format, use export_to_path instead",
+            )),
+        };
+
+        writer.flush()?;
+        Ok(ExportSummary { records, bytes: writer.bytes, format })
+    }
+
+    /// 导出数据并直接写入文件，二进制格式（Parquet）只能通过此方法导出
+    pub async fn export_to_path(&self, format: ExportFormat, path: &Path) -> TrackerResult<ExportSummary> {
+        match format {
+            #[cfg(feature = "parquet")]
+            ExportFormat::Parquet => {
+                let transactions = self.get_all_transactions().await;
+                parquet_export::write_transactions(&transactions, path)?;
+                Ok(ExportSummary {
+                    records: transactions.len(),
+                    bytes: std::fs::metadata(path)?.len() as usize,
+                    format,
+                })
+            }
+            format => {
+                let file = std::fs::File::create(path)?;
+                self.export_to_writer(format, std::io::BufWriter::new(file)).await
             }
         }
     }
 
+    /// 所有已记录的交易（按交易ID和代币类型去重，按时间升序）
+    pub async fn get_all_transactions(&self) -> Vec<Transaction> {
+        let history = self.transaction_history.read().await;
+        let mut seen = std::collections::HashSet::new();
+        let mut transactions: Vec<Transaction> = history
+            .values()
+            .flatten()
+            .filter(|tx| seen.insert((tx.id.clone(), tx.token_type.clone())))
+            .cloned()
+            .collect();
+
+        transactions.sort_by_key(|tx| tx.timestamp);
+        transactions
+    }
+
+    /// 按时间升序逐条访问所有交易（按交易ID和代币类型去重），不复制交易记录，返回访问的条数
+    pub async fn for_each_transaction<F>(&self, mut visit: F) -> TrackerResult<usize>
+    where
+        F: FnMut(&Transaction) -> TrackerResult<()>,
+    {
+        let history = self.transaction_history.read().await;
+        let mut seen = std::collections::HashSet::new();
+        let mut transactions: Vec<&Transaction> = history
+            .values()
+            .flatten()
+            .filter(|tx| seen.insert((tx.id.as_str(), tx.token_type.as_str())))
+            .collect();
+
+        transactions.sort_by_key(|tx| tx.timestamp);
+        for tx in &transactions {
+            visit(tx)?;
+        }
+        Ok(transactions.len())
+    }
+
     pub async fn get_processor_stats(&self) -> ProcessorStats {
         let balances = self.address_balances.read().await;
         let stats = self.address_stats.read().await;
@@ -380,6 +1015,9 @@ impl TransactionProcessor {
             total_addresses: balances.len(),
             total_transactions,
             total_volume,
+            evicted_count: self.evicted_count(),
+            by_network: self.network_stats.read().await.clone(),
+            processing_time: self.processing_times.read().await.stats(),
             config: self.config.clone(),
         }
     }
@@ -390,13 +1028,119 @@ pub struct ProcessorStats {
     pub total_addresses: usize,
     pub total_transactions: u64,
     pub total_volume: u64,
+    pub evicted_count: u64,
+    /// 按网络名称分组的交易汇总
+    pub by_network: BTreeMap<String, NetworkStats>,
+    /// 事件处理耗时统计
+    pub processing_time: ProcessingTimeStats,
     pub config: ProcessorConfig,
 }
 
+/// JSON导出的文档结构，借用处理器中的数据直接序列化
+#[derive(Serialize)]
+struct ExportDocument<'a> {
+    balances: &'a HashMap<String, u64>,
+    export_time: String,
+    stats: &'a HashMap<String, AddressStats>,
+}
+
 #[derive(Debug, Clone)]
 pub enum ExportFormat {
     Json,
     Csv,
+    #[cfg(feature = "parquet")]
+    Parquet,
+}
+
+/// 一次导出的结果：记录数（json/csv 为地址数，parquet 为交易数）和写入的字节数
+#[derive(Debug, Clone)]
+pub struct ExportSummary {
+    pub records: usize,
+    pub bytes: usize,
+    pub format: ExportFormat,
+}
+
+/// 统计写入字节数的 writer 包装
+struct ByteCounter<W> {
+    inner: W,
+    bytes: usize,
+}
+
+impl<W: Write> Write for ByteCounter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.bytes += written;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(feature = "parquet")]
+mod parquet_export {
+    use super::{Transaction, TransactionStatus};
+    use crate::error::{TrackerError, TrackerResult};
+    use arrow::array::{ArrayRef, StringArray, TimestampSecondArray, UInt64Array};
+    use arrow::datatypes::{DataType, Field, Schema, TimeUnit};
+    use arrow::record_batch::RecordBatch;
+    use parquet::arrow::ArrowWriter;
+    use parquet::basic::Compression;
+    use parquet::file::properties::WriterProperties;
+    use std::path::Path;
+    use std::sync::Arc;
+
+    pub fn write_transactions(transactions: &[Transaction], path: &Path) -> TrackerResult<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Utf8, false),
+            Field::new("sender", DataType::Utf8, false),
+            Field::new("recipient", DataType::Utf8, false),
+            Field::new("amount", DataType::UInt64, false),
+            Field::new("token_type", DataType::Utf8, false),
+            Field::new("timestamp", DataType::Timestamp(TimeUnit::Second, Some("UTC".into())), false),
+            Field::new("block_number", DataType::UInt64, false),
+            Field::new("gas_used", DataType::UInt64, true),
+            Field::new("gas_price", DataType::UInt64, true),
+            Field::new("status", DataType::Utf8, false),
+        ]));
+
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(StringArray::from_iter_values(transactions.iter().map(|tx| tx.id.as_str()))),
+            Arc::new(StringArray::from_iter_values(transactions.iter().map(|tx| tx.sender.as_str()))),
+            Arc::new(StringArray::from_iter_values(transactions.iter().map(|tx| tx.recipient.as_str()))),
+            Arc::new(UInt64Array::from_iter_values(transactions.iter().map(|tx| tx.amount))),
+            Arc::new(StringArray::from_iter_values(transactions.iter().map(|tx| tx.token_type.as_str()))),
+            Arc::new(
+                TimestampSecondArray::from_iter_values(transactions.iter().map(|tx| tx.timestamp as i64))
+                    .with_timezone("UTC"),
+            ),
+            Arc::new(UInt64Array::from_iter_values(transactions.iter().map(|tx| tx.block_number))),
+            Arc::new(UInt64Array::from(transactions.iter().map(|tx| tx.gas_used).collect::<Vec<_>>())),
+            Arc::new(UInt64Array::from(transactions.iter().map(|tx| tx.gas_price).collect::<Vec<_>>())),
+            Arc::new(StringArray::from_iter_values(transactions.iter().map(|tx| match tx.status {
+                TransactionStatus::Success => "success",
+                TransactionStatus::Failed => "failed",
+                TransactionStatus::Pending => "pending",
+            }))),
+        ];
+
+        let batch = RecordBatch::try_new(schema.clone(), columns)
+            .map_err(|e| TrackerError::export_error(format!("Failed to build record batch: {}", e)))?;
+
+        let file = std::fs::File::create(path)?;
+        let props = WriterProperties::builder()
+            .set_compression(Compression::SNAPPY)
+            .build();
+        let mut writer = ArrowWriter::try_new(file, schema, Some(props))
+            .map_err(|e| TrackerError::export_error(format!("Failed to create Parquet writer: {}", e)))?;
+        writer.write(&batch)
+            .map_err(|e| TrackerError::export_error(format!("Failed to write Parquet data: {}", e)))?;
+        writer.close()
+            .map_err(|e| TrackerError::export_error(format!("Failed to finalize Parquet file: {}", e)))?;
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -427,13 +1171,47 @@ mod tests {
             timestamp: 1634567890,
             block_number: 12345,
             event_type: "transfer".to_string(),
+            gas_used: None,
+            status: TransactionStatus::Success,
+            network: String::new(),
         };
 
         let result = processor.process_transfer_event(event).await.unwrap();
         assert_eq!(result.transaction.amount, 1000000000);
         assert_eq!(result.sender_balance_change, -1000000000);
         assert_eq!(result.receiver_balance_change, 1000000000);
-        assert!(result.processing_time_ms > 0);
+        assert!(result.processing_time_ns > 0);
+
+        let processing_time = processor.get_processor_stats().await.processing_time;
+        assert_eq!(processing_time.count, 1);
+        assert_eq!(processing_time.total_ns, result.processing_time_ns);
+        assert_eq!(processing_time.max_ns, result.processing_time_ns);
+        assert_eq!(processing_time.p95_ns, result.processing_time_ns);
+    }
+
+    #[test]
+    fn test_processing_time_histogram() {
+        let mut histogram = ProcessingTimeHistogram::default();
+        assert_eq!(histogram.stats(), ProcessingTimeStats::default());
+
+        for ns in 1..=100 {
+            histogram.record(ns * 1_000);
+        }
+        let stats = histogram.stats();
+        assert_eq!(stats.count, 100);
+        assert_eq!(stats.total_ns, 5_050_000);
+        assert_eq!(stats.mean_ns(), 50_500);
+        assert_eq!(stats.max_ns, 100_000);
+        assert_eq!(stats.p95_ns, 95_000);
+
+        // 分位数只看最近的样本，计数和最大值覆盖全部
+        for _ in 0..MAX_PROCESSING_SAMPLES {
+            histogram.record(10);
+        }
+        let stats = histogram.stats();
+        assert_eq!(stats.count, 100 + MAX_PROCESSING_SAMPLES as u64);
+        assert_eq!(stats.max_ns, 100_000);
+        assert_eq!(stats.p95_ns, 10);
     }
 
     #[tokio::test]
@@ -451,6 +1229,9 @@ mod tests {
             timestamp: 1634567890,
             block_number: 12345,
             event_type: "transfer".to_string(),
+            gas_used: None,
+            status: TransactionStatus::Success,
+            network: String::new(),
         };
 
         processor.process_transfer_event(event).await.unwrap();
@@ -459,6 +1240,319 @@ mod tests {
         assert_eq!(processor.get_address_balance("0xrecipient").await, 1000000000);
     }
 
+    #[tokio::test]
+    async fn test_gas_tracking() {
+        let processor = TransactionProcessor::new();
+        processor.set_address_balance("0xsender", 5000000000).await;
+
+        let mut event = transfer_event("0x1", "0xsender", "0xrecipient", 1000000000, 1634567890);
+        event.gas_used = Some(2000000);
+        let result = processor.process_transfer_event(event).await.unwrap();
+        assert_eq!(result.transaction.gas_used, Some(2000000));
+        assert_eq!(result.sender_balance_change, -1002000000);
+
+        // 非SUI代币转账不从该代币余额中扣除gas
+        let mut event = transfer_event("0x2", "0xsender", "0xrecipient", 1000, 1634567891);
+        event.token_type = "0xabc::usdc::USDC".to_string();
+        event.gas_used = Some(1000000);
+        let result = processor.process_transfer_event(event).await.unwrap();
+        assert_eq!(result.sender_balance_change, -1000);
+
+        let sender_stats = processor.get_address_stats("0xsender").await.unwrap();
+        assert_eq!(sender_stats.total_gas_spent, 3000000);
+        let recipient_stats = processor.get_address_stats("0xrecipient").await.unwrap();
+        assert_eq!(recipient_stats.total_gas_spent, 0);
+        assert_eq!(processor.get_address_balance("0xsender").await, 5000000000 - 1002000000 - 1000);
+    }
+
+    #[tokio::test]
+    async fn test_failed_transaction() {
+        let processor = TransactionProcessor::new();
+        processor.set_address_balance("0xsender", 5000000000).await;
+
+        let mut event = transfer_event("0x1", "0xsender", "0xrecipient", 1000000000, 1634567890);
+        event.status = TransactionStatus::Failed;
+        event.gas_used = Some(1000000);
+        let result = processor.process_transfer_event(event).await.unwrap();
+
+        // 失败的交易只扣除gas
+        assert_eq!(result.transaction.status, TransactionStatus::Failed);
+        assert_eq!(result.sender_balance_change, -1000000);
+        assert_eq!(result.receiver_balance_change, 0);
+        assert_eq!(processor.get_address_balance("0xsender").await, 4999000000);
+        assert_eq!(processor.get_address_balance("0xrecipient").await, 0);
+
+        let sender_stats = processor.get_address_stats("0xsender").await.unwrap();
+        assert_eq!(sender_stats.total_sent, 0);
+        assert_eq!(sender_stats.total_transactions, 0);
+        assert_eq!(sender_stats.total_gas_spent, 1000000);
+        assert!(processor.get_address_stats("0xrecipient").await.is_none());
+
+        // 交易仍记录在历史中
+        assert_eq!(processor.get_address_history("0xsender", 10).await.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_estimate_depletion() {
+        let processor = TransactionProcessor::new();
+        let now = Utc::now().timestamp() as u64;
+
+        // 24小时内净流出 6 SUI，剩余 6 SUI，约24小时后耗尽
+        processor.set_address_balance("0xops", 12000000000).await;
+        processor.process_transfer_event(transfer_event("0x1", "0xops", "0xother", 8000000000, now - 3600)).await.unwrap();
+        processor.process_transfer_event(transfer_event("0x2", "0xother", "0xops", 2000000000, now - 60)).await.unwrap();
+
+        let estimate = processor.estimate_depletion("0xops", 24).await.unwrap();
+        assert_eq!(estimate.as_secs(), 24 * 3600);
+
+        // 净流入时不做预测
+        assert!(processor.estimate_depletion("0xother", 24).await.is_none());
+        assert!(processor.estimate_depletion("0xunknown", 24).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_set_address_balance() {
+        let processor = TransactionProcessor::new();
+
+        assert_eq!(processor.set_address_balance("0xaddr", 5000000000).await, 0);
+        assert_eq!(processor.set_address_balance("0xaddr", 4900000000).await, 5000000000);
+        assert_eq!(processor.get_address_balance("0xaddr").await, 4900000000);
+    }
+
+    #[tokio::test]
+    async fn test_token_balances() {
+        let processor = TransactionProcessor::new();
+        let mut event = transfer_event("0x1", "0xsender", "0xrecipient", 1000, 1634567890);
+        event.token_type = "0xabc::usdc::USDC".to_string();
+        processor.process_transfer_event(event).await.unwrap();
+        processor.process_transfer_event(transfer_event("0x2", "0xother", "0xrecipient", 2000, 1634567891)).await.unwrap();
+
+        let balances = processor.get_token_balances("0xrecipient").await;
+        assert_eq!(balances["0xabc::usdc::USDC"], 1000);
+        assert_eq!(balances["0x2::sui::SUI"], 2000);
+
+        // 只覆盖SUI余额，其他代币的计算余额保持不变
+        assert_eq!(processor.set_token_balance("0xrecipient", "0x2::sui::SUI", 2500).await, 2000);
+        assert_eq!(processor.get_token_balances("0xrecipient").await["0xabc::usdc::USDC"], 1000);
+        assert_eq!(processor.get_address_balance("0xrecipient").await, 3500);
+    }
+
+    fn transfer_event(id: &str, sender: &str, recipient: &str, amount: u64, timestamp: u64) -> TransferEvent {
+        TransferEvent {
+            transaction_id: id.to_string(),
+            package_id: "0x2".to_string(),
+            transaction_module: "coin".to_string(),
+            sender: sender.to_string(),
+            recipient: recipient.to_string(),
+            amount,
+            token_type: "0x2::sui::SUI".to_string(),
+            timestamp,
+            block_number: 1,
+            event_type: "transfer".to_string(),
+            gas_used: None,
+            status: TransactionStatus::Success,
+            network: String::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_net_flow() {
+        let processor = TransactionProcessor::new();
+        let now = Utc::now().timestamp() as u64;
+
+        processor.process_transfer_event(transfer_event("0x1", "0xa", "0xtreasury", 10_000_000_000, now - 60)).await.unwrap();
+        processor.process_transfer_event(transfer_event("0x2", "0xtreasury", "0xb", 3_000_000_000, now - 30)).await.unwrap();
+        processor.process_transfer_event(transfer_event("0x3", "0xtreasury", "0xc", 2_000_000_000, now)).await.unwrap();
+        // 窗口之外的交易不计入
+        processor.process_transfer_event(transfer_event("0x4", "0xd", "0xtreasury", 50_000_000_000, now - 48 * 3600)).await.unwrap();
+
+        assert_eq!(processor.get_net_flow("0xtreasury", 24).await, 5_000_000_000);
+        assert_eq!(processor.get_net_flow("0xb", 24).await, 3_000_000_000);
+        assert_eq!(processor.get_net_flow("0xa", 24).await, -10_000_000_000);
+        assert_eq!(processor.get_net_flow("0xunknown", 24).await, 0);
+
+        let stats = processor.get_address_stats("0xtreasury").await.unwrap();
+        assert_eq!(stats.net_flow(), 55_000_000_000);
+        assert_eq!(net_flow(u64::MAX, 0), i64::MAX);
+        assert_eq!(net_flow(0, u64::MAX), i64::MIN);
+    }
+
+    #[tokio::test]
+    async fn test_top_addresses_by_volume() {
+        let processor = TransactionProcessor::new();
+        let now = Utc::now().timestamp() as u64;
+
+        processor.process_transfer_event(transfer_event("0x1", "0xa", "0xb", 5_000_000_000, now)).await.unwrap();
+        processor.process_transfer_event(transfer_event("0x2", "0xc", "0xd", 2_000_000_000, now)).await.unwrap();
+        processor.process_transfer_event(transfer_event("0x3", "0xc", "0xe", 3_000_000_000, now)).await.unwrap();
+        processor.process_transfer_event(transfer_event("0x4", "0xf", "0xg", 100_000_000_000, now - 48 * 3600)).await.unwrap();
+
+        let top = processor.get_top_addresses_by_volume(3, 24).await;
+        // 0xa、0xb、0xc 总量都是 5 SUI，0xc 有两笔交易排在最前
+        assert_eq!(top, vec![
+            ("0xc".to_string(), 5_000_000_000),
+            ("0xa".to_string(), 5_000_000_000),
+            ("0xb".to_string(), 5_000_000_000),
+        ]);
+    }
+
+    #[tokio::test]
+    async fn test_volume_timeseries() {
+        let processor = TransactionProcessor::new();
+        let now = Utc::now().timestamp() as u64;
+
+        processor.process_transfer_event(transfer_event("0x1", "0xa", "0xb", 1_000, now)).await.unwrap();
+        processor.process_transfer_event(transfer_event("0x2", "0xb", "0xc", 2_000, now)).await.unwrap();
+        processor.process_transfer_event(transfer_event("0x3", "0xa", "0xc", 4_000, now - 7200)).await.unwrap();
+        processor.process_transfer_event(transfer_event("0x4", "0xa", "0xc", 8_000, now - 48 * 3600)).await.unwrap();
+        let mut failed = transfer_event("0x5", "0xa", "0xc", 16_000, now);
+        failed.status = TransactionStatus::Failed;
+        processor.process_transfer_event(failed).await.unwrap();
+        let mut other_token = transfer_event("0x6", "0xa", "0xc", 32_000, now);
+        other_token.token_type = "0xabc::usdc::USDC".to_string();
+        processor.process_transfer_event(other_token).await.unwrap();
+
+        // 短格式代币类型与完整形式等价
+        let series = processor.get_volume_timeseries(&format!("0x{:0>64}::sui::SUI", "2"), 3600, 3).await;
+        assert_eq!(series.len(), 4);
+        assert!(series.windows(2).all(|pair| pair[1].0 - pair[0].0 == 3600));
+        assert!(series.iter().all(|(bucket, _)| bucket % 3600 == 0));
+        let volume_at = |ts: u64| series.iter().find(|(bucket, _)| *bucket == ts / 3600 * 3600).unwrap().1;
+        assert_eq!(volume_at(now), 3_000);
+        assert_eq!(volume_at(now - 7200), 4_000);
+        assert_eq!(series.iter().map(|(_, volume)| volume).sum::<u64>(), 7_000);
+
+        assert_eq!(processor.get_volume_timeseries("0xabc::usdc::USDC", 3600, 1).await.iter().map(|(_, v)| v).sum::<u64>(), 32_000);
+        assert!(processor.get_volume_timeseries("0x2::sui::SUI", 0, 3).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_self_transfer_counted_once() {
+        let processor = TransactionProcessor::new();
+        processor.set_address_balance("0xa", 5_000_000_000).await;
+        processor.process_transfer_event(transfer_event("0x1", "0xa", "0xa", 1_000_000_000, 100)).await.unwrap();
+
+        assert_eq!(processor.get_address_history("0xa", 10).await.len(), 1);
+        let stats = processor.get_address_stats("0xa").await.unwrap();
+        assert_eq!(stats.total_transactions, 1);
+        assert_eq!(stats.last_transaction, Some(100));
+        assert_eq!(processor.get_address_balance("0xa").await, 5_000_000_000);
+    }
+
+    #[tokio::test]
+    async fn test_balance_history_anchored() {
+        let processor = TransactionProcessor::new();
+        processor.process_transfer_event(transfer_event("0x1", "0xb", "0xa", 5_000_000_000, 100)).await.unwrap();
+        processor.process_transfer_event(transfer_event("0x2", "0xa", "0xc", 2_000_000_000, 200)).await.unwrap();
+        processor.process_transfer_event(transfer_event("0x3", "0xb", "0xa", 1_000_000_000, 300)).await.unwrap();
+
+        let balances = |history: &BalanceHistory| history.history.iter().map(|s| s.balance).collect::<Vec<_>>();
+
+        let history = processor.get_balance_history("0xa", 10).await;
+        assert_eq!(history.anchor, BalanceAnchor::Start(0));
+        assert_eq!(balances(&history), vec![5_000_000_000, 3_000_000_000, 4_000_000_000]);
+
+        // 地址原有 10 SUI
+        let history = processor.get_balance_history_anchored("0xa", 10, BalanceAnchor::Start(10_000_000_000)).await;
+        assert_eq!(balances(&history), vec![15_000_000_000, 13_000_000_000, 14_000_000_000]);
+
+        // 链上当前余额 20 SUI，最近两笔向前回推
+        let history = processor.get_balance_history_anchored("0xa", 2, BalanceAnchor::Latest(20_000_000_000)).await;
+        assert_eq!(balances(&history), vec![19_000_000_000, 20_000_000_000]);
+        assert_eq!(history.history[0].transaction_id.as_deref(), Some("0x2"));
+    }
+
+    #[tokio::test]
+    async fn test_update_transaction_status() {
+        let processor = TransactionProcessor::new();
+        let mut event = transfer_event("0x1", "0xa", "0xb", 5_000_000_000, 100);
+        event.status = TransactionStatus::Pending;
+        processor.process_transfer_event(event).await.unwrap();
+        let mut event = transfer_event("0x2", "0xa", "0xb", 2_000_000_000, 200);
+        event.status = TransactionStatus::Pending;
+        processor.process_transfer_event(event).await.unwrap();
+        processor.set_address_balance("0xa", 10_000_000_000).await;
+
+        assert_eq!(processor.pending_transaction_ids().await, vec!["0x1", "0x2"]);
+
+        processor.update_transaction_status("0x1", TransactionStatus::Success).await.unwrap();
+        processor.update_transaction_status("0x2", TransactionStatus::Failed).await.unwrap();
+        assert!(processor.pending_transaction_ids().await.is_empty());
+
+        // 失败的交易退回金额并从统计中扣除
+        assert_eq!(processor.get_address_balance("0xa").await, 12_000_000_000);
+        assert_eq!(processor.get_address_balance("0xb").await, 5_000_000_000);
+        let sender_stats = processor.get_address_stats("0xa").await.unwrap();
+        assert_eq!(sender_stats.total_transactions, 1);
+        assert_eq!(sender_stats.total_sent, 5_000_000_000);
+        let receiver_stats = processor.get_address_stats("0xb").await.unwrap();
+        assert_eq!(receiver_stats.total_received, 5_000_000_000);
+        assert_eq!(processor.get_processor_stats().await.by_network[""].total_volume, 5_000_000_000);
+
+        let statuses: Vec<TransactionStatus> = processor.get_address_history("0xb", 10).await
+            .into_iter().map(|tx| tx.status).collect();
+        assert_eq!(statuses, vec![TransactionStatus::Failed, TransactionStatus::Success]);
+
+        // 已确认的交易不能再次更新
+        assert!(processor.update_transaction_status("0x1", TransactionStatus::Failed).await.is_err());
+        assert!(processor.update_transaction_status("0x9", TransactionStatus::Success).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_recent_transactions_deduplicated() {
+        let processor = TransactionProcessor::new();
+        // 0xa 和 0xb 之间的转账在两边的历史中各记录一次
+        processor.process_transfer_event(transfer_event("0x1", "0xa", "0xb", 5_000_000_000, 100)).await.unwrap();
+        processor.process_transfer_event(transfer_event("0x2", "0xb", "0xc", 1_000_000_000, 200)).await.unwrap();
+        processor.process_transfer_event(transfer_event("0x3", "0xc", "0xa", 2_000_000_000, 200)).await.unwrap();
+
+        let recent = processor.get_recent_transactions(10).await;
+        let ids: Vec<&str> = recent.iter().map(|tx| tx.id.as_str()).collect();
+        assert_eq!(ids, vec!["0x2", "0x3", "0x1"]);
+
+        let recent = processor.get_recent_transactions(2).await;
+        let ids: Vec<&str> = recent.iter().map(|tx| tx.id.as_str()).collect();
+        assert_eq!(ids, vec!["0x2", "0x3"]);
+    }
+
+    #[tokio::test]
+    async fn test_search_transactions() {
+        let processor = TransactionProcessor::new();
+        processor.process_transfer_event(transfer_event("0x1", "0xa", "0xb", 5_000_000_000, 100)).await.unwrap();
+        processor.process_transfer_event(transfer_event("0x2", "0xb", "0xc", 20_000_000_000, 200)).await.unwrap();
+        processor.process_transfer_event(transfer_event("0x3", "0xa", "0xc", 30_000_000_000, 300)).await.unwrap();
+
+        let results = processor.search_transactions(SearchCriteria {
+            address: Some("0xa".to_string()),
+            min_amount: Some(10_000_000_000),
+            ..Default::default()
+        }).await;
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].id, "0x3");
+
+        let results = processor.search_transactions(SearchCriteria {
+            start_ts: Some(150),
+            end_ts: Some(300),
+            ..Default::default()
+        }).await;
+        let ids: Vec<&str> = results.iter().map(|tx| tx.id.as_str()).collect();
+        assert_eq!(ids, vec!["0x2", "0x3"]);
+
+        let results = processor.search_transactions(SearchCriteria {
+            status: Some(TransactionStatus::Failed),
+            ..Default::default()
+        }).await;
+        assert!(results.is_empty());
+
+        // 空条件返回最近的交易
+        let results = processor.search_transactions(SearchCriteria {
+            limit: Some(2),
+            ..Default::default()
+        }).await;
+        let ids: Vec<&str> = results.iter().map(|tx| tx.id.as_str()).collect();
+        assert_eq!(ids, vec!["0x2", "0x3"]);
+    }
+
     #[tokio::test]
     async fn test_address_history() {
         let processor = TransactionProcessor::new();
@@ -474,6 +1568,9 @@ mod tests {
             timestamp: 1634567890,
             block_number: 12345,
             event_type: "transfer".to_string(),
+            gas_used: None,
+            status: TransactionStatus::Success,
+            network: String::new(),
         };
 
         processor.process_transfer_event(event).await.unwrap();
@@ -493,6 +1590,8 @@ mod tests {
             max_history_records: 10,
             cleanup_interval_hours: 24,
             enable_detailed_stats: true,
+            exclude_failed_from_volume: true,
+            export_json_pretty: true,
         });
         
         // 创建一个旧交易
@@ -507,6 +1606,9 @@ mod tests {
             timestamp: 1000000000, // 很旧的时间戳
             block_number: 12345,
             event_type: "transfer".to_string(),
+            gas_used: None,
+            status: TransactionStatus::Success,
+            network: String::new(),
         };
 
         processor.process_transfer_event(old_event).await.unwrap();
@@ -518,6 +1620,30 @@ mod tests {
         assert_eq!(history.len(), 0);
     }
 
+    #[tokio::test]
+    async fn test_history_eviction() {
+        let processor = TransactionProcessor::with_config(ProcessorConfig {
+            max_history_records: 3,
+            cleanup_interval_hours: 24,
+            enable_detailed_stats: true,
+            exclude_failed_from_volume: true,
+            export_json_pretty: true,
+        });
+
+        for i in 0..5u64 {
+            let event = transfer_event(&format!("0x{}", i), "0xsender", "0xrecipient", 1000, 1634567890 + i);
+            processor.process_transfer_event(event).await.unwrap();
+        }
+
+        // 两个地址各淘汰两条最旧的记录
+        assert_eq!(processor.evicted_count(), 4);
+        assert_eq!(processor.get_processor_stats().await.evicted_count, 4);
+
+        let history = processor.get_address_history("0xsender", 10).await;
+        let ids: Vec<&str> = history.iter().map(|tx| tx.id.as_str()).collect();
+        assert_eq!(ids, vec!["0x4", "0x3", "0x2"]);
+    }
+
     #[tokio::test]
     async fn test_export_data() {
         let processor = TransactionProcessor::new();
@@ -533,6 +1659,9 @@ mod tests {
             timestamp: 1634567890,
             block_number: 12345,
             event_type: "transfer".to_string(),
+            gas_used: None,
+            status: TransactionStatus::Success,
+            network: String::new(),
         };
 
         processor.process_transfer_event(event).await.unwrap();
@@ -544,4 +1673,103 @@ mod tests {
         let csv_data = processor.export_data(ExportFormat::Csv).await.unwrap();
         assert!(csv_data.contains("Address,Balance,Total Transactions"));
     }
+
+    /// 记录最大单次写入的 writer，用于确认导出是逐行写入的
+    #[derive(Default)]
+    struct CountingWriter {
+        bytes: usize,
+        lines: usize,
+        largest_write: usize,
+    }
+
+    impl Write for CountingWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.bytes += buf.len();
+            self.lines += buf.iter().filter(|b| **b == b'\n').count();
+            self.largest_write = self.largest_write.max(buf.len());
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_export_to_writer_streams_large_history() {
+        let processor = TransactionProcessor::new();
+
+        for i in 0..5_000u64 {
+            let event = TransferEvent {
+                transaction_id: format!("0xtx{}", i),
+                package_id: "0x2".to_string(),
+                transaction_module: "coin".to_string(),
+                sender: format!("0xsender{}", i),
+                recipient: format!("0xrecipient{}", i),
+                amount: 1000 + i,
+                token_type: "0x2::sui::SUI".to_string(),
+                timestamp: 1634567890 + i,
+                block_number: i,
+                event_type: "transfer".to_string(),
+                gas_used: None,
+                status: TransactionStatus::Success,
+                network: String::new(),
+            };
+            processor.process_transfer_event(event).await.unwrap();
+        }
+
+        let rows = processor.get_all_stats().await.len();
+        let mut writer = CountingWriter::default();
+        let summary = processor.export_to_writer(ExportFormat::Csv, &mut writer).await.unwrap();
+
+        assert!(rows >= 5_000);
+        assert_eq!(writer.lines, rows + 1);
+        assert_eq!(summary.records, rows);
+        assert_eq!(summary.bytes, writer.bytes);
+        // 每次写入都远小于完整导出
+        assert!(writer.largest_write < 256);
+        assert!(writer.bytes > 100 * writer.largest_write);
+
+        let mut json_writer = CountingWriter::default();
+        processor.export_to_writer(ExportFormat::Json, &mut json_writer).await.unwrap();
+        assert!(json_writer.largest_write < 256);
+    }
+
+    #[cfg(feature = "parquet")]
+    #[tokio::test]
+    async fn test_export_parquet() {
+        let processor = TransactionProcessor::new();
+
+        let event = TransferEvent {
+            transaction_id: "0x123".to_string(),
+            package_id: "0x456".to_string(),
+            transaction_module: "test".to_string(),
+            sender: "0xsender".to_string(),
+            recipient: "0xrecipient".to_string(),
+            amount: 1000000000,
+            token_type: "0x2::sui::SUI".to_string(),
+            timestamp: 1634567890,
+            block_number: 12345,
+            event_type: "transfer".to_string(),
+            gas_used: None,
+            status: TransactionStatus::Success,
+            network: String::new(),
+        };
+
+        processor.process_transfer_event(event).await.unwrap();
+
+        // 发送方与接收方历史中的同一笔交易只导出一次
+        assert_eq!(processor.get_all_transactions().await.len(), 1);
+        assert!(processor.export_data(ExportFormat::Parquet).await.is_err());
+
+        let path = std::env::temp_dir().join(format!("tracker_export_{}.parquet", std::process::id()));
+        let summary = processor.export_to_path(ExportFormat::Parquet, &path).await.unwrap();
+
+        let bytes = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(summary.records, 1);
+        assert_eq!(summary.bytes, bytes.len());
+        assert!(bytes.starts_with(b"PAR1"));
+        assert!(bytes.ends_with(b"PAR1"));
+    }
 }
\ No newline at end of file
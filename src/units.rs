@@ -0,0 +1,87 @@
+//! 金额单位换算
+//!
+//! 链上金额均以最小单位（SUI 为 MIST）记录，这里集中定义换算常量，
+//! 避免在各模块中硬编码除数。
+
+/// 1 SUI = 10^9 MIST
+pub const MIST_PER_SUI: u64 = 1_000_000_000;
+
+/// SUI 的小数位数
+pub const SUI_DECIMALS: u32 = 9;
+
+/// 将最小单位金额按 `decimals` 位小数换算为代币数值
+///
+/// SUI 金额传入 [`SUI_DECIMALS`]；接受有符号金额（如余额变化、净流量）。
+pub fn mist_to_sui(amount: impl Into<i128>, decimals: u32) -> f64 {
+    let amount = amount.into();
+    if decimals == SUI_DECIMALS {
+        amount as f64 / MIST_PER_SUI as f64
+    } else {
+        amount as f64 / 10f64.powi(decimals as i32)
+    }
+}
+
+/// 将十进制 SUI 字符串（如 "10.5"）精确换算为 MIST
+///
+/// 最多 9 位小数；负数、NaN、科学计数法及溢出 u64 的值返回 `None`。
+pub fn parse_sui_amount(value: &str) -> Option<u64> {
+    let value = value.trim();
+    let (whole, frac) = match value.split_once('.') {
+        Some((whole, frac)) => (whole, frac),
+        None => (value, ""),
+    };
+    if whole.is_empty() && frac.is_empty() {
+        return None;
+    }
+    if !whole.bytes().all(|b| b.is_ascii_digit())
+        || !frac.bytes().all(|b| b.is_ascii_digit())
+        || frac.len() > SUI_DECIMALS as usize
+    {
+        return None;
+    }
+
+    let whole: u64 = if whole.is_empty() { 0 } else { whole.parse().ok()? };
+    let frac: u64 = if frac.is_empty() {
+        0
+    } else {
+        // 右侧补零到 9 位，如 "5" -> 500_000_000
+        format!("{:0<width$}", frac, width = SUI_DECIMALS as usize).parse().ok()?
+    };
+
+    whole.checked_mul(MIST_PER_SUI)?.checked_add(frac)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mist_to_sui() {
+        assert_eq!(MIST_PER_SUI, 10u64.pow(SUI_DECIMALS));
+        assert_eq!(mist_to_sui(1_500_000_000u64, SUI_DECIMALS), 1.5);
+        assert_eq!(mist_to_sui(-2_000_000_000i64, SUI_DECIMALS), -2.0);
+        assert_eq!(mist_to_sui(u64::MAX, SUI_DECIMALS), u64::MAX as f64 / 1e9);
+        assert_eq!(mist_to_sui(12_345u64, 2), 123.45);
+        assert_eq!(mist_to_sui(7u64, 0), 7.0);
+    }
+
+    #[test]
+    fn test_parse_sui_amount() {
+        assert_eq!(parse_sui_amount("10.5"), Some(10_500_000_000));
+        assert_eq!(parse_sui_amount("1"), Some(MIST_PER_SUI));
+        assert_eq!(parse_sui_amount(" 0.000000001 "), Some(1));
+        assert_eq!(parse_sui_amount(".25"), Some(250_000_000));
+        assert_eq!(parse_sui_amount("3."), Some(3 * MIST_PER_SUI));
+        assert_eq!(parse_sui_amount("0"), Some(0));
+
+        assert_eq!(parse_sui_amount("-1"), None);
+        assert_eq!(parse_sui_amount("NaN"), None);
+        assert_eq!(parse_sui_amount("inf"), None);
+        assert_eq!(parse_sui_amount("1e9"), None);
+        assert_eq!(parse_sui_amount("0.0000000001"), None);
+        assert_eq!(parse_sui_amount("."), None);
+        assert_eq!(parse_sui_amount(""), None);
+        assert_eq!(parse_sui_amount("1.2.3"), None);
+        assert_eq!(parse_sui_amount("99999999999"), None);
+    }
+}
@@ -273,15 +273,20 @@ async fn test_integration_flow() {
             rpc_url: "https://fullnode.testnet.sui.io:443".to_string(),
             websocket_url: "wss://fullnode.testnet.sui.io".to_string(),
             timeout_seconds: 10,
+            ..Config::default().network
         },
         monitoring: sui_token_transfer_tracker::config::MonitoringConfig {
             poll_interval_seconds: 5,
             max_history_records: 100,
             batch_size: 10,
             cleanup_interval_hours: 1,
+            ..Config::default().monitoring
         },
         addresses: sui_token_transfer_tracker::config::AddressConfig {
             monitored: vec![],
+            state_file: String::new(),
+            groups: std::collections::HashMap::new(),
+            ..Config::default().addresses
         },
         alerts: sui_token_transfer_tracker::config::AlertConfig {
             low_balance_threshold: 1000000000,
@@ -289,12 +294,14 @@ async fn test_integration_flow() {
             enable_console_alerts: true,
             enable_file_alerts: false,
             alert_file_path: "test_alerts.log".to_string(),
+            ..Config::default().alerts
         },
         output: sui_token_transfer_tracker::config::OutputConfig {
             use_colors: false,
             show_timestamps: true,
             max_recent_transactions: 5,
             balance_summary_interval: 60,
+            ..Config::default().output
         },
         logging: sui_token_transfer_tracker::config::LoggingConfig {
             level: "debug".to_string(),
@@ -302,6 +309,8 @@ async fn test_integration_flow() {
             max_file_size_mb: 1,
             rotate_files: 1,
         },
+        networks: vec![],
+        price: Default::default(),
     };
 
     let tracker_result = TokenTransferTracker::new(config).await;
@@ -379,6 +388,514 @@ async fn test_concurrent_operations() {
     }
 }
 
+fn mock_transaction(digest: &str, timestamp: i64, changes: &[(&str, i64)]) -> sui_token_transfer_tracker::sui_client::SuiTransaction {
+    sui_token_transfer_tracker::sui_client::SuiTransaction {
+        digest: digest.to_string(),
+        timestamp: chrono::DateTime::from_timestamp(timestamp, 0),
+        gas_used: Some("1000".to_string()),
+        balance_changes: changes
+            .iter()
+            .map(|(owner, amount)| sui_token_transfer_tracker::sui_client::BalanceChange {
+                owner: owner.to_string(),
+                coin_type: "0x2::sui::SUI".to_string(),
+                amount: *amount,
+            })
+            .collect(),
+        status: Default::default(),
+        checkpoint: None,
+    }
+}
+
+#[tokio::test]
+async fn test_mock_event_monitor_pipeline() {
+    use sui_token_transfer_tracker::mock_sui_client::MockSuiClient;
+
+    let owner = format!("0x{}", "a".repeat(64));
+    let recipient = format!("0x{}", "b".repeat(64));
+    let mock = MockSuiClient::new();
+    mock.add_sent_transaction(&owner, mock_transaction("digest-1", 1_700_000_000, &[(&recipient, 2_000_000_000)]));
+
+    let (monitor, mut receiver) = EventMonitor::new(Arc::new(mock), Duration::from_secs(10)).await;
+    monitor.add_address(owner.clone()).await.unwrap();
+
+    assert_eq!(monitor.force_check_all_addresses().await.unwrap(), 1);
+    let event = receiver.try_recv().unwrap();
+    assert_eq!(event.transaction_id, "digest-1");
+    assert_eq!(event.sender, owner);
+    assert_eq!(event.recipient, recipient);
+    assert_eq!(event.amount, 2_000_000_000);
+    assert_eq!(event.network, "mock");
+}
+
+#[tokio::test]
+async fn test_mock_event_monitor_resumes_from_cursor() {
+    use sui_token_transfer_tracker::mock_sui_client::MockSuiClient;
+
+    let owner = format!("0x{}", "a".repeat(64));
+    let recipient = format!("0x{}", "b".repeat(64));
+    let mock = Arc::new(MockSuiClient::new());
+    mock.add_sent_transaction(&owner, mock_transaction("digest-1", 1_700_000_000, &[(&recipient, 1_000)]));
+
+    let (monitor, _receiver) = EventMonitor::new(mock.clone(), Duration::from_secs(10)).await;
+    monitor.add_address(owner.clone()).await.unwrap();
+    assert_eq!(monitor.force_check_all_addresses().await.unwrap(), 1);
+    // 进度已推进，再次检查不会重复发送
+    assert_eq!(monitor.force_check_all_addresses().await.unwrap(), 0);
+    let cursors = monitor.address_cursors().await;
+    assert_eq!(cursors[&owner].last_digest.as_deref(), Some("digest-1"));
+
+    // 模拟重启：新的监控器恢复保存的进度后只处理之后的交易
+    mock.add_sent_transaction(&owner, mock_transaction("digest-2", 1_700_000_010, &[(&recipient, 2_000)]));
+    let (restarted, mut receiver) = EventMonitor::new(mock.clone(), Duration::from_secs(10)).await;
+    restarted.add_address(owner.clone()).await.unwrap();
+    assert_eq!(restarted.restore_cursors(&cursors, &Default::default()).await, 1);
+    assert_eq!(restarted.force_check_all_addresses().await.unwrap(), 1);
+    assert_eq!(receiver.try_recv().unwrap().transaction_id, "digest-2");
+    assert!(receiver.try_recv().is_err());
+}
+
+#[tokio::test]
+async fn test_mock_event_monitor_backpressure() {
+    use sui_token_transfer_tracker::mock_sui_client::MockSuiClient;
+
+    let owner = format!("0x{}", "a".repeat(64));
+    let recipient = format!("0x{}", "b".repeat(64));
+    let mock = MockSuiClient::new();
+    for i in 0..5 {
+        mock.add_sent_transaction(&owner, mock_transaction(&format!("digest-{}", i), 1_700_000_000 + i, &[(&recipient, 1_000)]));
+    }
+
+    // 容量为 2 的通道放不下 5 个事件，查询等待消费者而不是丢弃事件
+    let (monitor, mut receiver) = EventMonitor::with_capacity(Arc::new(mock), Duration::from_secs(10), 2).await;
+    monitor.add_address(owner.clone()).await.unwrap();
+    let event_sender = monitor.event_sender();
+    let check = tokio::spawn(async move { monitor.force_check_all_addresses().await.unwrap() });
+
+    let mut received = Vec::new();
+    while received.len() < 5 {
+        let event = tokio::time::timeout(Duration::from_secs(5), receiver.recv()).await.unwrap().unwrap();
+        received.push(event.transaction_id);
+    }
+    assert_eq!(check.await.unwrap(), 5);
+    assert_eq!(received, (0..5).map(|i| format!("digest-{}", i)).collect::<Vec<_>>());
+    assert_eq!(event_sender.capacity(), 2);
+    assert_eq!(event_sender.queued(), 0);
+    assert!(event_sender.backpressure_waits() > 0);
+}
+
+#[tokio::test]
+async fn test_mock_event_monitor_finality_depth() {
+    use sui_token_transfer_tracker::mock_sui_client::MockSuiClient;
+    use sui_token_transfer_tracker::transaction_processor::TransactionStatus;
+
+    let owner = format!("0x{}", "a".repeat(64));
+    let recipient = format!("0x{}", "b".repeat(64));
+    let mock = MockSuiClient::new();
+    mock.set_latest_checkpoint(105);
+    for (digest, checkpoint) in [("final", 100), ("recent", 103)] {
+        let mut tx = mock_transaction(digest, 1_700_000_000, &[(&recipient, 1_000)]);
+        tx.checkpoint = Some(checkpoint);
+        mock.add_sent_transaction(&owner, tx);
+    }
+
+    let (mut monitor, mut receiver) = EventMonitor::new(Arc::new(mock), Duration::from_secs(10)).await;
+    monitor.set_finality_depth(5);
+    monitor.add_address(owner.clone()).await.unwrap();
+
+    assert_eq!(monitor.force_check_all_addresses().await.unwrap(), 2);
+    let first = receiver.try_recv().unwrap();
+    let second = receiver.try_recv().unwrap();
+    assert_eq!((first.transaction_id.as_str(), first.status), ("final", TransactionStatus::Success));
+    assert_eq!((second.transaction_id.as_str(), second.status), ("recent", TransactionStatus::Pending));
+    assert_eq!(second.block_number, 103);
+}
+
+#[tokio::test]
+async fn test_mock_event_monitor_pause_resume() {
+    use sui_token_transfer_tracker::mock_sui_client::MockSuiClient;
+
+    let owner = format!("0x{}", "a".repeat(64));
+    let recipient = format!("0x{}", "b".repeat(64));
+    let mock = MockSuiClient::new();
+    mock.add_sent_transaction(&owner, mock_transaction("digest-1", 1_700_000_000, &[(&recipient, 1_000)]));
+
+    let (monitor, mut receiver) = EventMonitor::new(Arc::new(mock), Duration::from_millis(10)).await;
+    monitor.add_address(owner.clone()).await.unwrap();
+    monitor.pause_monitoring().await;
+    assert!(monitor.is_paused().await);
+
+    monitor.start_monitoring().await;
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert!(receiver.try_recv().is_err());
+
+    monitor.resume_monitoring().await;
+    assert!(!monitor.is_paused().await);
+    let event = tokio::time::timeout(Duration::from_secs(1), receiver.recv()).await.unwrap().unwrap();
+    assert_eq!(event.transaction_id, "digest-1");
+    monitor.stop_monitoring().await;
+}
+
+#[tokio::test]
+async fn test_mock_tracker_max_monitored_addresses() {
+    use sui_token_transfer_tracker::mock_sui_client::MockSuiClient;
+
+    let first = format!("0x{}", "a".repeat(64));
+    let second = format!("0x{}", "b".repeat(64));
+    let mut config = Config::default();
+    config.monitoring.max_monitored_addresses = 1;
+    config.output.quiet = true;
+    let tracker = TokenTransferTracker::with_rpc_client(config, Arc::new(MockSuiClient::new())).await.unwrap();
+
+    tracker.add_address(first.clone()).await.unwrap();
+    // 重复添加已监控的地址不受上限影响
+    tracker.add_address(first.clone()).await.unwrap();
+
+    let err = tracker.add_address(second).await.unwrap_err().to_string();
+    assert!(err.contains("1 addresses already monitored"), "{}", err);
+    assert!(err.contains("max_monitored_addresses is 1"), "{}", err);
+    assert_eq!(tracker.get_all_addresses().await, vec![first]);
+}
+
+#[tokio::test]
+async fn test_mock_tracker_readd_address_keeps_state() {
+    use sui_token_transfer_tracker::mock_sui_client::MockSuiClient;
+
+    let address = format!("0x{}", "a".repeat(64));
+    let client = Arc::new(MockSuiClient::new());
+    client.set_balance(&address, "0x2::sui::SUI", 1_000);
+    let mut config = Config::default();
+    config.output.quiet = true;
+    let tracker = TokenTransferTracker::with_rpc_client(config, client.clone()).await.unwrap();
+
+    assert!(tracker.add_address(address.clone()).await.unwrap());
+    let before = tracker.get_address_info(&address).await.unwrap();
+
+    // 跨过秒级时间戳，确认 first_seen 不会被重置
+    tokio::time::sleep(Duration::from_millis(1100)).await;
+    client.set_balance(&address, "0x2::sui::SUI", 2_000);
+    assert!(!tracker.add_address(address.to_uppercase().replacen("0X", "0x", 1)).await.unwrap());
+
+    let after = tracker.get_address_info(&address).await.unwrap();
+    assert_eq!(after.balance, 2_000);
+    assert!(after.last_checked > before.last_checked);
+    assert_eq!(after.first_seen, before.first_seen);
+    assert_eq!(after.last_seen, before.last_seen);
+    assert_eq!(after.total_transactions, before.total_transactions);
+    assert_eq!(after.alert_threshold, before.alert_threshold);
+    assert_eq!(tracker.get_all_addresses().await, vec![address]);
+}
+
+#[tokio::test]
+async fn test_mock_tracker_reconciles_per_coin_type() {
+    use sui_token_transfer_tracker::event_monitor::TransferEvent;
+    use sui_token_transfer_tracker::mock_sui_client::MockSuiClient;
+    use sui_token_transfer_tracker::transaction_processor::TransactionStatus;
+
+    let owner = format!("0x{}", "a".repeat(64));
+    let sender = format!("0x{}", "b".repeat(64));
+    let usdc = "0xabc::usdc::USDC";
+    let client = Arc::new(MockSuiClient::new());
+    client.set_balance(&owner, "0x2::sui::SUI", 5_000);
+    client.set_balance(&owner, usdc, 1_000);
+    let mut config = Config::default();
+    config.output.quiet = true;
+    config.monitoring.reconcile_tolerance = 0;
+    config.addresses.monitored = vec![owner.clone()];
+    let tracker = TokenTransferTracker::with_rpc_client(config, client).await.unwrap();
+
+    tracker.transaction_processor.process_transfer_event(TransferEvent {
+        transaction_id: "usdc-in".to_string(),
+        package_id: "0x2".to_string(),
+        transaction_module: "pay".to_string(),
+        sender,
+        recipient: owner.clone(),
+        amount: 1_000,
+        token_type: usdc.to_string(),
+        timestamp: 1_700_000_000,
+        block_number: 0,
+        event_type: "transfer".to_string(),
+        gas_used: None,
+        status: TransactionStatus::Success,
+        network: "mock".to_string(),
+    }).await.unwrap();
+
+    // USDC 与链上一致，只有 SUI 需要校正，其他代币不会被 SUI 余额覆盖
+    let report = tracker.reconcile_balances().await.unwrap();
+    assert_eq!(report.checked, 2);
+    assert_eq!(report.reconciled, 1);
+    assert_eq!(report.total_drift, 5_000);
+    let balances = tracker.transaction_processor.get_token_balances(&owner).await;
+    assert_eq!(balances[usdc], 1_000);
+    assert_eq!(balances["0x2::sui::SUI"], 5_000);
+}
+
+#[tokio::test]
+async fn test_mock_tracker_confirms_pending_transactions() {
+    use sui_token_transfer_tracker::event_monitor::TransferEvent;
+    use sui_token_transfer_tracker::mock_sui_client::MockSuiClient;
+    use sui_token_transfer_tracker::transaction_processor::TransactionStatus;
+    use sui_token_transfer_tracker::PendingConfirmationReport;
+
+    let sender = format!("0x{}", "a".repeat(64));
+    let recipient = format!("0x{}", "b".repeat(64));
+    let client = Arc::new(MockSuiClient::new());
+    client.add_sent_transaction(&sender, mock_transaction("digest-ok", 1_700_000_000, &[(&recipient, 500)]));
+    let mut config = Config::default();
+    config.output.quiet = true;
+    config.monitoring.pending_confirmation_max_checks = 2;
+    let tracker = TokenTransferTracker::with_rpc_client(config, client.clone()).await.unwrap();
+
+    for digest in ["digest-ok", "digest-missing"] {
+        tracker.transaction_processor.process_transfer_event(TransferEvent {
+            transaction_id: digest.to_string(),
+            package_id: "0x2".to_string(),
+            transaction_module: "pay".to_string(),
+            sender: sender.clone(),
+            recipient: recipient.clone(),
+            amount: 500,
+            token_type: "0x2::sui::SUI".to_string(),
+            timestamp: 1_700_000_000,
+            block_number: 0,
+            event_type: "transfer".to_string(),
+            gas_used: None,
+            status: TransactionStatus::Pending,
+            network: "mock".to_string(),
+        }).await.unwrap();
+    }
+    assert_eq!(tracker.transaction_processor.get_address_balance(&recipient).await, 1_000);
+
+    // 查到链上结果的交易被确认，查不到的继续等待
+    let report = tracker.confirm_pending_transactions().await.unwrap();
+    assert_eq!(report, PendingConfirmationReport { checked: 2, confirmed: 1, failed: 0, expired: 0 });
+    assert_eq!(tracker.transaction_processor.pending_transaction_ids().await, vec!["digest-missing".to_string()]);
+
+    // 达到查询次数上限后标记为失败并退回金额
+    let report = tracker.confirm_pending_transactions().await.unwrap();
+    assert_eq!(report, PendingConfirmationReport { checked: 1, confirmed: 0, failed: 0, expired: 1 });
+    assert!(tracker.transaction_processor.pending_transaction_ids().await.is_empty());
+    assert_eq!(tracker.transaction_processor.get_address_balance(&recipient).await, 500);
+
+    let report = tracker.confirm_pending_transactions().await.unwrap();
+    assert_eq!(report, PendingConfirmationReport::default());
+}
+
+#[tokio::test]
+async fn test_mock_tracker_pending_expiry_keeps_observed_status() {
+    use sui_token_transfer_tracker::event_monitor::TransferEvent;
+    use sui_token_transfer_tracker::mock_sui_client::MockSuiClient;
+    use sui_token_transfer_tracker::transaction_processor::TransactionStatus;
+    use sui_token_transfer_tracker::PendingConfirmationReport;
+
+    let sender = format!("0x{}", "a".repeat(64));
+    let recipient = format!("0x{}", "b".repeat(64));
+    let client = Arc::new(MockSuiClient::new());
+    let mut on_chain = mock_transaction("digest-shallow", 1_700_000_000, &[(&recipient, 500)]);
+    on_chain.checkpoint = Some(5);
+    client.add_sent_transaction(&sender, on_chain);
+    client.set_latest_checkpoint(6);
+    let mut config = Config::default();
+    config.output.quiet = true;
+    config.monitoring.finality_depth = 10;
+    config.monitoring.pending_confirmation_max_checks = 2;
+    let tracker = TokenTransferTracker::with_rpc_client(config, client.clone()).await.unwrap();
+
+    tracker.transaction_processor.process_transfer_event(TransferEvent {
+        transaction_id: "digest-shallow".to_string(),
+        package_id: "0x2".to_string(),
+        transaction_module: "pay".to_string(),
+        sender: sender.clone(),
+        recipient: recipient.clone(),
+        amount: 500,
+        token_type: "0x2::sui::SUI".to_string(),
+        timestamp: 1_700_000_000,
+        block_number: 0,
+        event_type: "transfer".to_string(),
+        gas_used: None,
+        status: TransactionStatus::Pending,
+        network: "mock".to_string(),
+    }).await.unwrap();
+
+    // 节点故障期间的查询不计入次数上限
+    client.set_healthy(false);
+    for _ in 0..3 {
+        let report = tracker.confirm_pending_transactions().await.unwrap();
+        assert_eq!(report, PendingConfirmationReport { checked: 1, confirmed: 0, failed: 0, expired: 0 });
+    }
+    client.set_healthy(true);
+
+    // 链上已成功但未达到 finality_depth，过期时沿用 Success，不退回金额
+    let report = tracker.confirm_pending_transactions().await.unwrap();
+    assert_eq!(report, PendingConfirmationReport { checked: 1, confirmed: 0, failed: 0, expired: 0 });
+    let report = tracker.confirm_pending_transactions().await.unwrap();
+    assert_eq!(report, PendingConfirmationReport { checked: 1, confirmed: 0, failed: 0, expired: 1 });
+    assert!(tracker.transaction_processor.pending_transaction_ids().await.is_empty());
+    assert_eq!(tracker.transaction_processor.get_address_balance(&recipient).await, 500);
+}
+
+#[tokio::test]
+async fn test_mock_tracker_coin_report() {
+    use sui_token_transfer_tracker::mock_sui_client::MockSuiClient;
+    use sui_token_transfer_tracker::sui_client::SuiCoin;
+
+    let address = format!("0x{}", "a".repeat(64));
+    let coin = |id: usize, coin_type: &str, balance: u64| SuiCoin {
+        coin_type: coin_type.to_string(),
+        coin_object_id: format!("0x{:064x}", id),
+        version: "1".to_string(),
+        digest: format!("digest{}", id),
+        balance,
+        previous_transaction: format!("tx{}", id),
+    };
+
+    let mock = Arc::new(MockSuiClient::new());
+    // 超过一页（50 个）的 SUI Coin，加一个其他代币的 Coin
+    mock.add_coin(&address, coin(0, "0x2::sui::SUI", 40_000_000_000));
+    for id in 1..60 {
+        mock.add_coin(&address, coin(id, "0x2::sui::SUI", 1_000_000_000));
+    }
+    mock.add_coin(&address, coin(99, "0xdead::usdc::USDC", 5));
+
+    let tracker = TokenTransferTracker::with_rpc_client(Config::default(), mock).await.unwrap();
+    let report = tracker.query_coin_report(&address, None).await.unwrap();
+    assert_eq!(report.coin_count, 60);
+    assert_eq!(report.total_balance, 99_000_000_000);
+    assert_eq!(report.largest_coin, 40_000_000_000);
+    assert_eq!(report.smallest_coin, 1_000_000_000);
+    assert!(report.fragmentation_score > 0.5);
+
+    let report = tracker.query_coin_report(&address, Some("0xdead::usdc::USDC")).await.unwrap();
+    assert_eq!(report.coin_count, 1);
+    assert_eq!(report.fragmentation_score, 0.0);
+}
+
+#[tokio::test]
+async fn test_mock_event_monitor_package_filter() {
+    use sui_token_transfer_tracker::mock_sui_client::MockSuiClient;
+
+    let package = format!("0x{}", "d".repeat(64));
+    let recipient = format!("0x{}", "b".repeat(64));
+    let move_event = |digest: &str, module: &str, amount: u64| serde_json::json!({
+        "id": { "txDigest": digest, "eventSeq": "0" },
+        "packageId": package,
+        "transactionModule": module,
+        "sender": format!("0x{}", "a".repeat(64)),
+        "type": format!("{}::{}::Withdraw<0x2::sui::SUI>", package, module),
+        "parsedJson": { "amount": amount.to_string(), "recipient": recipient },
+        "timestampMs": "1700000000000",
+    });
+
+    let mock = Arc::new(MockSuiClient::new());
+    mock.add_move_event(move_event("old", "pool", 1_000));
+
+    let (monitor, mut receiver) = EventMonitor::new(mock.clone(), Duration::from_millis(10)).await;
+    assert!(monitor.add_package_filter("not-a-package".to_string()).await.is_err());
+    monitor.add_package_filter(format!("{}::pool", package)).await.unwrap();
+    assert_eq!(monitor.get_package_filters().await, vec![format!("{}::pool", package)]);
+
+    monitor.start_monitoring().await;
+    let event = tokio::time::timeout(Duration::from_secs(1), receiver.recv()).await.unwrap().unwrap();
+    assert_eq!((event.transaction_id.as_str(), event.amount), ("old", 1_000));
+    assert_eq!(event.recipient, recipient);
+    assert_eq!(event.network, "mock");
+
+    // 其他模块的事件不匹配，游标之后的新事件只发送一次
+    mock.add_move_event(move_event("other", "router", 5));
+    mock.add_move_event(move_event("new", "pool", 2_000));
+    let event = tokio::time::timeout(Duration::from_secs(1), receiver.recv()).await.unwrap().unwrap();
+    assert_eq!(event.transaction_id, "new");
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert!(receiver.try_recv().is_err());
+    monitor.stop_monitoring().await;
+}
+
+#[tokio::test]
+async fn test_mock_tracker_replay() {
+    use sui_token_transfer_tracker::mock_sui_client::MockSuiClient;
+
+    let owner = format!("0x{}", "a".repeat(64));
+    let other = format!("0x{}", "c".repeat(64));
+    let mock = MockSuiClient::new();
+    mock.set_balance(&owner, "0x2::sui::SUI", 5_000_000_000);
+    mock.add_sent_transaction(&owner, mock_transaction("sent-1", 1_700_000_000, &[(&other, 1_000_000_000)]));
+    mock.add_received_transaction(&owner, mock_transaction("received-1", 1_700_000_100, &[
+        (&other, -3_000_000_000),
+        (&owner, 3_000_000_000),
+    ]));
+
+    let mut config = Config::default();
+    config.addresses.monitored = vec![owner.clone()];
+    let tracker = TokenTransferTracker::with_rpc_client(config, Arc::new(mock)).await.unwrap();
+
+    assert_eq!(tracker.query_balance(&owner, None).await.unwrap(), 5_000_000_000);
+    assert_eq!(tracker.get_tracker_stats().await.addresses_monitored, 1);
+
+    assert_eq!(tracker.replay_address_history(&owner, 10).await.unwrap(), 2);
+    let stats = tracker.get_tracker_stats().await;
+    assert_eq!(stats.total_events_processed, 2);
+
+    let tx = tracker.get_transaction("received-1").await.unwrap();
+    assert_eq!(tx.balance_changes.len(), 2);
+}
+
+#[tokio::test]
+async fn test_mock_tracker_load_history_for_volume_timeseries() {
+    use sui_token_transfer_tracker::mock_sui_client::MockSuiClient;
+
+    let owner = format!("0x{}", "a".repeat(64));
+    let other = format!("0x{}", "c".repeat(64));
+    let timestamp = chrono::Utc::now().timestamp() - 60;
+    let transfer = mock_transaction("between", timestamp, &[(&owner, -1_000), (&other, 1_000)]);
+    let mock = MockSuiClient::new();
+    mock.add_sent_transaction(&owner, transfer.clone());
+    mock.add_received_transaction(&other, transfer);
+
+    let mut config = Config::default();
+    config.output.quiet = true;
+    config.addresses.monitored = vec![owner.clone(), other.clone()];
+    let tracker = TokenTransferTracker::with_rpc_client(config, Arc::new(mock)).await.unwrap();
+
+    // 监控地址之间的转账在两个地址的历史中各出现一次，只载入一次
+    let addresses = tracker.get_all_addresses().await;
+    assert_eq!(tracker.load_address_history(&addresses, 50).await.unwrap(), 1);
+    let series = tracker.transaction_processor.get_volume_timeseries("0x2::sui::SUI", 3600, 24).await;
+    assert_eq!(series.iter().map(|(_, volume)| volume).sum::<u64>(), 1_000);
+}
+
+#[tokio::test]
+async fn test_mock_tracker_processes_polled_events() {
+    use sui_token_transfer_tracker::mock_sui_client::MockSuiClient;
+
+    let owner = format!("0x{}", "a".repeat(64));
+    let recipient = format!("0x{}", "b".repeat(64));
+    let mock = MockSuiClient::new();
+    mock.add_sent_transaction(&owner, mock_transaction("polled-1", 1_700_000_000, &[(&recipient, 2_000_000_000)]));
+
+    let mut config = Config::default();
+    config.output.quiet = true;
+    config.monitoring.poll_interval_seconds = 1;
+    config.addresses.monitored = vec![owner.clone()];
+    let mut tracker = TokenTransferTracker::with_rpc_client(config, Arc::new(mock)).await.unwrap();
+
+    // 处理循环不会自行退出，超时后检查轮询到的事件是否已被处理
+    let result = tokio::time::timeout(Duration::from_secs(2), tracker.start_monitoring()).await;
+    assert!(result.is_err());
+
+    let stats = tracker.get_tracker_stats().await;
+    assert!(stats.total_events_processed >= 1);
+    let transactions = tracker.transaction_processor.get_all_transactions().await;
+    assert!(transactions.iter().any(|tx| tx.id == "polled-1"));
+}
+
+#[tokio::test]
+async fn test_mock_tracker_unhealthy() {
+    use sui_token_transfer_tracker::mock_sui_client::MockSuiClient;
+
+    let mock = MockSuiClient::new();
+    mock.set_healthy(false);
+    let result = TokenTransferTracker::with_rpc_client(Config::default(), Arc::new(mock)).await;
+    assert!(result.is_err());
+}
+
 #[test]
 fn test_error_messages() {
     use sui_token_transfer_tracker::error::TrackerError;